@@ -21,3 +21,170 @@ fn parse_file(b: &mut Bencher) {
     let tokens = TokenStream::from_str(&content).unwrap();
     b.iter(|| syn::parse2::<syn::File>(tokens.clone()));
 }
+
+#[bench]
+fn parse_file_uncached(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    b.iter(|| syn::parse_file(&content));
+}
+
+#[bench]
+fn parse_file_cached(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let mut cache = syn::FileCache::new();
+    b.iter(|| cache.parse_file(&content));
+}
+
+// A large file in which no item, field, or variant has attributes, for
+// measuring the cost of looking for attributes that are not there.
+#[bench]
+fn parse_file_attr_sparse(b: &mut Bencher) {
+    let mut content = String::new();
+    for i in 0..1000 {
+        content += &format!(
+            "pub struct S{i} {{ a: u8, pub b: Vec<u16>, c: Option<String> }}
+             enum E{i} {{ A, B(u8), C {{ x: i32, y: i32 }} }}
+             impl S{i} {{
+                 pub fn get(&self) -> u8 {{ let x = self.a; x + 1 }}
+                 fn set(&mut self, a: u8) {{ self.a = a; }}
+             }}
+             fn f{i}(x: u32) -> u32 {{ if x > 0 {{ x * 2 }} else {{ 0 }} }}
+            ",
+            i = i,
+        );
+    }
+    let tokens = TokenStream::from_str(&content).unwrap();
+    b.iter(|| syn::parse2::<syn::File>(tokens.clone()));
+}
+
+#[cfg(all(feature = "visit", feature = "extra-traits", feature = "clone-impls"))]
+fn file_types(file: &syn::File) -> Vec<&syn::Type> {
+    use syn::visit::{self, Visit};
+
+    struct CollectTypes<'ast>(Vec<&'ast syn::Type>);
+
+    impl<'ast> Visit<'ast> for CollectTypes<'ast> {
+        fn visit_type(&mut self, ty: &'ast syn::Type) {
+            self.0.push(ty);
+            visit::visit_type(self, ty);
+        }
+    }
+
+    let mut collect = CollectTypes(Vec::new());
+    collect.visit_file(file);
+    collect.0
+}
+
+// Counts, for every type in the file, how many of the first 200 types are
+// equal to it.
+#[cfg(all(feature = "visit", feature = "extra-traits", feature = "clone-impls"))]
+#[bench]
+fn type_eq_structural(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+    let types = file_types(&file);
+    b.iter(|| {
+        types
+            .iter()
+            .map(|ty| types[..200].iter().filter(|other| other == &ty).count())
+            .sum::<usize>()
+    });
+}
+
+#[cfg(all(feature = "visit", feature = "extra-traits", feature = "clone-impls"))]
+#[bench]
+fn type_eq_interned(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+    let mut interner = syn::TypeInterner::new();
+    let ids: Vec<syn::InternedType> = file_types(&file)
+        .into_iter()
+        .map(|ty| interner.intern(ty))
+        .collect();
+    b.iter(|| {
+        ids.iter()
+            .map(|id| ids[..200].iter().filter(|other| *other == id).count())
+            .sum::<usize>()
+    });
+}
+
+#[cfg(all(feature = "visit", feature = "extra-traits", feature = "clone-impls"))]
+#[bench]
+fn type_intern(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+    let types = file_types(&file);
+    b.iter(|| {
+        let mut interner = syn::TypeInterner::new();
+        for ty in &types {
+            interner.intern(ty);
+        }
+        interner.len()
+    });
+}
+
+#[cfg(feature = "visit")]
+fn file_attrs(file: &syn::File) -> Vec<&syn::Attribute> {
+    use syn::visit::{self, Visit};
+
+    struct CollectAttrs<'ast>(Vec<&'ast syn::Attribute>);
+
+    impl<'ast> Visit<'ast> for CollectAttrs<'ast> {
+        fn visit_attribute(&mut self, attr: &'ast syn::Attribute) {
+            self.0.push(attr);
+            visit::visit_attribute(self, attr);
+        }
+    }
+
+    let mut collect = CollectAttrs(Vec::new());
+    collect.visit_file(file);
+    collect.0
+}
+
+// Counts the attributes in the file named like one of a few common attributes.
+#[cfg(feature = "visit")]
+#[bench]
+fn attr_path_is(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+    let attrs = file_attrs(&file);
+    b.iter(|| {
+        attrs
+            .iter()
+            .filter(|attr| {
+                attr.path_is("cfg")
+                    || attr.path_is("derive")
+                    || attr.path_is("doc")
+                    || attr.path_is("repr")
+            })
+            .count()
+    });
+}
+
+// The same as attr_path_is but by comparing against a constructed Path.
+#[cfg(all(feature = "visit", feature = "extra-traits"))]
+#[bench]
+fn attr_path_eq(b: &mut Bencher) {
+    repo::clone_rust();
+    let content = fs::read_to_string(FILE).unwrap();
+    let file = syn::parse_file(&content).unwrap();
+    let attrs = file_attrs(&file);
+    let path = |name| syn::Path::from(syn::Ident::new(name, proc_macro2::Span::call_site()));
+    b.iter(|| {
+        attrs
+            .iter()
+            .filter(|attr| {
+                attr.path == path("cfg")
+                    || attr.path == path("derive")
+                    || attr.path == path("doc")
+                    || attr.path == path("repr")
+            })
+            .count()
+    });
+}
@@ -260,7 +260,7 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             use crate::*;
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::token::{Brace, Bracket, Paren, Group};
-            use proc_macro2::Span;
+            use proc_macro2::{Span, TokenStream};
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::gen::helper::fold::*;
 
@@ -236,7 +236,7 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             use crate::*;
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::punctuated::Punctuated;
-            use proc_macro2::Span;
+            use proc_macro2::{Span, TokenStream};
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::gen::helper::visit::*;
 
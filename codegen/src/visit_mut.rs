@@ -205,6 +205,16 @@ fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Defi
         }
     }
 
+    let visit_span_only =
+        s.data == Data::Private && !gen::TERMINAL_TYPES.contains(&s.ident.as_str());
+    if visit_span_only {
+        visit_mut_impl = quote! {
+            let mut span = node.span();
+            v.visit_span_mut(&mut span);
+            node.set_span(span);
+        };
+    }
+
     traits.extend(quote! {
         fn #visit_mut_fn(&mut self, i: &mut #ty) {
             #visit_mut_fn(self, i)
@@ -232,7 +242,7 @@ pub fn generate(defs: &Definitions) -> Result<()> {
             use crate::*;
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::punctuated::Punctuated;
-            use proc_macro2::Span;
+            use proc_macro2::{Span, TokenStream};
             #[cfg(any(feature = "full", feature = "derive"))]
             use crate::gen::helper::visit_mut::*;
 
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+
+use crate::fold::{self, Fold};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// Replaces every argument-position `impl Trait` in the signature of a
+/// function with an explicit generic type parameter.
+///
+/// Each `impl Trait` becomes a fresh type parameter appended after the
+/// existing generic parameters of the function, and the bounds of the `impl
+/// Trait` become a predicate on that parameter in the function's `where`
+/// clause. The parameters are named `T0`, `T1`, and so on in the order the
+/// `impl Trait` types appear, skipping any name that is already used as an
+/// identifier anywhere in the function so the new parameters never collide
+/// with or shadow existing ones.
+///
+/// An `impl Trait` nested inside of an argument type, such as in `Vec<impl
+/// Trait>` or in the bounds of another `impl Trait`, is replaced as well. The
+/// return type and the function body are left untouched.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{desugar_apit, parse_quote, ItemFn};
+///
+/// let item: ItemFn = parse_quote! {
+///     fn f<T>(t: T, a: impl AsRef<str>, b: &mut impl Iterator<Item = u8>) {}
+/// };
+///
+/// let expected = quote! {
+///     fn f<T, T0, T1>(t: T, a: T0, b: &mut T1)
+///     where
+///         T0: AsRef<str>,
+///         T1: Iterator<Item = u8>
+///     {}
+/// };
+///
+/// let desugared = desugar_apit(item);
+/// assert_eq!(quote!(#desugared).to_string(), expected.to_string());
+/// ```
+pub fn desugar_apit(item: ItemFn) -> ItemFn {
+    let mut idents = CollectIdents(HashSet::new());
+    let mut item = idents.fold_item_fn(item);
+
+    let mut desugar = DesugarApit {
+        used: idents.0,
+        next: 0,
+        params: Vec::new(),
+    };
+    item.sig.inputs = item
+        .sig
+        .inputs
+        .into_pairs()
+        .map(|pair| {
+            let (arg, punct) = pair.into_tuple();
+            let arg = match arg {
+                FnArg::Typed(mut arg) => {
+                    *arg.ty = desugar.fold_type(*arg.ty);
+                    FnArg::Typed(arg)
+                }
+                receiver @ FnArg::Receiver(_) => receiver,
+            };
+            punctuated::Pair::new(arg, punct)
+        })
+        .collect();
+
+    if desugar.params.is_empty() {
+        return item;
+    }
+
+    let generics = &mut item.sig.generics;
+    if generics.lt_token.is_none() {
+        generics.lt_token = Some(<Token![<]>::default());
+        generics.gt_token = Some(<Token![>]>::default());
+    }
+    for (ident, _) in &desugar.params {
+        generics
+            .params
+            .push(GenericParam::Type(TypeParam::from(ident.clone())));
+    }
+    let where_clause = generics.make_where_clause();
+    for (ident, bounds) in desugar.params {
+        where_clause
+            .predicates
+            .push(WherePredicate::Type(PredicateType {
+                lifetimes: None,
+                bounded_ty: Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(ident),
+                }),
+                colon_token: <Token![:]>::default(),
+                bounds,
+            }));
+    }
+
+    item
+}
+
+struct CollectIdents(HashSet<String>);
+
+impl Fold for CollectIdents {
+    fn fold_ident(&mut self, node: Ident) -> Ident {
+        self.0.insert(node.to_string());
+        node
+    }
+}
+
+struct DesugarApit {
+    used: HashSet<String>,
+    next: usize,
+    params: Vec<(Ident, Punctuated<TypeParamBound, Token![+]>)>,
+}
+
+impl DesugarApit {
+    fn fresh_ident(&mut self, span: Span) -> Ident {
+        loop {
+            let name = format!("T{}", self.next);
+            self.next += 1;
+            if !self.used.contains(&name) {
+                return Ident::new(&name, span);
+            }
+        }
+    }
+}
+
+impl Fold for DesugarApit {
+    fn fold_type(&mut self, node: Type) -> Type {
+        match fold::fold_type(self, node) {
+            Type::ImplTrait(impl_trait) => {
+                let ident = self.fresh_ident(impl_trait.impl_token.span);
+                self.params.push((ident.clone(), impl_trait.bounds));
+                Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(ident),
+                })
+            }
+            other => other,
+        }
+    }
+}
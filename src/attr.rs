@@ -131,6 +131,38 @@ impl Hash for Attribute {
 }
 
 impl Attribute {
+    /// Determines whether the path of this attribute is the single identifier
+    /// `name`, as in `#[cfg(...)]` or `#[doc = "..."]`.
+    ///
+    /// This is the same as [`Path::is_ident`] on the attribute's path. It
+    /// compares against the identifier in place without constructing a `Path`
+    /// to compare with, so it is cheap enough to call on every attribute of
+    /// every item when filtering for the ones a macro cares about. A path with
+    /// a leading colon, more than one segment, or path arguments never
+    /// matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, DeriveInput};
+    ///
+    /// let input: DeriveInput = parse_quote! {
+    ///     /// Docs.
+    ///     #[derive(Clone)]
+    ///     #[serde::rename = "other"]
+    ///     struct S;
+    /// };
+    ///
+    /// let derives = input.attrs.iter().filter(|attr| attr.path_is("derive"));
+    /// assert_eq!(derives.count(), 1);
+    ///
+    /// assert!(input.attrs[0].path_is("doc"));
+    /// assert!(!input.attrs[2].path_is("rename"));
+    /// ```
+    pub fn path_is(&self, name: &str) -> bool {
+        self.path.is_ident(name)
+    }
+
     /// Parses the content of the attribute, consisting of the path and tokens,
     /// as a [`Meta`] if possible.
     ///
@@ -181,8 +213,24 @@ impl Attribute {
     ///           ^^^^^^^^^ what gets parsed
     /// ```
     ///
+    /// The arguments must be parsed in their entirety, so it is an error for
+    /// any tokens to be left over after `T` has been parsed.
+    ///
     /// *This function is available if Syn is built with the `"parsing"`
     /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Attribute, Expr};
+    ///
+    /// let attr: Attribute = parse_quote! {
+    ///     #[default(42 + 1)]
+    /// };
+    ///
+    /// let expr: Expr = attr.parse_args().unwrap();
+    /// assert_eq!(expr, parse_quote!(42 + 1));
+    /// ```
     #[cfg(feature = "parsing")]
     pub fn parse_args<T: Parse>(&self) -> Result<T> {
         self.parse_args_with(T::parse)
@@ -201,8 +249,76 @@ impl Attribute {
         parser.parse2(self.tokens.clone())
     }
 
+    /// Returns the documentation text of a `#[doc = "..."]` attribute.
+    ///
+    /// Doc comments like `/// text` and `/** text */` are turned into this
+    /// form when they are tokenized, so they cannot be told apart from an
+    /// explicit `#[doc = " text"]` and both are returned here, including the
+    /// leading space of the comment. Doc attributes with a list body, like
+    /// `#[doc(hidden)]`, `#[doc(alias = "x")]`, or `#[doc(cfg(unix))]`, carry
+    /// no text and return `None`, as does any attribute other than `doc`.
+    ///
+    /// *This function is available if Syn is built with the `"parsing"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemFn};
+    ///
+    /// let item: ItemFn = parse_quote! {
+    ///     /// Adds one.
+    ///     #[doc(alias = "increment")]
+    ///     #[doc = "Wraps on overflow."]
+    ///     fn add_one(x: u8) -> u8 { x.wrapping_add(1) }
+    /// };
+    ///
+    /// let docs: Vec<String> = item
+    ///     .attrs
+    ///     .iter()
+    ///     .filter_map(|attr| attr.doc_string())
+    ///     .map(|doc| doc.value())
+    ///     .collect();
+    /// assert_eq!(docs, [" Adds one.", "Wraps on overflow."]);
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn doc_string(&self) -> Option<LitStr> {
+        if !self.path.is_ident("doc") {
+            return None;
+        }
+        match self.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit),
+            _ => None,
+        }
+    }
+
+    /// Determines whether this is a `#[doc(hidden)]` attribute.
+    ///
+    /// The `hidden` may appear alongside other entries of the list, as in
+    /// `#[doc(hidden, alias = "x")]`. Doc attributes that carry text, like a
+    /// doc comment, never count as hidden even if the text is `"hidden"`.
+    ///
+    /// *This function is available if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    pub fn is_doc_hidden(&self) -> bool {
+        if !self.path.is_ident("doc") {
+            return false;
+        }
+        match self.parse_meta() {
+            Ok(meta) => meta.nested_words().iter().any(|word| *word == "hidden"),
+            Err(_) => false,
+        }
+    }
+
     /// Parses zero or more outer attributes from the stream.
     ///
+    /// If the next token is not `#`, this returns an empty vector after a
+    /// single peek and without allocating, so there is no need for callers to
+    /// check for a `#` first when attributes are usually absent.
+    ///
     /// *This function is available if Syn is built with the `"parsing"`
     /// feature.*
     #[cfg(feature = "parsing")]
@@ -375,6 +491,32 @@ impl Meta {
             Meta::NameValue(meta) => &meta.path,
         }
     }
+
+    /// Returns the bare words nested inside of a list meta item.
+    ///
+    /// For example this would return `always` for `#[inline(always)]` and
+    /// `Clone, Debug` for `#[derive(Clone, Debug)]`. Nested entries that are
+    /// not a single identifier, like the `since = "1.0"` in
+    /// `#[deprecated(since = "1.0", note)]`, a literal, or a multi-segment path
+    /// like `serde::Serialize`, are skipped, so only `note` would be returned
+    /// in that case.
+    ///
+    /// The result is empty for an empty list like `#[derive()]` and for meta
+    /// items that are not a list at all, like `#[must_use]` or
+    /// `#[path = "a.rs"]`.
+    pub fn nested_words(&self) -> Vec<&Ident> {
+        let list = match self {
+            Meta::List(list) => list,
+            Meta::Path(_) | Meta::NameValue(_) => return Vec::new(),
+        };
+        list.nested
+            .iter()
+            .filter_map(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.get_ident(),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 ast_enum_of_structs! {
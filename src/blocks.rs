@@ -0,0 +1,155 @@
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// A syntax tree node whose trivial blocks can be collapsed by
+/// [`collapse_trivial_blocks`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+pub trait CollapseTrivialBlocks: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn fold_collapse_trivial_blocks(self, folder: &mut TrivialBlockCollapser) -> Self;
+}
+
+/// Replaces blocks that consist of nothing but a tail expression, like `{ x }`,
+/// with that expression wherever doing so cannot change the meaning of the
+/// code.
+///
+/// A block is collapsed only where it is the body of a match arm, as in `A =>
+/// { x }`, or the body of a closure without a return type, as in `|a| { a + 1
+/// }`. In both positions any expression may appear in place of a block, and
+/// the value of the body is used as is, so removing the braces is purely
+/// cosmetic. A comma is added after a collapsed match arm unless it is the
+/// last arm, since only block-like arm bodies may omit one. Blocks nested
+/// directly inside of a collapsed block, as in `A => { { x } }`, are collapsed
+/// along with it.
+///
+/// Blocks in every other position are left alone. The body of a function,
+/// loop, or `if`, and a closure with an explicit return type, require a block.
+/// As an operand or a method call receiver, a block moves the value of a place
+/// expression, so `{ v }.len()` consumes `v` while `v.len()` does not, and as a
+/// `let` initializer a block can shorten the lifetime of temporaries. A block
+/// is also kept if it has a label, contains any statement or item, carries an
+/// attribute, or if its tail expression carries an attribute, and `unsafe`
+/// and `async` blocks are never collapsed.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{collapse_trivial_blocks, parse_quote, Expr};
+///
+/// let expr: Expr = parse_quote! {
+///     match x {
+///         Some(x) => { x + 1 }
+///         None => { let y = 0; y }
+///     }
+/// };
+///
+/// let expected = quote! {
+///     match x {
+///         Some(x) => x + 1,
+///         None => { let y = 0; y }
+///     }
+/// };
+///
+/// let expr = collapse_trivial_blocks(expr);
+/// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+/// ```
+pub fn collapse_trivial_blocks<T: CollapseTrivialBlocks>(node: T) -> T {
+    node.fold_collapse_trivial_blocks(&mut TrivialBlockCollapser { _private: () })
+}
+
+#[doc(hidden)]
+pub struct TrivialBlockCollapser {
+    _private: (),
+}
+
+impl Fold for TrivialBlockCollapser {
+    fn fold_expr_match(&mut self, node: ExprMatch) -> ExprMatch {
+        let mut node = fold::fold_expr_match(self, node);
+        let last = node.arms.len().saturating_sub(1);
+        for (i, arm) in node.arms.iter_mut().enumerate() {
+            if collapse(&mut arm.body) && i != last && arm.comma.is_none() {
+                arm.comma = Some(<Token![,]>::default());
+            }
+        }
+        node
+    }
+
+    fn fold_expr_closure(&mut self, node: ExprClosure) -> ExprClosure {
+        let mut node = fold::fold_expr_closure(self, node);
+        if let ReturnType::Default = node.output {
+            collapse(&mut node.body);
+        }
+        node
+    }
+}
+
+// Repeatedly replaces the expression with the tail of the trivial block it
+// consists of, and returns whether it was replaced at least once.
+fn collapse(expr: &mut Box<Expr>) -> bool {
+    let mut collapsed = false;
+    while let Some(tail) = trivial_tail(expr) {
+        *expr = Box::new(tail);
+        collapsed = true;
+    }
+    collapsed
+}
+
+fn trivial_tail(expr: &mut Expr) -> Option<Expr> {
+    let block = match expr {
+        Expr::Block(block) if block.attrs.is_empty() && block.label.is_none() => block,
+        _ => return None,
+    };
+    let tail = match block.block.stmts.as_mut_slice() {
+        [Stmt::Expr(tail)] => tail,
+        _ => return None,
+    };
+    let attrs = tail.replace_attrs(Vec::new());
+    if !attrs.is_empty() {
+        tail.replace_attrs(attrs);
+        return None;
+    }
+    match block.block.stmts.pop() {
+        Some(Stmt::Expr(tail)) => Some(tail),
+        _ => unreachable!(),
+    }
+}
+
+macro_rules! impl_collapse_trivial_blocks {
+    ($($ty:ident => $fold:ident,)*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl CollapseTrivialBlocks for $ty {
+                fn fold_collapse_trivial_blocks(
+                    self,
+                    folder: &mut TrivialBlockCollapser,
+                ) -> Self {
+                    folder.$fold(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_collapse_trivial_blocks! {
+    Block => fold_block,
+    Expr => fold_expr,
+    File => fold_file,
+    ImplItem => fold_impl_item,
+    Item => fold_item,
+    ItemFn => fold_item_fn,
+    Stmt => fold_stmt,
+    TraitItem => fold_trait_item,
+}
+
+mod private {
+    pub trait Sealed {}
+}
@@ -0,0 +1,137 @@
+//! Resetting every span in a syntax tree to a canonical value.
+//!
+//! *This module is available if Syn is built with the `"visit-mut"` feature.*
+
+use crate::visit_mut::{self, VisitMut};
+use crate::*;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+
+/// A syntax tree node whose spans can be reset by [`clear_spans`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"visit-mut"` feature.*
+pub trait ClearSpans: private::Sealed {
+    #[doc(hidden)]
+    fn visit_clear_spans(&mut self, visitor: &mut SpanClearer);
+}
+
+/// Sets every span in the given syntax tree to `Span::call_site()`.
+///
+/// This covers the spans of all tokens and identifiers in the tree as well as
+/// literals and any unparsed tokens, such as the body of a macro invocation or
+/// the arguments of an attribute. Two syntax trees parsed from the same source
+/// at different positions have identical spans after being cleared, which
+/// makes them suitable to use as cache keys.
+///
+/// *This function is available if Syn is built with the `"visit-mut"`
+/// feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{clear_spans, parse_quote, ItemFn};
+///
+/// let mut item: ItemFn = parse_quote! {
+///     fn f() {
+///         println!("{}", 1 + 1);
+///     }
+/// };
+///
+/// clear_spans(&mut item);
+/// ```
+pub fn clear_spans<T: ClearSpans + ?Sized>(node: &mut T) {
+    node.visit_clear_spans(&mut SpanClearer);
+}
+
+#[doc(hidden)]
+pub struct SpanClearer;
+
+impl SpanClearer {
+    fn clear_token_stream(&mut self, tokens: &mut TokenStream) {
+        *tokens = tokens
+            .clone()
+            .into_iter()
+            .map(|mut token| {
+                if let TokenTree::Group(group) = &mut token {
+                    let mut stream = group.stream();
+                    self.clear_token_stream(&mut stream);
+                    *group = Group::new(group.delimiter(), stream);
+                }
+                token.set_span(Span::call_site());
+                token
+            })
+            .collect();
+    }
+}
+
+impl VisitMut for SpanClearer {
+    fn visit_span_mut(&mut self, span: &mut Span) {
+        *span = Span::call_site();
+    }
+
+    fn visit_token_stream_mut(&mut self, tokens: &mut TokenStream) {
+        self.clear_token_stream(tokens);
+    }
+
+    fn visit_lit_mut(&mut self, node: &mut Lit) {
+        match node {
+            Lit::Verbatim(lit) => lit.set_span(Span::call_site()),
+            _ => visit_mut::visit_lit_mut(self, node),
+        }
+    }
+}
+
+macro_rules! impl_clear_spans {
+    ($($(#[$cfg:meta])* $ty:ident => $visit:ident,)*) => {
+        $(
+            $(#[$cfg])*
+            impl private::Sealed for $ty {}
+
+            $(#[$cfg])*
+            impl ClearSpans for $ty {
+                fn visit_clear_spans(&mut self, visitor: &mut SpanClearer) {
+                    visitor.$visit(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_clear_spans! {
+    Attribute => visit_attribute_mut,
+    #[cfg(feature = "derive")]
+    DeriveInput => visit_derive_input_mut,
+    Expr => visit_expr_mut,
+    Generics => visit_generics_mut,
+    Lit => visit_lit_mut,
+    Macro => visit_macro_mut,
+    Path => visit_path_mut,
+    Type => visit_type_mut,
+    Visibility => visit_visibility_mut,
+    WhereClause => visit_where_clause_mut,
+    #[cfg(feature = "full")]
+    Block => visit_block_mut,
+    #[cfg(feature = "full")]
+    File => visit_file_mut,
+    #[cfg(feature = "full")]
+    ForeignItem => visit_foreign_item_mut,
+    #[cfg(feature = "full")]
+    ImplItem => visit_impl_item_mut,
+    #[cfg(feature = "full")]
+    Item => visit_item_mut,
+    #[cfg(feature = "full")]
+    ItemFn => visit_item_fn_mut,
+    #[cfg(feature = "full")]
+    Pat => visit_pat_mut,
+    #[cfg(feature = "full")]
+    Signature => visit_signature_mut,
+    #[cfg(feature = "full")]
+    Stmt => visit_stmt_mut,
+    #[cfg(feature = "full")]
+    TraitItem => visit_trait_item_mut,
+}
+
+mod private {
+    pub trait Sealed {}
+}
@@ -95,6 +95,32 @@ impl Fields {
     }
 }
 
+impl Variant {
+    /// Returns the explicit discriminant of this variant, such as `1 << 2` in
+    /// `A = 1 << 2`, or `None` if the variant does not have one.
+    pub fn discriminant(&self) -> Option<&Expr> {
+        self.discriminant.as_ref().map(|(_eq_token, expr)| expr)
+    }
+}
+
+impl Field {
+    /// Finds the first attribute on this field whose path is the given ident,
+    /// such as `serde` in `#[serde(rename = "x")]`.
+    ///
+    /// The tokens inside of the attribute are not inspected, so this works for
+    /// inert helper attributes of any shape.
+    ///
+    /// *This function is available if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    pub fn find_attr<I: ?Sized>(&self, name: &I) -> Option<&Attribute>
+    where
+        Ident: PartialEq<I>,
+    {
+        self.attrs.iter().find(|attr| attr.path.is_ident(name))
+    }
+}
+
 impl IntoIterator for Fields {
     type Item = Field;
     type IntoIter = punctuated::IntoIter<Field>;
@@ -315,9 +341,12 @@ pub mod parsing {
                 let mut content;
                 parenthesized!(content in ahead);
 
-                if content.peek(Token![crate])
+                // A path like `crate::A` is the parenthesized type of a tuple
+                // struct field, as in `struct S(pub (crate::A, B));`.
+                if (content.peek(Token![crate])
                     || content.peek(Token![self])
-                    || content.peek(Token![super])
+                    || content.peek(Token![super]))
+                    && !content.peek2(Token![::])
                 {
                     return Ok(Visibility::Restricted(VisRestricted {
                         pub_token,
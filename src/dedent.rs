@@ -0,0 +1,219 @@
+use std::mem;
+
+use proc_macro2::{Group, Literal, TokenStream, TokenTree};
+
+use crate::visit_mut::VisitMut;
+use crate::*;
+
+/// Removes the common leading indentation from every line of the string
+/// literals in a file that satisfy the predicate.
+///
+/// The indentation removed from a literal is the longest run of spaces and
+/// tabs that every line containing something other than whitespace begins
+/// with. Lines containing nothing but whitespace do not count toward the
+/// common indentation and are emptied. The line structure is otherwise
+/// unchanged, including leading and trailing newlines. A literal that would
+/// not change is left exactly as it was written.
+///
+/// The predicate is called with each string literal in the file, in the order
+/// they appear. As well as string literals in expressions and patterns, this
+/// includes those in the tokens of attributes and macro invocations, such as
+/// `#[doc = "..."]` and `println!("...")`. A doc comment is passed to the
+/// predicate as the string literal it stands for, without its `///` or `//!`
+/// marker, and is printed back as a `#[doc = "..."]` attribute if it is
+/// changed. Byte strings are never modified.
+///
+/// A changed literal keeps its span, its suffix if any, and its form. A raw
+/// string keeps its `r#"..."#` delimiters unchanged, which remain valid since
+/// removing indentation never introduces a `"` followed by a `#`. A string
+/// with escapes is escaped again after its contents are dedented, and keeps
+/// writing newlines as line breaks if that is how it was written or as `\n`
+/// escapes otherwise. Other escapes are normalized in the process, so a
+/// character written as `\x41` is written as `A`, and a `\` at the end of a
+/// line is replaced by the text it stands for.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"visit-mut"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{dedent_str_lits, parse_quote, Expr, File, Item, Lit};
+///
+/// let mut file: File = parse_quote! {
+///     const HELP: &str = "
+///         Usage: tool [options]
+///
+///         Options:
+///             -h  Print this message
+///     ";
+/// };
+///
+/// dedent_str_lits(&mut file, |lit| lit.value().starts_with('\n'));
+///
+/// let value = match &file.items[0] {
+///     Item::Const(item) => match &*item.expr {
+///         Expr::Lit(expr) => match &expr.lit {
+///             Lit::Str(lit) => lit.value(),
+///             _ => unreachable!(),
+///         },
+///         _ => unreachable!(),
+///     },
+///     _ => unreachable!(),
+/// };
+///
+/// let expected = "\nUsage: tool [options]\n\nOptions:\n    -h  Print this message\n";
+/// assert_eq!(value, expected);
+/// ```
+pub fn dedent_str_lits<F>(file: &mut File, predicate: F)
+where
+    F: FnMut(&LitStr) -> bool,
+{
+    DedentStrLits { predicate }.visit_file_mut(file);
+}
+
+struct DedentStrLits<F> {
+    predicate: F,
+}
+
+impl<F> DedentStrLits<F>
+where
+    F: FnMut(&LitStr) -> bool,
+{
+    fn dedent(&mut self, lit: &LitStr) -> Option<LitStr> {
+        if !(self.predicate)(lit) {
+            return None;
+        }
+        let value = lit.value();
+        let dedented = dedent(&value)?;
+
+        let repr = lit.token().to_string();
+        let content = if repr.starts_with('r') {
+            dedented
+        } else {
+            escape(&dedented, repr.contains('\n'))
+        };
+        let (prefix, suffix) = delimiters(&repr, lit.suffix());
+        let repr = format!("{}{}{}", prefix, content, suffix);
+
+        let mut token = match repr.parse::<TokenStream>().unwrap().into_iter().next() {
+            Some(TokenTree::Literal(token)) => token,
+            _ => unreachable!(),
+        };
+        token.set_span(lit.span());
+        match Lit::new(token) {
+            Lit::Str(lit) => Some(lit),
+            _ => unreachable!(),
+        }
+    }
+
+    fn dedent_tokens(&mut self, tokens: TokenStream) -> TokenStream {
+        tokens
+            .into_iter()
+            .map(|token| match token {
+                TokenTree::Literal(literal) => TokenTree::Literal(self.dedent_literal(literal)),
+                TokenTree::Group(group) => {
+                    let stream = self.dedent_tokens(group.stream());
+                    let mut new = Group::new(group.delimiter(), stream);
+                    new.set_span(group.span());
+                    TokenTree::Group(new)
+                }
+                token => token,
+            })
+            .collect()
+    }
+
+    fn dedent_literal(&mut self, literal: Literal) -> Literal {
+        match Lit::new(literal.clone()) {
+            Lit::Str(lit) => match self.dedent(&lit) {
+                Some(lit) => lit.token().clone(),
+                None => literal,
+            },
+            _ => literal,
+        }
+    }
+}
+
+impl<F> VisitMut for DedentStrLits<F>
+where
+    F: FnMut(&LitStr) -> bool,
+{
+    fn visit_lit_str_mut(&mut self, node: &mut LitStr) {
+        if let Some(lit) = self.dedent(node) {
+            *node = lit;
+        }
+    }
+
+    fn visit_token_stream_mut(&mut self, node: &mut TokenStream) {
+        let tokens = mem::replace(node, TokenStream::new());
+        *node = self.dedent_tokens(tokens);
+    }
+}
+
+// Returns the value with its common indentation removed, or None if that
+// leaves it unchanged.
+fn dedent(value: &str) -> Option<String> {
+    let mut margin: Option<&str> = None;
+    for line in value.split('\n') {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = indentation(line);
+        margin = Some(match margin {
+            Some(margin) => common_prefix(margin, indent),
+            None => indent,
+        });
+    }
+
+    let margin = margin.unwrap_or("");
+    let lines: Vec<&str> = value
+        .split('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                ""
+            } else {
+                &line[margin.len()..]
+            }
+        })
+        .collect();
+    let dedented = lines.join("\n");
+    if dedented == value {
+        None
+    } else {
+        Some(dedented)
+    }
+}
+
+fn indentation(line: &str) -> &str {
+    let content = line.trim_start_matches(|c| c == ' ' || c == '\t');
+    &line[..line.len() - content.len()]
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a.bytes().zip(b.bytes()).take_while(|(a, b)| a == b).count();
+    &a[..len]
+}
+
+fn escape(value: &str, keep_newlines: bool) -> String {
+    let mut escaped = String::new();
+    for ch in value.chars() {
+        if ch == '\n' && keep_newlines {
+            escaped.push('\n');
+        } else if ch == '\'' {
+            escaped.push(ch);
+        } else {
+            escaped.extend(ch.escape_debug());
+        }
+    }
+    escaped
+}
+
+// The text of the literal before and after its content, like `r#"` and `"#`,
+// the latter including the suffix.
+fn delimiters<'a>(repr: &'a str, suffix: &str) -> (&'a str, &'a str) {
+    let unsuffixed = &repr[..repr.len() - suffix.len()];
+    let hashes = unsuffixed.len() - unsuffixed.trim_end_matches('#').len();
+    let open = repr.find('"').unwrap() + 1;
+    let close = unsuffixed.len() - hashes - 1;
+    (&repr[..open], &repr[close..])
+}
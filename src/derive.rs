@@ -85,6 +85,77 @@ ast_struct! {
     }
 }
 
+impl DeriveInput {
+    /// Returns an iterator over the fields of the input, in order.
+    ///
+    /// For a struct or union this is its fields. For an enum this is the
+    /// fields of every variant one after another, so the same traversal works
+    /// for a derive that only needs to look at every field, such as to add a
+    /// trait bound for each field type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, DeriveInput};
+    ///
+    /// let input: DeriveInput = parse_quote! {
+    ///     enum Shape {
+    ///         Circle { radius: f64 },
+    ///         Rect(f64, f64),
+    ///         Empty,
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(input.fields().count(), 3);
+    /// ```
+    pub fn fields(&self) -> DeriveFields<'_> {
+        match &self.data {
+            Data::Struct(data) => DeriveFields {
+                fields: data.fields.iter(),
+                variants: crate::punctuated::empty_punctuated_iter(),
+            },
+            Data::Enum(data) => DeriveFields {
+                fields: crate::punctuated::empty_punctuated_iter(),
+                variants: data.variants.iter(),
+            },
+            Data::Union(data) => DeriveFields {
+                fields: data.fields.named.iter(),
+                variants: crate::punctuated::empty_punctuated_iter(),
+            },
+        }
+    }
+
+    /// Returns the variants of the input if it is an enum, or `None` if it is
+    /// a struct or union.
+    pub fn variants(&self) -> Option<&Punctuated<Variant, Token![,]>> {
+        match &self.data {
+            Data::Enum(data) => Some(&data.variants),
+            Data::Struct(_) | Data::Union(_) => None,
+        }
+    }
+}
+
+/// Returned by [`DeriveInput::fields`].
+///
+/// *This type is available if Syn is built with the `"derive"` feature.*
+pub struct DeriveFields<'a> {
+    fields: punctuated::Iter<'a, Field>,
+    variants: punctuated::Iter<'a, Variant>,
+}
+
+impl<'a> Iterator for DeriveFields<'a> {
+    type Item = &'a Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(field) = self.fields.next() {
+                return Some(field);
+            }
+            self.fields = self.variants.next()?.fields.iter();
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+
+use crate::expr::Reserved;
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// Lowers every `for` loop in an expression into the `loop` and `match` it is
+/// defined in terms of.
+///
+/// A loop written as `'label: for pat in expr { body }` becomes:
+///
+/// ```text
+/// match ::core::iter::IntoIterator::into_iter(expr) {
+///     mut __iter0 => 'label: loop {
+///         match ::core::iter::Iterator::next(&mut __iter0) {
+///             ::core::option::Option::Some(pat) => { body }
+///             ::core::option::Option::None => break,
+///         }
+///     }
+/// }
+/// ```
+///
+/// The label of the `for` loop, if any, is moved to the generated `loop`, so
+/// that `break 'label` and `continue 'label` in the body still refer to it,
+/// and so do unlabeled `break` and `continue`, since the generated `loop` is
+/// the innermost loop around the body. Outer attributes of the `for` loop are
+/// moved to the `match` and inner attributes stay at the top of the body.
+///
+/// Each loop binds its iterator to a fresh identifier, named `__iter0`,
+/// `__iter1`, and so on in the order the loops appear, skipping any name that
+/// is already used as an identifier anywhere in the expression, including
+/// inside of macro invocations, so the binding never shadows a variable the
+/// body refers to. Nested loops are lowered from the inside out. The traits
+/// and `Option` are named through `::core` paths so that they are not
+/// affected by items of the same name in scope. In the 2015 edition this
+/// requires an `extern crate core;` at the crate root.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{desugar_for, parse_quote, Expr};
+///
+/// let expr: Expr = parse_quote! {
+///     'outer: for x in v {
+///         if x == 0 {
+///             continue 'outer;
+///         }
+///         println!("{}", x);
+///     }
+/// };
+///
+/// let expected = quote! {
+///     match ::core::iter::IntoIterator::into_iter(v) {
+///         mut __iter0 => 'outer: loop {
+///             match ::core::iter::Iterator::next(&mut __iter0) {
+///                 ::core::option::Option::Some(x) => {
+///                     if x == 0 {
+///                         continue 'outer;
+///                     }
+///                     println!("{}", x);
+///                 }
+///                 ::core::option::Option::None => break,
+///             }
+///         }
+///     }
+/// };
+///
+/// let expr = desugar_for(expr);
+/// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+/// ```
+pub fn desugar_for(expr: Expr) -> Expr {
+    let mut idents = CollectIdents(HashSet::new());
+    let expr = idents.fold_expr(expr);
+
+    let mut desugar = DesugarFor {
+        used: idents.0,
+        next: 0,
+    };
+    desugar.fold_expr(expr)
+}
+
+struct CollectIdents(HashSet<String>);
+
+impl CollectIdents {
+    fn scan_tokens(&mut self, tokens: &TokenStream) {
+        for token in tokens.clone() {
+            match token {
+                TokenTree::Ident(ident) => {
+                    self.0.insert(ident.to_string());
+                }
+                TokenTree::Group(group) => self.scan_tokens(&group.stream()),
+                TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+            }
+        }
+    }
+}
+
+impl Fold for CollectIdents {
+    fn fold_ident(&mut self, node: Ident) -> Ident {
+        self.0.insert(node.to_string());
+        node
+    }
+
+    fn fold_macro(&mut self, node: Macro) -> Macro {
+        self.scan_tokens(&node.tokens);
+        fold::fold_macro(self, node)
+    }
+
+    fn fold_attribute(&mut self, node: Attribute) -> Attribute {
+        self.scan_tokens(&node.tokens);
+        fold::fold_attribute(self, node)
+    }
+}
+
+struct DesugarFor {
+    used: HashSet<String>,
+    next: usize,
+}
+
+impl DesugarFor {
+    fn fresh_ident(&mut self, span: Span) -> Ident {
+        loop {
+            let name = format!("__iter{}", self.next);
+            self.next += 1;
+            if !self.used.contains(&name) {
+                return Ident::new(&name, span);
+            }
+        }
+    }
+
+    fn desugar(&mut self, node: ExprForLoop) -> Expr {
+        let (outer, inner): (Vec<Attribute>, Vec<Attribute>) =
+            node.attrs.into_iter().partition(|attr| match attr.style {
+                AttrStyle::Outer => true,
+                AttrStyle::Inner(_) => false,
+            });
+
+        let iter = self.fresh_ident(node.for_token.span);
+        let body = Expr::Block(ExprBlock {
+            attrs: inner,
+            label: None,
+            block: node.body,
+        });
+
+        // ::core::option::Option::Some(pat) => { body }
+        let some_arm = Arm {
+            attrs: Vec::new(),
+            pat: Pat::TupleStruct(PatTupleStruct {
+                attrs: Vec::new(),
+                path: core_path(&["option", "Option", "Some"]),
+                pat: PatTuple {
+                    attrs: Vec::new(),
+                    paren_token: token::Paren::default(),
+                    elems: Some(node.pat).into_iter().collect(),
+                },
+            }),
+            guard: None,
+            fat_arrow_token: <Token![=>]>::default(),
+            body: Box::new(body),
+            comma: None,
+        };
+
+        // ::core::option::Option::None => break,
+        let none_arm = Arm {
+            attrs: Vec::new(),
+            pat: Pat::Path(PatPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: core_path(&["option", "Option", "None"]),
+            }),
+            guard: None,
+            fat_arrow_token: <Token![=>]>::default(),
+            body: Box::new(Expr::Break(ExprBreak {
+                attrs: Vec::new(),
+                break_token: <Token![break]>::default(),
+                label: None,
+                expr: None,
+            })),
+            comma: Some(<Token![,]>::default()),
+        };
+
+        // match ::core::iter::Iterator::next(&mut iter) { ... }
+        let next = Expr::call(
+            Expr::path(core_path(&["iter", "Iterator", "next"])),
+            vec![Expr::Reference(ExprReference {
+                attrs: Vec::new(),
+                and_token: <Token![&]>::default(),
+                raw: Reserved::default(),
+                mutability: Some(<Token![mut]>::default()),
+                expr: Box::new(Expr::path(iter.clone())),
+            })],
+        );
+        let step = Expr::Match(ExprMatch {
+            attrs: Vec::new(),
+            match_token: <Token![match]>::default(),
+            expr: Box::new(next),
+            brace_token: token::Brace::default(),
+            arms: vec![some_arm, none_arm],
+        });
+
+        // mut iter => 'label: loop { ... }
+        let iter_arm = Arm {
+            attrs: Vec::new(),
+            pat: Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: Some(<Token![mut]>::default()),
+                ident: iter,
+                subpat: None,
+            }),
+            guard: None,
+            fat_arrow_token: <Token![=>]>::default(),
+            body: Box::new(Expr::Loop(ExprLoop {
+                attrs: Vec::new(),
+                label: node.label,
+                loop_token: <Token![loop]>::default(),
+                body: Block {
+                    brace_token: token::Brace::default(),
+                    stmts: vec![Stmt::Expr(step)],
+                },
+            })),
+            comma: None,
+        };
+
+        // match ::core::iter::IntoIterator::into_iter(expr) { ... }
+        let into_iter = Expr::call(
+            Expr::path(core_path(&["iter", "IntoIterator", "into_iter"])),
+            vec![*node.expr],
+        );
+        Expr::Match(ExprMatch {
+            attrs: outer,
+            match_token: <Token![match]>::default(),
+            expr: Box::new(into_iter),
+            brace_token: token::Brace::default(),
+            arms: vec![iter_arm],
+        })
+    }
+}
+
+// Builds a global path to an item of the core crate, like
+// `::core::option::Option::Some`.
+fn core_path(segments: &[&str]) -> Path {
+    let span = Span::call_site();
+    Path {
+        leading_colon: Some(<Token![::]>::default()),
+        segments: Some("core")
+            .into_iter()
+            .chain(segments.iter().cloned())
+            .map(|segment| PathSegment::from(Ident::new(segment, span)))
+            .collect(),
+    }
+}
+
+impl Fold for DesugarFor {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        match fold::fold_expr(self, node) {
+            Expr::ForLoop(node) => self.desugar(node),
+            other => other,
+        }
+    }
+}
@@ -261,3 +261,152 @@ impl From<LexError> for Error {
         Error::new(Span::call_site(), format!("{:?}", err))
     }
 }
+
+/// A collection of [`Error`]s to be reported together.
+///
+/// This is useful for code that keeps going after the first error in order to
+/// report as many problems as possible in one pass, such as a procedural macro
+/// validating every field of a struct. The errors can be propagated as a
+/// single value and then either rendered all at once with
+/// [`to_compile_error`] or iterated over individually.
+///
+/// [`to_compile_error`]: Errors::to_compile_error
+///
+/// # Example
+///
+/// ```
+/// use syn::{Error, Errors, Field, Result};
+///
+/// fn check_field(field: &Field) -> Result<()> {
+///     /* ... */
+///     # Ok(())
+/// }
+///
+/// fn check_fields(fields: &[Field]) -> std::result::Result<(), Errors> {
+///     let errors: Errors = fields
+///         .iter()
+///         .filter_map(|field| check_field(field).err())
+///         .collect();
+///
+///     if errors.is_empty() {
+///         Ok(())
+///     } else {
+///         Err(errors)
+///     }
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct Errors {
+    errors: Vec<Error>,
+}
+
+impl Errors {
+    /// Creates an empty collection of errors.
+    pub fn new() -> Self {
+        Errors { errors: Vec::new() }
+    }
+
+    /// Adds an error to the end of the collection.
+    pub fn push(&mut self, error: Error) {
+        self.errors.push(error);
+    }
+
+    /// Returns the number of errors in the collection.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns true if there are no errors in the collection.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns an iterator over the errors in the order they were added.
+    pub fn iter(&self) -> std::slice::Iter<'_, Error> {
+        self.errors.iter()
+    }
+
+    /// Render every error as an invocation of [`compile_error!`], one after
+    /// another.
+    ///
+    /// [`compile_error!`]: https://doc.rust-lang.org/std/macro.compile_error.html
+    pub fn to_compile_error(&self) -> TokenStream {
+        self.errors.iter().map(Error::to_compile_error).collect()
+    }
+}
+
+impl Debug for Errors {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_list().entries(&self.errors).finish()
+    }
+}
+
+impl Display for Errors {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str("\n")?;
+            }
+            Display::fmt(error, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Errors {
+    fn description(&self) -> &str {
+        "parse errors"
+    }
+}
+
+impl From<Error> for Errors {
+    fn from(error: Error) -> Self {
+        Errors {
+            errors: vec![error],
+        }
+    }
+}
+
+impl From<Vec<Error>> for Errors {
+    fn from(errors: Vec<Error>) -> Self {
+        Errors { errors }
+    }
+}
+
+impl From<Errors> for Vec<Error> {
+    fn from(errors: Errors) -> Self {
+        errors.errors
+    }
+}
+
+impl FromIterator<Error> for Errors {
+    fn from_iter<I: IntoIterator<Item = Error>>(iter: I) -> Self {
+        Errors {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Error> for Errors {
+    fn extend<I: IntoIterator<Item = Error>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
+
+impl IntoIterator for Errors {
+    type Item = Error;
+    type IntoIter = std::vec::IntoIter<Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Errors {
+    type Item = &'a Error;
+    type IntoIter = std::slice::Iter<'a, Error>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
@@ -2,10 +2,12 @@ use super::*;
 use crate::punctuated::Punctuated;
 #[cfg(feature = "extra-traits")]
 use crate::tt::TokenStreamHelper;
+#[cfg(all(feature = "visit", feature = "printing"))]
+use crate::visit::{self, Visit};
 use proc_macro2::{Span, TokenStream};
 #[cfg(feature = "extra-traits")]
 use std::hash::{Hash, Hasher};
-#[cfg(all(feature = "parsing", feature = "full"))]
+#[cfg(any(feature = "parsing", all(feature = "full", feature = "fold")))]
 use std::mem;
 
 ast_enum_of_structs! {
@@ -121,6 +123,9 @@ ast_enum_of_structs! {
         /// A closure expression: `|a, b| a + b`.
         Closure(ExprClosure),
 
+        /// An inline const block: `const { ... }`.
+        Const(ExprConst),
+
         /// A `continue`, with an optional label.
         Continue(ExprContinue),
 
@@ -181,6 +186,9 @@ ast_enum_of_structs! {
         /// A range expression: `1..2`, `1..`, `..2`, `1..=2`, `..=2`.
         Range(ExprRange),
 
+        /// A raw borrow of a place: `&raw const a` or `&raw mut a`.
+        RawAddr(ExprRawAddr),
+
         /// A referencing operation: `&a` or `&mut a`.
         Reference(ExprReference),
 
@@ -378,6 +386,17 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// An inline const block: `const { ... }`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct ExprConst #full {
+        pub attrs: Vec<Attribute>,
+        pub const_token: Token![const],
+        pub block: Block,
+    }
+}
+
 ast_struct! {
     /// A `continue`, with an optional label.
     ///
@@ -574,6 +593,31 @@ ast_struct! {
     }
 }
 
+impl ExprPath {
+    /// Determines whether this expression is the `self` value.
+    ///
+    /// Paths to other things, including the `Self` type and its associated
+    /// items as in `Self::new`, as well as qualified paths, are not matched.
+    /// A use of a field or method of `self`, like `self.x`, is a different
+    /// expression whose receiver is this one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ExprPath};
+    ///
+    /// let value: ExprPath = parse_quote!(self);
+    /// assert!(value.is_self_value());
+    ///
+    /// let ty: ExprPath = parse_quote!(Self);
+    /// assert!(!ty.is_self_value());
+    /// assert!(ty.path.is_self_type());
+    /// ```
+    pub fn is_self_value(&self) -> bool {
+        self.qself.is_none() && self.path.is_ident("self")
+    }
+}
+
 ast_struct! {
     /// A range expression: `1..2`, `1..`, `..2`, `1..=2`, `..=2`.
     ///
@@ -586,6 +630,19 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// A raw borrow of a place: `&raw const a` or `&raw mut a`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct ExprRawAddr #full {
+        pub attrs: Vec<Attribute>,
+        pub and_token: Token![&],
+        pub raw: Token![raw],
+        pub mutability: PointerMutability,
+        pub expr: Box<Expr>,
+    }
+}
+
 ast_struct! {
     /// A referencing operation: `&a` or `&mut a`.
     ///
@@ -751,6 +808,7 @@ impl PartialEq for Expr {
             (Expr::Call(this), Expr::Call(other)) => this == other,
             (Expr::Cast(this), Expr::Cast(other)) => this == other,
             (Expr::Closure(this), Expr::Closure(other)) => this == other,
+            (Expr::Const(this), Expr::Const(other)) => this == other,
             (Expr::Continue(this), Expr::Continue(other)) => this == other,
             (Expr::Field(this), Expr::Field(other)) => this == other,
             (Expr::ForLoop(this), Expr::ForLoop(other)) => this == other,
@@ -767,6 +825,7 @@ impl PartialEq for Expr {
             (Expr::Paren(this), Expr::Paren(other)) => this == other,
             (Expr::Path(this), Expr::Path(other)) => this == other,
             (Expr::Range(this), Expr::Range(other)) => this == other,
+            (Expr::RawAddr(this), Expr::RawAddr(other)) => this == other,
             (Expr::Reference(this), Expr::Reference(other)) => this == other,
             (Expr::Repeat(this), Expr::Repeat(other)) => this == other,
             (Expr::Return(this), Expr::Return(other)) => this == other,
@@ -842,120 +901,128 @@ impl Hash for Expr {
                 hash.write_u8(11);
                 expr.hash(hash);
             }
-            Expr::Continue(expr) => {
+            Expr::Const(expr) => {
                 hash.write_u8(12);
                 expr.hash(hash);
             }
-            Expr::Field(expr) => {
+            Expr::Continue(expr) => {
                 hash.write_u8(13);
                 expr.hash(hash);
             }
-            Expr::ForLoop(expr) => {
+            Expr::Field(expr) => {
                 hash.write_u8(14);
                 expr.hash(hash);
             }
-            Expr::Group(expr) => {
+            Expr::ForLoop(expr) => {
                 hash.write_u8(15);
                 expr.hash(hash);
             }
-            Expr::If(expr) => {
+            Expr::Group(expr) => {
                 hash.write_u8(16);
                 expr.hash(hash);
             }
-            Expr::InPlace(expr) => {
+            Expr::If(expr) => {
                 hash.write_u8(17);
                 expr.hash(hash);
             }
-            Expr::Index(expr) => {
+            Expr::InPlace(expr) => {
                 hash.write_u8(18);
                 expr.hash(hash);
             }
-            Expr::Let(expr) => {
+            Expr::Index(expr) => {
                 hash.write_u8(19);
                 expr.hash(hash);
             }
-            Expr::Lit(expr) => {
+            Expr::Let(expr) => {
                 hash.write_u8(20);
                 expr.hash(hash);
             }
-            Expr::Loop(expr) => {
+            Expr::Lit(expr) => {
                 hash.write_u8(21);
                 expr.hash(hash);
             }
-            Expr::Macro(expr) => {
+            Expr::Loop(expr) => {
                 hash.write_u8(22);
                 expr.hash(hash);
             }
-            Expr::Match(expr) => {
+            Expr::Macro(expr) => {
                 hash.write_u8(23);
                 expr.hash(hash);
             }
-            Expr::MethodCall(expr) => {
+            Expr::Match(expr) => {
                 hash.write_u8(24);
                 expr.hash(hash);
             }
-            Expr::Paren(expr) => {
+            Expr::MethodCall(expr) => {
                 hash.write_u8(25);
                 expr.hash(hash);
             }
-            Expr::Path(expr) => {
+            Expr::Paren(expr) => {
                 hash.write_u8(26);
                 expr.hash(hash);
             }
-            Expr::Range(expr) => {
+            Expr::Path(expr) => {
                 hash.write_u8(27);
                 expr.hash(hash);
             }
-            Expr::Reference(expr) => {
+            Expr::Range(expr) => {
                 hash.write_u8(28);
                 expr.hash(hash);
             }
-            Expr::Repeat(expr) => {
+            Expr::RawAddr(expr) => {
                 hash.write_u8(29);
                 expr.hash(hash);
             }
-            Expr::Return(expr) => {
+            Expr::Reference(expr) => {
                 hash.write_u8(30);
                 expr.hash(hash);
             }
-            Expr::Struct(expr) => {
+            Expr::Repeat(expr) => {
                 hash.write_u8(31);
                 expr.hash(hash);
             }
-            Expr::Try(expr) => {
+            Expr::Return(expr) => {
                 hash.write_u8(32);
                 expr.hash(hash);
             }
-            Expr::TryBlock(expr) => {
+            Expr::Struct(expr) => {
                 hash.write_u8(33);
                 expr.hash(hash);
             }
-            Expr::Tuple(expr) => {
+            Expr::Try(expr) => {
                 hash.write_u8(34);
                 expr.hash(hash);
             }
-            Expr::Type(expr) => {
+            Expr::TryBlock(expr) => {
                 hash.write_u8(35);
                 expr.hash(hash);
             }
-            Expr::Unary(expr) => {
+            Expr::Tuple(expr) => {
                 hash.write_u8(36);
                 expr.hash(hash);
             }
-            Expr::Unsafe(expr) => {
+            Expr::Type(expr) => {
                 hash.write_u8(37);
                 expr.hash(hash);
             }
-            Expr::Verbatim(expr) => {
+            Expr::Unary(expr) => {
                 hash.write_u8(38);
+                expr.hash(hash);
+            }
+            Expr::Unsafe(expr) => {
+                hash.write_u8(39);
+                expr.hash(hash);
+            }
+            Expr::Verbatim(expr) => {
+                hash.write_u8(40);
                 TokenStreamHelper(expr).hash(hash);
             }
             Expr::While(expr) => {
-                hash.write_u8(39);
+                hash.write_u8(41);
                 expr.hash(hash);
             }
             Expr::Yield(expr) => {
-                hash.write_u8(40);
+                hash.write_u8(42);
                 expr.hash(hash);
             }
             Expr::__Nonexhaustive => unreachable!(),
@@ -964,7 +1031,7 @@ impl Hash for Expr {
 }
 
 impl Expr {
-    #[cfg(all(feature = "parsing", feature = "full"))]
+    #[cfg(all(feature = "full", any(feature = "parsing", feature = "fold")))]
     pub(crate) fn replace_attrs(&mut self, new: Vec<Attribute>) -> Vec<Attribute> {
         match self {
             Expr::Box(ExprBox { attrs, .. })
@@ -985,6 +1052,7 @@ impl Expr {
             | Expr::Loop(ExprLoop { attrs, .. })
             | Expr::Match(ExprMatch { attrs, .. })
             | Expr::Closure(ExprClosure { attrs, .. })
+            | Expr::Const(ExprConst { attrs, .. })
             | Expr::Unsafe(ExprUnsafe { attrs, .. })
             | Expr::Block(ExprBlock { attrs, .. })
             | Expr::Assign(ExprAssign { attrs, .. })
@@ -992,6 +1060,7 @@ impl Expr {
             | Expr::Field(ExprField { attrs, .. })
             | Expr::Index(ExprIndex { attrs, .. })
             | Expr::Range(ExprRange { attrs, .. })
+            | Expr::RawAddr(ExprRawAddr { attrs, .. })
             | Expr::Path(ExprPath { attrs, .. })
             | Expr::Reference(ExprReference { attrs, .. })
             | Expr::Break(ExprBreak { attrs, .. })
@@ -1013,6 +1082,288 @@ impl Expr {
     }
 }
 
+impl Expr {
+    /// Constructs a function call expression `func(args...)`.
+    ///
+    /// If `func` would not parse back as the callee of a call when printed,
+    /// such as a binary, closure or field expression, it is wrapped in
+    /// parentheses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr, Path};
+    ///
+    /// let max: Path = parse_quote!(std::cmp::max);
+    /// let call = Expr::call(Expr::path(max), vec![parse_quote!(a), parse_quote!(b + 1)]);
+    ///
+    /// let expected = quote!(std::cmp::max(a, b + 1));
+    /// assert_eq!(quote!(#call).to_string(), expected.to_string());
+    /// ```
+    pub fn call<I>(func: Expr, args: I) -> Expr
+    where
+        I: IntoIterator<Item = Expr>,
+    {
+        Expr::Call(ExprCall {
+            attrs: Vec::new(),
+            func: callee_operand(func),
+            paren_token: token::Paren::default(),
+            args: args.into_iter().collect(),
+        })
+    }
+
+    /// Constructs a method call expression `receiver.method(args...)`.
+    ///
+    /// If `receiver` would not parse back as the receiver of a method call
+    /// when printed, such as a binary or reference expression or an integer
+    /// literal, it is wrapped in parentheses.
+    ///
+    /// *This function is available if Syn is built with the `"full"` feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr, Ident};
+    ///
+    /// let method = Ident::new("push", Span::call_site());
+    /// let call = Expr::method_call(parse_quote!(*vec), method, vec![parse_quote!(x)]);
+    ///
+    /// let expected = quote!((*vec).push(x));
+    /// assert_eq!(quote!(#call).to_string(), expected.to_string());
+    /// ```
+    #[cfg(feature = "full")]
+    pub fn method_call<I>(receiver: Expr, method: Ident, args: I) -> Expr
+    where
+        I: IntoIterator<Item = Expr>,
+    {
+        Expr::MethodCall(ExprMethodCall {
+            attrs: Vec::new(),
+            receiver: receiver_operand(receiver),
+            dot_token: <Token![.]>::default(),
+            method,
+            turbofish: None,
+            paren_token: token::Paren::default(),
+            args: args.into_iter().collect(),
+        })
+    }
+
+    /// Constructs a field access expression `base.member`, where the member
+    /// is either a named field or the index of an unnamed field.
+    ///
+    /// If `base` would not parse back as the base of a field access when
+    /// printed, such as a binary expression or an integer literal, it is
+    /// wrapped in parentheses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr};
+    ///
+    /// let field = Expr::field(parse_quote!(pair), 0);
+    ///
+    /// let expected = quote!(pair.0);
+    /// assert_eq!(quote!(#field).to_string(), expected.to_string());
+    /// ```
+    pub fn field<M>(base: Expr, member: M) -> Expr
+    where
+        M: Into<Member>,
+    {
+        Expr::Field(ExprField {
+            attrs: Vec::new(),
+            base: receiver_operand(base),
+            dot_token: <Token![.]>::default(),
+            member: member.into(),
+        })
+    }
+
+    /// Constructs a path expression such as `x` or `std::mem::swap`.
+    pub fn path<P>(path: P) -> Expr
+    where
+        P: Into<Path>,
+    {
+        Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: path.into(),
+        })
+    }
+
+    /// Checks that no comparisons are chained without parentheses, as in
+    /// `a < b < c`.
+    ///
+    /// Syn parses a chain of comparison operators left to right, so that
+    /// `a < b < c` is the comparison of `a < b` with `c`, but Rust rejects
+    /// any such chain because comparison operators are not associative. The
+    /// error spans the first chain found anywhere in the expression, which is
+    /// the outermost one. Comparisons with parentheses around one of them,
+    /// like `(a < b) == c`, are accepted.
+    ///
+    /// *This function is available if Syn is built with the `"visit"` and
+    /// `"printing"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Expr};
+    ///
+    /// let expr: Expr = parse_quote!(lo <= x < hi);
+    /// let err = expr.check_chained_comparison().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "comparison operators cannot be chained; \
+    ///      use `&&` to combine comparisons, as in `a < b && b < c`",
+    /// );
+    ///
+    /// let expr: Expr = parse_quote!(lo <= x && x < hi);
+    /// assert!(expr.check_chained_comparison().is_ok());
+    /// ```
+    #[cfg(all(feature = "visit", feature = "printing"))]
+    pub fn check_chained_comparison(&self) -> Result<()> {
+        let mut visitor = ChainedComparison(None);
+        visitor.visit_expr(self);
+        match visitor.0 {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(feature = "visit", feature = "printing"))]
+struct ChainedComparison(Option<Error>);
+
+#[cfg(all(feature = "visit", feature = "printing"))]
+impl<'ast> Visit<'ast> for ChainedComparison {
+    fn visit_expr_binary(&mut self, node: &'ast ExprBinary) {
+        if self.0.is_some() {
+            return;
+        }
+        if node.op.is_comparison() {
+            let operands = [&*node.left, &*node.right];
+            let chained = operands.iter().any(|operand| match operand {
+                Expr::Binary(operand) => operand.op.is_comparison(),
+                _ => false,
+            });
+            if chained {
+                self.0 = Some(Error::new_spanned(
+                    node,
+                    "comparison operators cannot be chained; \
+                     use `&&` to combine comparisons, as in `a < b && b < c`",
+                ));
+                return;
+            }
+        }
+        visit::visit_expr_binary(self, node);
+    }
+}
+
+// Wraps an expression in parentheses unless it can be printed directly to the
+// left of a postfix `(...)` or `.` without changing how it parses.
+pub(crate) fn postfix_operand(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Array(_)
+        | Expr::Await(_)
+        | Expr::Call(_)
+        | Expr::Field(_)
+        | Expr::Group(_)
+        | Expr::Index(_)
+        | Expr::Lit(_)
+        | Expr::Macro(_)
+        | Expr::MethodCall(_)
+        | Expr::Paren(_)
+        | Expr::Path(_)
+        | Expr::Repeat(_)
+        | Expr::Try(_)
+        | Expr::Tuple(_) => Box::new(expr),
+        _ => paren(expr),
+    }
+}
+
+// Like `postfix_operand` for the callee of a call. A field access would print
+// as `s.f(x)`, which parses as a method call.
+pub(crate) fn callee_operand(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Field(_) | Expr::MethodCall(_) => paren(expr),
+        _ => postfix_operand(expr),
+    }
+}
+
+// Like `postfix_operand` for the base of a field access or the receiver of a
+// method call. An integer literal would print as `1.0`, which lexes as a
+// float.
+pub(crate) fn receiver_operand(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(_), .. }) => paren(expr),
+        _ => postfix_operand(expr),
+    }
+}
+
+// Wraps an expression in parentheses unless it can be printed directly as the
+// operand of a unary operator or a reference without changing how it parses.
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+pub(crate) fn unary_operand(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Unary(_) | Expr::Reference(_) => Box::new(expr),
+        _ => postfix_operand(expr),
+    }
+}
+
+// Like `unary_operand` for the operand of a cast.
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+pub(crate) fn cast_operand(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Cast(_) => Box::new(expr),
+        _ => unary_operand(expr),
+    }
+}
+
+// Like `unary_operand` for the left operand of the binary operator `op`, or
+// its right operand if `right` is set. A binary operand needs to bind more
+// tightly than `op`, or as tightly on the left of a left-associative `op`. A
+// cast to the left of `<` or `<<` would parse as a type with generic
+// arguments.
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+pub(crate) fn binary_operand(expr: Expr, op: &BinOp, right: bool) -> Box<Expr> {
+    let base = Precedence::of(op);
+    let binds = match &expr {
+        Expr::Binary(binary) => {
+            let precedence = Precedence::of(&binary.op);
+            precedence > base || precedence == base && !right && base != Precedence::Compare
+        }
+        Expr::Cast(_) => match op {
+            BinOp::Lt(_) | BinOp::Shl(_) => right,
+            _ => true,
+        },
+        _ => return unary_operand(expr),
+    };
+    if binds {
+        Box::new(expr)
+    } else {
+        paren(expr)
+    }
+}
+
+// Like `unary_operand` for a bound of a range, which binds more loosely than
+// any binary operator.
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+pub(crate) fn range_operand(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Binary(_) | Expr::Cast(_) => Box::new(expr),
+        _ => unary_operand(expr),
+    }
+}
+
+fn paren(expr: Expr) -> Box<Expr> {
+    Box::new(Expr::Paren(ExprParen {
+        attrs: Vec::new(),
+        paren_token: token::Paren::default(),
+        expr: Box::new(expr),
+    }))
+}
+
 ast_enum! {
     /// A struct or tuple struct field accessed in a struct literal or field
     /// expression.
@@ -1048,6 +1399,24 @@ impl From<usize> for Index {
     }
 }
 
+impl From<Ident> for Member {
+    fn from(ident: Ident) -> Member {
+        Member::Named(ident)
+    }
+}
+
+impl From<Index> for Member {
+    fn from(index: Index) -> Member {
+        Member::Unnamed(index)
+    }
+}
+
+impl From<usize> for Member {
+    fn from(index: usize) -> Member {
+        Member::Unnamed(Index::from(index))
+    }
+}
+
 #[cfg(feature = "extra-traits")]
 impl Eq for Index {}
 
@@ -1180,6 +1549,19 @@ ast_enum! {
     }
 }
 
+#[cfg(feature = "full")]
+ast_enum! {
+    /// Mutability of a raw borrow: `const` in `&raw const a` or `mut` in
+    /// `&raw mut a`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    #[cfg_attr(feature = "clone-impls", derive(Copy))]
+    pub enum PointerMutability {
+        Const(Token![const]),
+        Mut(Token![mut]),
+    }
+}
+
 #[cfg(any(feature = "parsing", feature = "printing"))]
 #[cfg(feature = "full")]
 pub(crate) fn requires_terminator(expr: &Expr) -> bool {
@@ -1193,11 +1575,71 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
         | Expr::Loop(..)
         | Expr::ForLoop(..)
         | Expr::Async(..)
+        | Expr::Const(..)
         | Expr::TryBlock(..) => false,
         _ => true,
     }
 }
 
+// The binding strength of binary operators and of the other infix forms, from
+// loosest to tightest. Some of the levels are only used by the parser.
+#[cfg(any(
+    feature = "parsing",
+    all(feature = "full", feature = "fold", feature = "clone-impls")
+))]
+#[cfg_attr(not(feature = "parsing"), allow(dead_code))]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub(crate) enum Precedence {
+    Any,
+    Assign,
+    Range,
+    Or,
+    And,
+    Compare,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
+    Arithmetic,
+    Term,
+    Cast,
+}
+
+#[cfg(any(
+    feature = "parsing",
+    all(feature = "full", feature = "fold", feature = "clone-impls")
+))]
+impl Precedence {
+    pub(crate) fn of(op: &BinOp) -> Self {
+        match *op {
+            BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
+            BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
+            BinOp::And(_) => Precedence::And,
+            BinOp::Or(_) => Precedence::Or,
+            BinOp::BitXor(_) => Precedence::BitXor,
+            BinOp::BitAnd(_) => Precedence::BitAnd,
+            BinOp::BitOr(_) => Precedence::BitOr,
+            BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => Precedence::Compare,
+            BinOp::AddEq(_)
+            | BinOp::SubEq(_)
+            | BinOp::MulEq(_)
+            | BinOp::DivEq(_)
+            | BinOp::RemEq(_)
+            | BinOp::BitXorEq(_)
+            | BinOp::BitAndEq(_)
+            | BinOp::BitOrEq(_)
+            | BinOp::ShlEq(_)
+            | BinOp::ShrEq(_) => Precedence::Assign,
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
@@ -1213,54 +1655,6 @@ pub(crate) mod parsing {
     #[derive(Copy, Clone)]
     pub struct AllowStruct(bool);
 
-    #[derive(Copy, Clone, PartialEq, PartialOrd)]
-    enum Precedence {
-        Any,
-        Assign,
-        Range,
-        Or,
-        And,
-        Compare,
-        BitOr,
-        BitXor,
-        BitAnd,
-        Shift,
-        Arithmetic,
-        Term,
-        Cast,
-    }
-
-    impl Precedence {
-        fn of(op: &BinOp) -> Self {
-            match *op {
-                BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
-                BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
-                BinOp::And(_) => Precedence::And,
-                BinOp::Or(_) => Precedence::Or,
-                BinOp::BitXor(_) => Precedence::BitXor,
-                BinOp::BitAnd(_) => Precedence::BitAnd,
-                BinOp::BitOr(_) => Precedence::BitOr,
-                BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
-                BinOp::Eq(_)
-                | BinOp::Lt(_)
-                | BinOp::Le(_)
-                | BinOp::Ne(_)
-                | BinOp::Ge(_)
-                | BinOp::Gt(_) => Precedence::Compare,
-                BinOp::AddEq(_)
-                | BinOp::SubEq(_)
-                | BinOp::MulEq(_)
-                | BinOp::DivEq(_)
-                | BinOp::RemEq(_)
-                | BinOp::BitXorEq(_)
-                | BinOp::BitAndEq(_)
-                | BinOp::BitOrEq(_)
-                | BinOp::ShlEq(_)
-                | BinOp::ShrEq(_) => Precedence::Assign,
-            }
-        }
-    }
-
     impl Parse for Expr {
         fn parse(input: ParseStream) -> Result<Self> {
             ambiguous_expr(input, AllowStruct(true))
@@ -1468,13 +1862,26 @@ pub(crate) mod parsing {
         {
             let attrs = input.call(Attribute::parse_outer)?;
             if input.peek(Token![&]) {
-                Ok(Expr::Reference(ExprReference {
-                    attrs,
-                    and_token: input.parse()?,
-                    raw: Reserved::default(),
-                    mutability: input.parse()?,
-                    expr: Box::new(unary_expr(input, allow_struct)?),
-                }))
+                let and_token: Token![&] = input.parse()?;
+                if input.peek(Token![raw])
+                    && (input.peek2(Token![const]) || input.peek2(Token![mut]))
+                {
+                    Ok(Expr::RawAddr(ExprRawAddr {
+                        attrs,
+                        and_token,
+                        raw: input.parse()?,
+                        mutability: input.parse()?,
+                        expr: Box::new(unary_expr(input, allow_struct)?),
+                    }))
+                } else {
+                    Ok(Expr::Reference(ExprReference {
+                        attrs,
+                        and_token,
+                        raw: Reserved::default(),
+                        mutability: input.parse()?,
+                        expr: Box::new(unary_expr(input, allow_struct)?),
+                    }))
+                }
             } else if input.peek(Token![box]) {
                 Ok(Expr::Box(ExprBox {
                     attrs,
@@ -1544,7 +1951,14 @@ pub(crate) mod parsing {
                     args: content.parse_terminated(Expr::parse)?,
                 });
             } else if input.peek(Token![.]) && !input.peek(Token![..]) {
-                let dot_token: Token![.] = input.parse()?;
+                let mut dot_token: Token![.] = input.parse()?;
+
+                if input.peek(LitFloat) {
+                    let float: LitFloat = input.parse()?;
+                    if multi_index(&mut e, &mut dot_token, float)? {
+                        continue;
+                    }
+                }
 
                 if input.peek(token::Await) {
                     e = Expr::Await(ExprAwait {
@@ -1641,10 +2055,19 @@ pub(crate) mod parsing {
                 });
             } else if input.peek(Token![.]) && !input.peek(Token![..]) && !input.peek2(token::Await)
             {
+                let mut dot_token: Token![.] = input.parse()?;
+
+                if input.peek(LitFloat) {
+                    let float: LitFloat = input.parse()?;
+                    if multi_index(&mut e, &mut dot_token, float)? {
+                        continue;
+                    }
+                }
+
                 e = Expr::Field(ExprField {
                     attrs: Vec::new(),
                     base: Box::new(e),
-                    dot_token: input.parse()?,
+                    dot_token,
                     member: input.parse()?,
                 });
             } else if input.peek(token::Bracket) {
@@ -1663,6 +2086,37 @@ pub(crate) mod parsing {
         Ok(e)
     }
 
+    // The lexer turns the `0.1` in `x.0.1` into a single float literal. Expands
+    // it into the field accesses `.0` and `.1` on `e`. Returns false if the
+    // float ends in a dot, as in `x.0.`, which is left in `dot_token` for the
+    // caller to parse the following member.
+    fn multi_index(e: &mut Expr, dot_token: &mut Token![.], float: LitFloat) -> Result<bool> {
+        let mut float_repr = float.to_string();
+        let trailing_dot = float_repr.ends_with('.');
+        if trailing_dot {
+            float_repr.truncate(float_repr.len() - 1);
+        }
+        for part in float_repr.split('.') {
+            let digits = part.bytes().all(|b| b.is_ascii_digit());
+            let index = match part.parse::<u32>() {
+                Ok(index) if digits => index,
+                _ => return Err(Error::new(float.span(), "expected tuple field index")),
+            };
+            let base = mem::replace(e, Expr::Verbatim(TokenStream::new()));
+            *e = Expr::Field(ExprField {
+                attrs: Vec::new(),
+                base: Box::new(base),
+                dot_token: Token![.](dot_token.span),
+                member: Member::Unnamed(Index {
+                    index,
+                    span: float.span(),
+                }),
+            });
+            *dot_token = Token![.](float.span());
+        }
+        Ok(!trailing_dot)
+    }
+
     // Parse all atomic expressions which don't have to worry about precedence
     // interactions, as they are fully contained.
     #[cfg(feature = "full")]
@@ -1677,6 +2131,8 @@ pub(crate) mod parsing {
             input.call(expr_async).map(Expr::Async)
         } else if input.peek(Token![try]) && input.peek2(token::Brace) {
             input.call(expr_try_block).map(Expr::TryBlock)
+        } else if input.peek(Token![const]) && input.peek2(token::Brace) {
+            input.call(expr_const).map(Expr::Const)
         } else if input.peek(Token![|])
             || input.peek(Token![async]) && (input.peek2(Token![|]) || input.peek2(Token![move]))
             || input.peek(Token![static])
@@ -1912,6 +2368,8 @@ pub(crate) mod parsing {
             Expr::Match(input.parse()?)
         } else if input.peek(Token![try]) && input.peek2(token::Brace) {
             Expr::TryBlock(input.call(expr_try_block)?)
+        } else if input.peek(Token![const]) && input.peek2(token::Brace) {
+            Expr::Const(input.call(expr_const)?)
         } else if input.peek(Token![unsafe]) {
             Expr::Unsafe(input.call(expr_unsafe)?)
         } else if input.peek(token::Brace) {
@@ -2191,6 +2649,7 @@ pub(crate) mod parsing {
         ExprField, Field, "expected struct field access",
         ExprIndex, Index, "expected indexing expression",
         ExprRange, Range, "expected range expression",
+        ExprRawAddr, RawAddr, "expected raw borrow expression",
         ExprReference, Reference, "expected referencing operation",
         ExprBreak, Break, "expected break expression",
         ExprContinue, Continue, "expected continue expression",
@@ -2202,6 +2661,7 @@ pub(crate) mod parsing {
         ExprTry, Try, "expected try expression",
         ExprAsync, Async, "expected async block",
         ExprTryBlock, TryBlock, "expected try block",
+        ExprConst, Const, "expected inline const block",
         ExprYield, Yield, "expected yield expression",
     }
 
@@ -2214,6 +2674,17 @@ pub(crate) mod parsing {
         })
     }
 
+    // Also used for the `const { ... }` form of a const generic argument,
+    // which is parsed the same way as an inline const block in an expression.
+    #[cfg(feature = "full")]
+    pub(crate) fn expr_const(input: ParseStream) -> Result<ExprConst> {
+        Ok(ExprConst {
+            attrs: Vec::new(),
+            const_token: input.parse()?,
+            block: input.parse()?,
+        })
+    }
+
     #[cfg(feature = "full")]
     fn expr_yield(input: ParseStream) -> Result<ExprYield> {
         Ok(ExprYield {
@@ -2558,6 +3029,20 @@ pub(crate) mod parsing {
         })
     }
 
+    #[cfg(feature = "full")]
+    impl Parse for PointerMutability {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Token![const]) {
+                input.parse().map(PointerMutability::Const)
+            } else if lookahead.peek(Token![mut]) {
+                input.parse().map(PointerMutability::Mut)
+            } else {
+                Err(lookahead.error())
+            }
+        }
+    }
+
     #[cfg(feature = "full")]
     impl Parse for RangeLimits {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -2990,6 +3475,15 @@ pub(crate) mod printing {
         }
     }
 
+    #[cfg(feature = "full")]
+    impl ToTokens for ExprConst {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            outer_attrs_to_tokens(&self.attrs, tokens);
+            self.const_token.to_tokens(tokens);
+            self.block.to_tokens(tokens);
+        }
+    }
+
     #[cfg(feature = "full")]
     impl ToTokens for ExprYield {
         fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -3114,6 +3608,27 @@ pub(crate) mod printing {
         }
     }
 
+    #[cfg(feature = "full")]
+    impl ToTokens for ExprRawAddr {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            outer_attrs_to_tokens(&self.attrs, tokens);
+            self.and_token.to_tokens(tokens);
+            self.raw.to_tokens(tokens);
+            self.mutability.to_tokens(tokens);
+            self.expr.to_tokens(tokens);
+        }
+    }
+
+    #[cfg(feature = "full")]
+    impl ToTokens for PointerMutability {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                PointerMutability::Const(t) => t.to_tokens(tokens),
+                PointerMutability::Mut(t) => t.to_tokens(tokens),
+            }
+        }
+    }
+
     #[cfg(feature = "full")]
     impl ToTokens for ExprReference {
         fn to_tokens(&self, tokens: &mut TokenStream) {
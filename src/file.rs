@@ -74,6 +74,54 @@ ast_struct! {
     }
 }
 
+impl File {
+    /// Returns the names of the nightly language features enabled by
+    /// `#![feature(...)]` attributes at the top of the file, in the order they
+    /// are written.
+    ///
+    /// Every `feature` attribute is read, so `#![feature(a, b)]` and
+    /// `#![feature(a)] #![feature(b)]` both produce `a, b`. Features enabled
+    /// conditionally through `#![cfg_attr(..., feature(...))]` are not
+    /// included, and neither are outer `#[feature(...)]` attributes on items,
+    /// which are not valid. Entries that are not a plain identifier are
+    /// skipped.
+    ///
+    /// *This function is available if Syn is built with the `"parsing"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let file = syn::parse_file(
+    ///     "#![feature(test, proc_macro_hygiene)]\n\
+    ///      #![allow(dead_code)]\n\
+    ///      #![feature(never_type)]\n\
+    ///      fn main() {}",
+    /// )
+    /// .unwrap();
+    ///
+    /// let features = file.crate_features();
+    /// assert_eq!(features, ["test", "proc_macro_hygiene", "never_type"]);
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn crate_features(&self) -> Vec<Ident> {
+        let mut features = Vec::new();
+        for attr in &self.attrs {
+            let is_inner = match attr.style {
+                AttrStyle::Outer => false,
+                AttrStyle::Inner(_) => true,
+            };
+            if !is_inner || !attr.path.is_ident("feature") {
+                continue;
+            }
+            if let Ok(meta) = attr.parse_meta() {
+                features.extend(meta.nested_words().into_iter().cloned());
+            }
+        }
+        features
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+
+use crate::buffer::TokenBuffer;
+use crate::parse;
+use crate::{File, Result};
+
+/// A cache of the tokens of files that are parsed repeatedly.
+///
+/// Parsing a file with [`FileCache::parse_file`] behaves the same as
+/// [`parse_file`] except that the tokens lexed from the content are kept, so
+/// that parsing identical content again, for example a generated file that a
+/// build script parses many times, skips tokenizing it. Only the tokens are
+/// cached; the syntax tree is built anew on every call. Content that fails to
+/// tokenize is not cached.
+///
+/// The cache holds on to the content and tokens of every distinct file parsed
+/// through it until it is cleared or dropped, so its owner decides how long
+/// they are kept.
+///
+/// A cache must not be kept across invocations of a procedural macro, because
+/// the tokens it holds would be reused after the compiler has invalidated
+/// them.
+///
+/// [`FileCache::parse_file`]: #method.parse_file
+/// [`parse_file`]: fn.parse_file.html
+///
+/// *This type is available if Syn is built with the `"parsing"` and `"full"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{FileCache, Item};
+///
+/// let content = "fn main() {}";
+///
+/// let mut cache = FileCache::new();
+/// for _ in 0..3 {
+///     let file = cache.parse_file(content).unwrap();
+///     assert!(match file.items[0] {
+///         Item::Fn(_) => true,
+///         _ => false,
+///     });
+/// }
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Default)]
+pub struct FileCache {
+    tokens: HashMap<String, TokenBuffer>,
+}
+
+impl FileCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        FileCache {
+            tokens: HashMap::new(),
+        }
+    }
+
+    /// Parses the content of a file of Rust code, reusing the tokens of an
+    /// earlier call with the same content.
+    pub fn parse_file(&mut self, content: &str) -> Result<File> {
+        let (shebang, content) = crate::split_shebang(content);
+        if !self.tokens.contains_key(content) {
+            let tokens = TokenStream::from_str(content)?;
+            let buffer = TokenBuffer::new2(tokens);
+            self.tokens.insert(content.to_owned(), buffer);
+        }
+
+        let mut file: File = parse::parse_buffer(&self.tokens[content])?;
+        file.shebang = shebang;
+        Ok(file)
+    }
+
+    /// Returns the number of distinct files whose tokens are cached.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Returns true if no tokens are cached.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Removes the tokens of all files from the cache.
+    pub fn clear(&mut self) {
+        self.tokens.clear();
+    }
+}
@@ -0,0 +1,183 @@
+use quote::ToTokens;
+
+use crate::punctuated::Punctuated;
+use crate::visit::{self, Visit};
+use crate::*;
+
+/// A function found by [`collect_fns`].
+///
+/// *This type is available if Syn is built with the `"full"`, `"visit"`, and
+/// `"printing"` features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct FnInfo<'ast> {
+    /// The path of the function relative to the root of the file, such as
+    /// `a::b::Foo::new`.
+    pub path: String,
+    /// The generic parameters and where clause of the function itself, not
+    /// including those of an enclosing impl or trait.
+    pub generics: &'ast Generics,
+    /// The arguments of the function, including the receiver of a method.
+    pub inputs: &'ast Punctuated<FnArg, Token![,]>,
+    /// The return type of the function.
+    pub output: &'ast ReturnType,
+}
+
+/// Collects every function and method declared in a file, in the order they
+/// appear.
+///
+/// This includes free functions, functions in foreign blocks, and methods in
+/// impls and traits, whether or not they have a body. The path of each one is
+/// made up of the names of the modules it is nested in followed by:
+///
+/// - for a function in an inherent impl, the name of the type, as in
+///   `Foo::new` for `impl<T> Foo<T> { fn new() }`;
+/// - for a method in a trait impl, the type and the trait, as in `<Foo as
+///   Display>::fmt`;
+/// - for a method in a trait definition, the name of the trait, as in
+///   `Iterator::next`.
+///
+/// Generic arguments are left out of the names of types and traits. A self
+/// type that is not a path, like `&T` or `[T]`, appears in the path as the
+/// string form of its tokens.
+///
+/// Functions that are nested inside the body of another function or inside
+/// of any other expression, like the initializer of a `const`, are not
+/// collected since they cannot be named from outside of it. Neither are
+/// functions in modules declared with `mod m;`, whose contents are in a
+/// different file.
+///
+/// *This function is available if Syn is built with the `"full"`, `"visit"`,
+/// and `"printing"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{collect_fns, parse_quote, File};
+///
+/// let file: File = parse_quote! {
+///     fn main() {}
+///
+///     mod shapes {
+///         pub struct Circle(f64);
+///
+///         impl Circle {
+///             pub fn new(r: f64) -> Self { Circle(r) }
+///         }
+///
+///         impl Default for Circle {
+///             fn default() -> Self { Circle(1.0) }
+///         }
+///     }
+/// };
+///
+/// let paths: Vec<String> = collect_fns(&file)
+///     .into_iter()
+///     .map(|info| info.path)
+///     .collect();
+///
+/// assert_eq!(
+///     paths,
+///     ["main", "shapes::Circle::new", "shapes::<Circle as Default>::default"],
+/// );
+/// ```
+pub fn collect_fns(file: &File) -> Vec<FnInfo<'_>> {
+    let mut visitor = CollectFns {
+        scope: Vec::new(),
+        fns: Vec::new(),
+    };
+    visitor.visit_file(file);
+    visitor.fns
+}
+
+struct CollectFns<'ast> {
+    // Names of the enclosing modules, impls, and traits.
+    scope: Vec<String>,
+    fns: Vec<FnInfo<'ast>>,
+}
+
+impl<'ast> CollectFns<'ast> {
+    fn push(&mut self, sig: &'ast Signature) {
+        let mut path = String::new();
+        for name in &self.scope {
+            path += name;
+            path += "::";
+        }
+        path += &sig.ident.to_string();
+
+        self.fns.push(FnInfo {
+            path,
+            generics: &sig.generics,
+            inputs: &sig.inputs,
+            output: &sig.output,
+        });
+    }
+
+    fn nested<F: FnOnce(&mut Self)>(&mut self, name: String, f: F) {
+        self.scope.push(name);
+        f(self);
+        self.scope.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for CollectFns<'ast> {
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        self.nested(node.ident.to_string(), |this| {
+            visit::visit_item_mod(this, node);
+        });
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let name = match &node.trait_ {
+            Some((_, path, _)) => format!("<{} as {}>", type_name(&node.self_ty), path_name(path)),
+            None => type_name(&node.self_ty),
+        };
+        self.nested(name, |this| visit::visit_item_impl(this, node));
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        self.nested(node.ident.to_string(), |this| {
+            visit::visit_item_trait(this, node);
+        });
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.push(&node.sig);
+    }
+
+    fn visit_impl_item_method(&mut self, node: &'ast ImplItemMethod) {
+        self.push(&node.sig);
+    }
+
+    fn visit_trait_item_method(&mut self, node: &'ast TraitItemMethod) {
+        self.push(&node.sig);
+    }
+
+    fn visit_foreign_item_fn(&mut self, node: &'ast ForeignItemFn) {
+        self.push(&node.sig);
+    }
+
+    fn visit_expr(&mut self, _node: &'ast Expr) {
+        // Items inside of an expression cannot be named from outside of it.
+    }
+}
+
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => path_name(&ty.path),
+        Type::Group(ty) => type_name(&ty.elem),
+        Type::Paren(ty) => type_name(&ty.elem),
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+fn path_name(path: &Path) -> String {
+    let mut name = String::new();
+    for segment in &path.segments {
+        if !name.is_empty() {
+            name += "::";
+        }
+        name += &segment.ident.to_string();
+    }
+    name
+}
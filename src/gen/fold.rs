@@ -7,7 +7,7 @@ use crate::gen::helper::fold::*;
 #[cfg(any(feature = "full", feature = "derive"))]
 use crate::token::{Brace, Bracket, Group, Paren};
 use crate::*;
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 #[cfg(feature = "full")]
 macro_rules! full {
     ($e:expr) => {
@@ -156,6 +156,10 @@ pub trait Fold {
         fold_expr_closure(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_expr_const(&mut self, i: ExprConst) -> ExprConst {
+        fold_expr_const(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_expr_continue(&mut self, i: ExprContinue) -> ExprContinue {
         fold_expr_continue(self, i)
     }
@@ -220,6 +224,10 @@ pub trait Fold {
         fold_expr_range(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_expr_raw_addr(&mut self, i: ExprRawAddr) -> ExprRawAddr {
+        fold_expr_raw_addr(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_expr_reference(&mut self, i: ExprReference) -> ExprReference {
         fold_expr_reference(self, i)
     }
@@ -600,6 +608,10 @@ pub trait Fold {
     fn fold_path_segment(&mut self, i: PathSegment) -> PathSegment {
         fold_path_segment(self, i)
     }
+    #[cfg(feature = "full")]
+    fn fold_pointer_mutability(&mut self, i: PointerMutability) -> PointerMutability {
+        fold_pointer_mutability(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_predicate_eq(&mut self, i: PredicateEq) -> PredicateEq {
         fold_predicate_eq(self, i)
@@ -639,6 +651,9 @@ pub trait Fold {
     fn fold_stmt(&mut self, i: Stmt) -> Stmt {
         fold_stmt(self, i)
     }
+    fn fold_token_stream(&mut self, i: TokenStream) -> TokenStream {
+        fold_token_stream(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_trait_bound(&mut self, i: TraitBound) -> TraitBound {
         fold_trait_bound(self, i)
@@ -862,7 +877,7 @@ where
         style: f.fold_attr_style(node.style),
         bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)),
         path: f.fold_path(node.path),
-        tokens: node.tokens,
+        tokens: f.fold_token_stream(node.tokens),
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -1088,6 +1103,7 @@ where
         Expr::Call(_binding_0) => Expr::Call(f.fold_expr_call(_binding_0)),
         Expr::Cast(_binding_0) => Expr::Cast(f.fold_expr_cast(_binding_0)),
         Expr::Closure(_binding_0) => Expr::Closure(full!(f.fold_expr_closure(_binding_0))),
+        Expr::Const(_binding_0) => Expr::Const(full!(f.fold_expr_const(_binding_0))),
         Expr::Continue(_binding_0) => Expr::Continue(full!(f.fold_expr_continue(_binding_0))),
         Expr::Field(_binding_0) => Expr::Field(f.fold_expr_field(_binding_0)),
         Expr::ForLoop(_binding_0) => Expr::ForLoop(full!(f.fold_expr_for_loop(_binding_0))),
@@ -1106,6 +1122,7 @@ where
         Expr::Paren(_binding_0) => Expr::Paren(f.fold_expr_paren(_binding_0)),
         Expr::Path(_binding_0) => Expr::Path(f.fold_expr_path(_binding_0)),
         Expr::Range(_binding_0) => Expr::Range(full!(f.fold_expr_range(_binding_0))),
+        Expr::RawAddr(_binding_0) => Expr::RawAddr(full!(f.fold_expr_raw_addr(_binding_0))),
         Expr::Reference(_binding_0) => Expr::Reference(full!(f.fold_expr_reference(_binding_0))),
         Expr::Repeat(_binding_0) => Expr::Repeat(full!(f.fold_expr_repeat(_binding_0))),
         Expr::Return(_binding_0) => Expr::Return(full!(f.fold_expr_return(_binding_0))),
@@ -1116,7 +1133,7 @@ where
         Expr::Type(_binding_0) => Expr::Type(full!(f.fold_expr_type(_binding_0))),
         Expr::Unary(_binding_0) => Expr::Unary(f.fold_expr_unary(_binding_0)),
         Expr::Unsafe(_binding_0) => Expr::Unsafe(full!(f.fold_expr_unsafe(_binding_0))),
-        Expr::Verbatim(_binding_0) => Expr::Verbatim(_binding_0),
+        Expr::Verbatim(_binding_0) => Expr::Verbatim(f.fold_token_stream(_binding_0)),
         Expr::While(_binding_0) => Expr::While(full!(f.fold_expr_while(_binding_0))),
         Expr::Yield(_binding_0) => Expr::Yield(full!(f.fold_expr_yield(_binding_0))),
         _ => unreachable!(),
@@ -1269,6 +1286,17 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_expr_const<F>(f: &mut F, node: ExprConst) -> ExprConst
+where
+    F: Fold + ?Sized,
+{
+    ExprConst {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)),
+        block: f.fold_block(node.block),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_expr_continue<F>(f: &mut F, node: ExprContinue) -> ExprContinue
 where
     F: Fold + ?Sized,
@@ -1467,6 +1495,19 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_expr_raw_addr<F>(f: &mut F, node: ExprRawAddr) -> ExprRawAddr
+where
+    F: Fold + ?Sized,
+{
+    ExprRawAddr {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        and_token: Token ! [ & ](tokens_helper(f, &node.and_token.spans)),
+        raw: Token![raw](tokens_helper(f, &node.raw.span)),
+        mutability: f.fold_pointer_mutability(node.mutability),
+        expr: Box::new(f.fold_expr(*node.expr)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_expr_reference<F>(f: &mut F, node: ExprReference) -> ExprReference
 where
     F: Fold + ?Sized,
@@ -1709,7 +1750,7 @@ where
         }
         ForeignItem::Type(_binding_0) => ForeignItem::Type(f.fold_foreign_item_type(_binding_0)),
         ForeignItem::Macro(_binding_0) => ForeignItem::Macro(f.fold_foreign_item_macro(_binding_0)),
-        ForeignItem::Verbatim(_binding_0) => ForeignItem::Verbatim(_binding_0),
+        ForeignItem::Verbatim(_binding_0) => ForeignItem::Verbatim(f.fold_token_stream(_binding_0)),
         _ => unreachable!(),
     }
 }
@@ -1848,7 +1889,7 @@ where
             ImplItem::Existential(f.fold_impl_item_existential(_binding_0))
         }
         ImplItem::Macro(_binding_0) => ImplItem::Macro(f.fold_impl_item_macro(_binding_0)),
-        ImplItem::Verbatim(_binding_0) => ImplItem::Verbatim(_binding_0),
+        ImplItem::Verbatim(_binding_0) => ImplItem::Verbatim(f.fold_token_stream(_binding_0)),
         _ => unreachable!(),
     }
 }
@@ -1960,7 +2001,7 @@ where
         Item::Type(_binding_0) => Item::Type(f.fold_item_type(_binding_0)),
         Item::Union(_binding_0) => Item::Union(f.fold_item_union(_binding_0)),
         Item::Use(_binding_0) => Item::Use(f.fold_item_use(_binding_0)),
-        Item::Verbatim(_binding_0) => Item::Verbatim(_binding_0),
+        Item::Verbatim(_binding_0) => Item::Verbatim(f.fold_token_stream(_binding_0)),
         _ => unreachable!(),
     }
 }
@@ -2102,7 +2143,7 @@ where
         vis: f.fold_visibility(node.vis),
         macro_token: Token![macro](tokens_helper(f, &node.macro_token.span)),
         ident: f.fold_ident(node.ident),
-        rules: node.rules,
+        rules: f.fold_token_stream(node.rules),
     }
 }
 #[cfg(feature = "full")]
@@ -2368,6 +2409,12 @@ where
                 Box::new(f.fold_expr(*(it).1)),
             )
         }),
+        diverge: (node.diverge).map(|it| {
+            (
+                Token![else](tokens_helper(f, &(it).0.span)),
+                Box::new(f.fold_expr(*(it).1)),
+            )
+        }),
         semi_token: Token ! [ ; ](tokens_helper(f, &node.semi_token.spans)),
     }
 }
@@ -2380,7 +2427,7 @@ where
         path: f.fold_path(node.path),
         bang_token: Token![!](tokens_helper(f, &node.bang_token.spans)),
         delimiter: f.fold_macro_delimiter(node.delimiter),
-        tokens: node.tokens,
+        tokens: f.fold_token_stream(node.tokens),
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -2499,7 +2546,7 @@ where
         Pat::Tuple(_binding_0) => Pat::Tuple(f.fold_pat_tuple(_binding_0)),
         Pat::TupleStruct(_binding_0) => Pat::TupleStruct(f.fold_pat_tuple_struct(_binding_0)),
         Pat::Type(_binding_0) => Pat::Type(f.fold_pat_type(_binding_0)),
-        Pat::Verbatim(_binding_0) => Pat::Verbatim(_binding_0),
+        Pat::Verbatim(_binding_0) => Pat::Verbatim(f.fold_token_stream(_binding_0)),
         Pat::Wild(_binding_0) => Pat::Wild(f.fold_pat_wild(_binding_0)),
         _ => unreachable!(),
     }
@@ -2712,6 +2759,20 @@ where
         arguments: f.fold_path_arguments(node.arguments),
     }
 }
+#[cfg(feature = "full")]
+pub fn fold_pointer_mutability<F>(f: &mut F, node: PointerMutability) -> PointerMutability
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            PointerMutability::Const(Token![const](tokens_helper(f, &_binding_0.span)))
+        }
+        PointerMutability::Mut(_binding_0) => {
+            PointerMutability::Mut(Token![mut](tokens_helper(f, &_binding_0.span)))
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_predicate_eq<F>(f: &mut F, node: PredicateEq) -> PredicateEq
 where
@@ -2843,6 +2904,12 @@ where
         ),
     }
 }
+pub fn fold_token_stream<F>(f: &mut F, node: TokenStream) -> TokenStream
+where
+    F: Fold + ?Sized,
+{
+    node
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_trait_bound<F>(f: &mut F, node: TraitBound) -> TraitBound
 where
@@ -2865,6 +2932,14 @@ where
         TraitBoundModifier::Maybe(_binding_0) => {
             TraitBoundModifier::Maybe(Token ! [ ? ](tokens_helper(f, &_binding_0.spans)))
         }
+        TraitBoundModifier::MaybeConst(_binding_0, _binding_1) => TraitBoundModifier::MaybeConst(
+            Token ! [ ? ](tokens_helper(f, &_binding_0.spans)),
+            Token![const](tokens_helper(f, &_binding_1.span)),
+        ),
+        TraitBoundModifier::TildeConst(_binding_0, _binding_1) => TraitBoundModifier::TildeConst(
+            Token ! [ ~ ](tokens_helper(f, &_binding_0.spans)),
+            Token![const](tokens_helper(f, &_binding_1.span)),
+        ),
     }
 }
 #[cfg(feature = "full")]
@@ -2877,7 +2952,7 @@ where
         TraitItem::Method(_binding_0) => TraitItem::Method(f.fold_trait_item_method(_binding_0)),
         TraitItem::Type(_binding_0) => TraitItem::Type(f.fold_trait_item_type(_binding_0)),
         TraitItem::Macro(_binding_0) => TraitItem::Macro(f.fold_trait_item_macro(_binding_0)),
-        TraitItem::Verbatim(_binding_0) => TraitItem::Verbatim(_binding_0),
+        TraitItem::Verbatim(_binding_0) => TraitItem::Verbatim(f.fold_token_stream(_binding_0)),
         _ => unreachable!(),
     }
 }
@@ -2965,7 +3040,7 @@ where
         Type::Slice(_binding_0) => Type::Slice(f.fold_type_slice(_binding_0)),
         Type::TraitObject(_binding_0) => Type::TraitObject(f.fold_type_trait_object(_binding_0)),
         Type::Tuple(_binding_0) => Type::Tuple(f.fold_type_tuple(_binding_0)),
-        Type::Verbatim(_binding_0) => Type::Verbatim(_binding_0),
+        Type::Verbatim(_binding_0) => Type::Verbatim(f.fold_token_stream(_binding_0)),
         _ => unreachable!(),
     }
 }
@@ -7,7 +7,7 @@ use crate::gen::helper::visit::*;
 #[cfg(any(feature = "full", feature = "derive"))]
 use crate::punctuated::Punctuated;
 use crate::*;
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 #[cfg(feature = "full")]
 macro_rules! full {
     ($e:expr) => {
@@ -157,6 +157,10 @@ pub trait Visit<'ast> {
         visit_expr_closure(self, i)
     }
     #[cfg(feature = "full")]
+    fn visit_expr_const(&mut self, i: &'ast ExprConst) {
+        visit_expr_const(self, i)
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_continue(&mut self, i: &'ast ExprContinue) {
         visit_expr_continue(self, i)
     }
@@ -221,6 +225,10 @@ pub trait Visit<'ast> {
         visit_expr_range(self, i)
     }
     #[cfg(feature = "full")]
+    fn visit_expr_raw_addr(&mut self, i: &'ast ExprRawAddr) {
+        visit_expr_raw_addr(self, i)
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_reference(&mut self, i: &'ast ExprReference) {
         visit_expr_reference(self, i)
     }
@@ -598,6 +606,10 @@ pub trait Visit<'ast> {
     fn visit_path_segment(&mut self, i: &'ast PathSegment) {
         visit_path_segment(self, i)
     }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability(&mut self, i: &'ast PointerMutability) {
+        visit_pointer_mutability(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_predicate_eq(&mut self, i: &'ast PredicateEq) {
         visit_predicate_eq(self, i)
@@ -637,6 +649,9 @@ pub trait Visit<'ast> {
     fn visit_stmt(&mut self, i: &'ast Stmt) {
         visit_stmt(self, i)
     }
+    fn visit_token_stream(&mut self, i: &'ast TokenStream) {
+        visit_token_stream(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_trait_bound(&mut self, i: &'ast TraitBound) {
         visit_trait_bound(self, i)
@@ -864,7 +879,7 @@ where
     v.visit_attr_style(&node.style);
     tokens_helper(v, &node.bracket_token.span);
     v.visit_path(&node.path);
-    skip!(node.tokens);
+    v.visit_token_stream(&node.tokens);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_bare_fn_arg<'ast, V>(v: &mut V, node: &'ast BareFnArg)
@@ -1161,6 +1176,9 @@ where
         Expr::Closure(_binding_0) => {
             full!(v.visit_expr_closure(_binding_0));
         }
+        Expr::Const(_binding_0) => {
+            full!(v.visit_expr_const(_binding_0));
+        }
         Expr::Continue(_binding_0) => {
             full!(v.visit_expr_continue(_binding_0));
         }
@@ -1209,6 +1227,9 @@ where
         Expr::Range(_binding_0) => {
             full!(v.visit_expr_range(_binding_0));
         }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr(_binding_0));
+        }
         Expr::Reference(_binding_0) => {
             full!(v.visit_expr_reference(_binding_0));
         }
@@ -1240,7 +1261,7 @@ where
             full!(v.visit_expr_unsafe(_binding_0));
         }
         Expr::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         Expr::While(_binding_0) => {
             full!(v.visit_expr_while(_binding_0));
@@ -1430,6 +1451,17 @@ where
     v.visit_expr(&*node.body);
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_const<'ast, V>(v: &mut V, node: &'ast ExprConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)
+    }
+    tokens_helper(v, &node.const_token.span);
+    v.visit_block(&node.block);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_continue<'ast, V>(v: &mut V, node: &'ast ExprContinue)
 where
     V: Visit<'ast> + ?Sized,
@@ -1648,6 +1680,19 @@ where
     };
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_raw_addr<'ast, V>(v: &mut V, node: &'ast ExprRawAddr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)
+    }
+    tokens_helper(v, &node.and_token.spans);
+    tokens_helper(v, &node.raw.span);
+    v.visit_pointer_mutability(&node.mutability);
+    v.visit_expr(&*node.expr);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_reference<'ast, V>(v: &mut V, node: &'ast ExprReference)
 where
     V: Visit<'ast> + ?Sized,
@@ -1946,7 +1991,7 @@ where
             v.visit_foreign_item_macro(_binding_0);
         }
         ForeignItem::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -2111,7 +2156,7 @@ where
             v.visit_impl_item_macro(_binding_0);
         }
         ImplItem::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -2273,7 +2318,7 @@ where
             v.visit_item_use(_binding_0);
         }
         Item::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -2441,7 +2486,7 @@ where
     v.visit_visibility(&node.vis);
     tokens_helper(v, &node.macro_token.span);
     v.visit_ident(&node.ident);
-    skip!(node.rules);
+    v.visit_token_stream(&node.rules);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_mod<'ast, V>(v: &mut V, node: &'ast ItemMod)
@@ -2727,6 +2772,10 @@ where
         tokens_helper(v, &(it).0.spans);
         v.visit_expr(&*(it).1);
     };
+    if let Some(it) = &node.diverge {
+        tokens_helper(v, &(it).0.span);
+        v.visit_expr(&*(it).1);
+    };
     tokens_helper(v, &node.semi_token.spans);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -2737,7 +2786,7 @@ where
     v.visit_path(&node.path);
     tokens_helper(v, &node.bang_token.spans);
     v.visit_macro_delimiter(&node.delimiter);
-    skip!(node.tokens);
+    v.visit_token_stream(&node.tokens);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_macro_delimiter<'ast, V>(v: &mut V, node: &'ast MacroDelimiter)
@@ -2907,7 +2956,7 @@ where
             v.visit_pat_type(_binding_0);
         }
         Pat::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         Pat::Wild(_binding_0) => {
             v.visit_pat_wild(_binding_0);
@@ -3161,6 +3210,20 @@ where
     v.visit_ident(&node.ident);
     v.visit_path_arguments(&node.arguments);
 }
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability<'ast, V>(v: &mut V, node: &'ast PointerMutability)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &_binding_0.span);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &_binding_0.span);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_predicate_eq<'ast, V>(v: &mut V, node: &'ast PredicateEq)
 where
@@ -3321,6 +3384,11 @@ where
         }
     }
 }
+pub fn visit_token_stream<'ast, V>(v: &mut V, node: &'ast TokenStream)
+where
+    V: Visit<'ast> + ?Sized,
+{
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_trait_bound<'ast, V>(v: &mut V, node: &'ast TraitBound)
 where
@@ -3345,6 +3413,14 @@ where
         TraitBoundModifier::Maybe(_binding_0) => {
             tokens_helper(v, &_binding_0.spans);
         }
+        TraitBoundModifier::MaybeConst(_binding_0, _binding_1) => {
+            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, &_binding_1.span);
+        }
+        TraitBoundModifier::TildeConst(_binding_0, _binding_1) => {
+            tokens_helper(v, &_binding_0.spans);
+            tokens_helper(v, &_binding_1.span);
+        }
     }
 }
 #[cfg(feature = "full")]
@@ -3366,7 +3442,7 @@ where
             v.visit_trait_item_macro(_binding_0);
         }
         TraitItem::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -3494,7 +3570,7 @@ where
             v.visit_type_tuple(_binding_0);
         }
         Type::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -7,7 +7,7 @@ use crate::gen::helper::visit_mut::*;
 #[cfg(any(feature = "full", feature = "derive"))]
 use crate::punctuated::Punctuated;
 use crate::*;
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 #[cfg(feature = "full")]
 macro_rules! full {
     ($e:expr) => {
@@ -161,6 +161,10 @@ pub trait VisitMut {
         visit_expr_closure_mut(self, i)
     }
     #[cfg(feature = "full")]
+    fn visit_expr_const_mut(&mut self, i: &mut ExprConst) {
+        visit_expr_const_mut(self, i)
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_continue_mut(&mut self, i: &mut ExprContinue) {
         visit_expr_continue_mut(self, i)
     }
@@ -225,6 +229,10 @@ pub trait VisitMut {
         visit_expr_range_mut(self, i)
     }
     #[cfg(feature = "full")]
+    fn visit_expr_raw_addr_mut(&mut self, i: &mut ExprRawAddr) {
+        visit_expr_raw_addr_mut(self, i)
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_reference_mut(&mut self, i: &mut ExprReference) {
         visit_expr_reference_mut(self, i)
     }
@@ -602,6 +610,10 @@ pub trait VisitMut {
     fn visit_path_segment_mut(&mut self, i: &mut PathSegment) {
         visit_path_segment_mut(self, i)
     }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability_mut(&mut self, i: &mut PointerMutability) {
+        visit_pointer_mutability_mut(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_predicate_eq_mut(&mut self, i: &mut PredicateEq) {
         visit_predicate_eq_mut(self, i)
@@ -641,6 +653,9 @@ pub trait VisitMut {
     fn visit_stmt_mut(&mut self, i: &mut Stmt) {
         visit_stmt_mut(self, i)
     }
+    fn visit_token_stream_mut(&mut self, i: &mut TokenStream) {
+        visit_token_stream_mut(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_trait_bound_mut(&mut self, i: &mut TraitBound) {
         visit_trait_bound_mut(self, i)
@@ -868,7 +883,7 @@ where
     v.visit_attr_style_mut(&mut node.style);
     tokens_helper(v, &mut node.bracket_token.span);
     v.visit_path_mut(&mut node.path);
-    skip!(node.tokens);
+    v.visit_token_stream_mut(&mut node.tokens);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_bare_fn_arg_mut<V>(v: &mut V, node: &mut BareFnArg)
@@ -1165,6 +1180,9 @@ where
         Expr::Closure(_binding_0) => {
             full!(v.visit_expr_closure_mut(_binding_0));
         }
+        Expr::Const(_binding_0) => {
+            full!(v.visit_expr_const_mut(_binding_0));
+        }
         Expr::Continue(_binding_0) => {
             full!(v.visit_expr_continue_mut(_binding_0));
         }
@@ -1213,6 +1231,9 @@ where
         Expr::Range(_binding_0) => {
             full!(v.visit_expr_range_mut(_binding_0));
         }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr_mut(_binding_0));
+        }
         Expr::Reference(_binding_0) => {
             full!(v.visit_expr_reference_mut(_binding_0));
         }
@@ -1244,7 +1265,7 @@ where
             full!(v.visit_expr_unsafe_mut(_binding_0));
         }
         Expr::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         Expr::While(_binding_0) => {
             full!(v.visit_expr_while_mut(_binding_0));
@@ -1434,6 +1455,17 @@ where
     v.visit_expr_mut(&mut *node.body);
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_const_mut<V>(v: &mut V, node: &mut ExprConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it)
+    }
+    tokens_helper(v, &mut node.const_token.span);
+    v.visit_block_mut(&mut node.block);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_continue_mut<V>(v: &mut V, node: &mut ExprContinue)
 where
     V: VisitMut + ?Sized,
@@ -1652,6 +1684,19 @@ where
     };
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_raw_addr_mut<V>(v: &mut V, node: &mut ExprRawAddr)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it)
+    }
+    tokens_helper(v, &mut node.and_token.spans);
+    tokens_helper(v, &mut node.raw.span);
+    v.visit_pointer_mutability_mut(&mut node.mutability);
+    v.visit_expr_mut(&mut *node.expr);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_reference_mut<V>(v: &mut V, node: &mut ExprReference)
 where
     V: VisitMut + ?Sized,
@@ -1950,7 +1995,7 @@ where
             v.visit_foreign_item_macro_mut(_binding_0);
         }
         ForeignItem::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -2117,7 +2162,7 @@ where
             v.visit_impl_item_macro_mut(_binding_0);
         }
         ImplItem::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -2279,7 +2324,7 @@ where
             v.visit_item_use_mut(_binding_0);
         }
         Item::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -2447,7 +2492,7 @@ where
     v.visit_visibility_mut(&mut node.vis);
     tokens_helper(v, &mut node.macro_token.span);
     v.visit_ident_mut(&mut node.ident);
-    skip!(node.rules);
+    v.visit_token_stream_mut(&mut node.rules);
 }
 #[cfg(feature = "full")]
 pub fn visit_item_mod_mut<V>(v: &mut V, node: &mut ItemMod)
@@ -2688,36 +2733,54 @@ pub fn visit_lit_byte_mut<V>(v: &mut V, node: &mut LitByte)
 where
     V: VisitMut + ?Sized,
 {
+    let mut span = node.span();
+    v.visit_span_mut(&mut span);
+    node.set_span(span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_lit_byte_str_mut<V>(v: &mut V, node: &mut LitByteStr)
 where
     V: VisitMut + ?Sized,
 {
+    let mut span = node.span();
+    v.visit_span_mut(&mut span);
+    node.set_span(span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_lit_char_mut<V>(v: &mut V, node: &mut LitChar)
 where
     V: VisitMut + ?Sized,
 {
+    let mut span = node.span();
+    v.visit_span_mut(&mut span);
+    node.set_span(span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_lit_float_mut<V>(v: &mut V, node: &mut LitFloat)
 where
     V: VisitMut + ?Sized,
 {
+    let mut span = node.span();
+    v.visit_span_mut(&mut span);
+    node.set_span(span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_lit_int_mut<V>(v: &mut V, node: &mut LitInt)
 where
     V: VisitMut + ?Sized,
 {
+    let mut span = node.span();
+    v.visit_span_mut(&mut span);
+    node.set_span(span);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_lit_str_mut<V>(v: &mut V, node: &mut LitStr)
 where
     V: VisitMut + ?Sized,
 {
+    let mut span = node.span();
+    v.visit_span_mut(&mut span);
+    node.set_span(span);
 }
 #[cfg(feature = "full")]
 pub fn visit_local_mut<V>(v: &mut V, node: &mut Local)
@@ -2733,6 +2796,10 @@ where
         tokens_helper(v, &mut (it).0.spans);
         v.visit_expr_mut(&mut *(it).1);
     };
+    if let Some(it) = &mut node.diverge {
+        tokens_helper(v, &mut (it).0.span);
+        v.visit_expr_mut(&mut *(it).1);
+    };
     tokens_helper(v, &mut node.semi_token.spans);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -2743,7 +2810,7 @@ where
     v.visit_path_mut(&mut node.path);
     tokens_helper(v, &mut node.bang_token.spans);
     v.visit_macro_delimiter_mut(&mut node.delimiter);
-    skip!(node.tokens);
+    v.visit_token_stream_mut(&mut node.tokens);
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_macro_delimiter_mut<V>(v: &mut V, node: &mut MacroDelimiter)
@@ -2913,7 +2980,7 @@ where
             v.visit_pat_type_mut(_binding_0);
         }
         Pat::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         Pat::Wild(_binding_0) => {
             v.visit_pat_wild_mut(_binding_0);
@@ -3167,6 +3234,20 @@ where
     v.visit_ident_mut(&mut node.ident);
     v.visit_path_arguments_mut(&mut node.arguments);
 }
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability_mut<V>(v: &mut V, node: &mut PointerMutability)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.span);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.span);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_predicate_eq_mut<V>(v: &mut V, node: &mut PredicateEq)
 where
@@ -3327,6 +3408,11 @@ where
         }
     }
 }
+pub fn visit_token_stream_mut<V>(v: &mut V, node: &mut TokenStream)
+where
+    V: VisitMut + ?Sized,
+{
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_trait_bound_mut<V>(v: &mut V, node: &mut TraitBound)
 where
@@ -3351,6 +3437,14 @@ where
         TraitBoundModifier::Maybe(_binding_0) => {
             tokens_helper(v, &mut _binding_0.spans);
         }
+        TraitBoundModifier::MaybeConst(_binding_0, _binding_1) => {
+            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, &mut _binding_1.span);
+        }
+        TraitBoundModifier::TildeConst(_binding_0, _binding_1) => {
+            tokens_helper(v, &mut _binding_0.spans);
+            tokens_helper(v, &mut _binding_1.span);
+        }
     }
 }
 #[cfg(feature = "full")]
@@ -3372,7 +3466,7 @@ where
             v.visit_trait_item_macro_mut(_binding_0);
         }
         TraitItem::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -3500,7 +3594,7 @@ where
             v.visit_type_tuple_mut(_binding_0);
         }
         Type::Verbatim(_binding_0) => {
-            skip!(_binding_0);
+            v.visit_token_stream_mut(_binding_0);
         }
         _ => unreachable!(),
     }
@@ -168,6 +168,86 @@ impl Generics {
             None => unreachable!(),
         }
     }
+
+    /// Returns true if there are no generic parameters and no `where`-clause
+    /// predicates, as is the case for `Generics::default()`.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty() && !self.has_where_clause()
+    }
+
+    /// Returns true if any of the generic parameters is a type parameter.
+    pub fn has_type_params(&self) -> bool {
+        self.type_params().next().is_some()
+    }
+
+    /// Returns true if there is a `where`-clause with at least one predicate.
+    ///
+    /// An empty `where` with no predicates, such as one created by
+    /// [`make_where_clause`], does not count.
+    ///
+    /// [`make_where_clause`]: Generics::make_where_clause
+    pub fn has_where_clause(&self) -> bool {
+        match &self.where_clause {
+            Some(where_clause) => !where_clause.predicates.is_empty(),
+            None => false,
+        }
+    }
+
+    /// Declares a lifetime parameter after the existing lifetime parameters
+    /// and before any type or const parameters, which must come after all
+    /// lifetimes.
+    ///
+    /// A lifetime that is already declared is not declared again. If
+    /// `bound_existing` is true, every other lifetime parameter is also given
+    /// the bound `'a: 'new` unless it already has it, so that the new
+    /// lifetime can be used for borrows of anything that lives for one of
+    /// the existing lifetimes.
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Generics};
+    ///
+    /// let mut generics: Generics = parse_quote!(<'a, T: 'a, const N: usize>);
+    /// generics.add_lifetime(&parse_quote!('new), false);
+    /// assert_eq!(
+    ///     quote!(#generics).to_string(),
+    ///     quote!(<'a, 'new, T: 'a, const N: usize>).to_string(),
+    /// );
+    ///
+    /// let mut generics: Generics = parse_quote!(<'a, 'b: 'a, T>);
+    /// generics.add_lifetime(&parse_quote!('new), true);
+    /// assert_eq!(
+    ///     quote!(#generics).to_string(),
+    ///     quote!(<'a: 'new, 'b: 'a + 'new, 'new, T>).to_string(),
+    /// );
+    /// ```
+    pub fn add_lifetime(&mut self, lifetime: &Lifetime, bound_existing: bool) {
+        if bound_existing {
+            for def in self.lifetimes_mut() {
+                if def.lifetime == *lifetime || def.bounds.iter().any(|bound| bound == lifetime) {
+                    continue;
+                }
+                if def.colon_token.is_none() {
+                    def.colon_token = Some(<Token![:]>::default());
+                }
+                def.bounds.push(lifetime.clone());
+            }
+        }
+
+        if self.lifetimes().any(|def| def.lifetime == *lifetime) {
+            return;
+        }
+        let index = self
+            .params
+            .iter()
+            .take_while(|param| match param {
+                GenericParam::Lifetime(_) => true,
+                GenericParam::Type(_) | GenericParam::Const(_) => false,
+            })
+            .count();
+        let param = GenericParam::Lifetime(LifetimeDef::new(lifetime.clone()));
+        self.params.insert(index, param);
+    }
 }
 
 pub struct TypeParams<'a>(Iter<'a, GenericParam>);
@@ -339,6 +419,144 @@ impl Generics {
     }
 }
 
+#[cfg(all(feature = "printing", feature = "clone-impls"))]
+impl Generics {
+    /// Adds a trait bound to every type parameter, as a derive macro does to
+    /// require that each one implements the trait being derived.
+    ///
+    /// Lifetime and const parameters are left alone, as is any type parameter
+    /// that already has exactly this bound, whether inline as in `<T: Bound>`
+    /// or in a `where`-clause predicate `T: Bound`. Paths are compared by
+    /// their tokens, so `Debug` and `fmt::Debug` count as different bounds.
+    ///
+    /// The bound is added inline to the list of bounds of each type
+    /// parameter, as in `<T: Clone + Bound>`, unless `in_where_clause` is
+    /// true, in which case a predicate `T: Bound` is appended to the
+    /// `where`-clause for each one instead.
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Generics};
+    ///
+    /// let mut generics: Generics = parse_quote!(<'a, T, U: Clone, const N: usize>);
+    /// generics.add_trait_bound(&parse_quote!(Clone), false);
+    /// assert_eq!(
+    ///     quote!(#generics).to_string(),
+    ///     quote!(<'a, T: Clone, U: Clone, const N: usize>).to_string(),
+    /// );
+    ///
+    /// let mut generics: Generics = parse_quote!(<T, U>);
+    /// generics.add_trait_bound(&parse_quote!(serde::Serialize), true);
+    /// let where_clause = &generics.where_clause;
+    /// assert_eq!(
+    ///     quote!(#generics #where_clause).to_string(),
+    ///     quote!(<T, U> where T: serde::Serialize, U: serde::Serialize).to_string(),
+    /// );
+    /// ```
+    ///
+    /// *This method is available if Syn is built with the `"derive"` or
+    /// `"full"` feature and the `"printing"` and `"clone-impls"` features.*
+    pub fn add_trait_bound(&mut self, bound: &Path, in_where_clause: bool) {
+        use quote::ToTokens;
+
+        let bound_tokens = bound.to_token_stream().to_string();
+        let mut unbounded = Vec::new();
+        for param in self.type_params() {
+            let bounded_in_where = match &self.where_clause {
+                Some(where_clause) => {
+                    where_clause
+                        .predicates
+                        .iter()
+                        .any(|predicate| match predicate {
+                            WherePredicate::Type(predicate) => {
+                                predicate.lifetimes.is_none()
+                                    && is_param(&predicate.bounded_ty, &param.ident)
+                                    && has_bound(&predicate.bounds, &bound_tokens)
+                            }
+                            _ => false,
+                        })
+                }
+                None => false,
+            };
+            if !bounded_in_where && !has_bound(&param.bounds, &bound_tokens) {
+                unbounded.push(param.ident.clone());
+            }
+        }
+
+        let new_bound = TypeParamBound::Trait(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path: bound.clone(),
+        });
+
+        if in_where_clause {
+            if unbounded.is_empty() {
+                return;
+            }
+            let where_clause = self.make_where_clause();
+            for ident in unbounded {
+                where_clause
+                    .predicates
+                    .push(WherePredicate::Type(PredicateType {
+                        lifetimes: None,
+                        bounded_ty: Type::Path(TypePath {
+                            qself: None,
+                            path: Path::from(ident),
+                        }),
+                        colon_token: <Token![:]>::default(),
+                        bounds: {
+                            let mut bounds = Punctuated::new();
+                            bounds.push(new_bound.clone());
+                            bounds
+                        },
+                    }));
+            }
+        } else {
+            for param in self.type_params_mut() {
+                if unbounded.contains(&param.ident) {
+                    if param.colon_token.is_none() {
+                        param.colon_token = Some(<Token![:]>::default());
+                    }
+                    param.bounds.push(new_bound.clone());
+                }
+            }
+        }
+    }
+}
+
+// Whether the type is just the name of the given type parameter.
+#[cfg(all(feature = "printing", feature = "clone-impls"))]
+fn is_param(ty: &Type, ident: &Ident) -> bool {
+    match ty {
+        Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty.path.leading_colon.is_none()
+                && ty.path.segments.len() == 1
+                && ty.path.segments[0].arguments.is_empty()
+                && ty.path.segments[0].ident == *ident
+        }
+        _ => false,
+    }
+}
+
+// Whether the bounds include a plain trait bound whose path has the given
+// tokens.
+#[cfg(all(feature = "printing", feature = "clone-impls"))]
+fn has_bound(bounds: &Punctuated<TypeParamBound, Token![+]>, path_tokens: &str) -> bool {
+    use quote::ToTokens;
+
+    bounds.iter().any(|bound| match bound {
+        TypeParamBound::Trait(TraitBound {
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path,
+            ..
+        }) => path.to_token_stream().to_string() == path_tokens,
+        _ => false,
+    })
+}
+
 #[cfg(feature = "printing")]
 impl<'a> TypeGenerics<'a> {
     /// Turn a type's generics like `<X, Y>` into a turbofish like `::<X, Y>`.
@@ -415,8 +633,8 @@ ast_struct! {
 }
 
 ast_enum! {
-    /// A modifier on a trait bound, currently only used for the `?` in
-    /// `?Sized`.
+    /// A modifier on a trait bound, such as the `?` in `?Sized` or the
+    /// experimental `~const` in `~const Clone`.
     ///
     /// *This type is available if Syn is built with the `"derive"` or `"full"`
     /// feature.*
@@ -424,6 +642,14 @@ ast_enum! {
     pub enum TraitBoundModifier {
         None,
         Maybe(Token![?]),
+        /// `?const Trait`
+        ///
+        /// Only parsed if Syn is built with the `"full"` feature.
+        MaybeConst(Token![?], Token![const]),
+        /// `~const Trait`
+        ///
+        /// Only parsed if Syn is built with the `"full"` feature.
+        TildeConst(Token![~], Token![const]),
     }
 }
 
@@ -760,6 +986,23 @@ pub mod parsing {
 
     impl Parse for TraitBoundModifier {
         fn parse(input: ParseStream) -> Result<Self> {
+            #[cfg(feature = "full")]
+            {
+                if input.peek(Token![?]) && input.peek2(Token![const]) {
+                    return Ok(TraitBoundModifier::MaybeConst(
+                        input.parse()?,
+                        input.parse()?,
+                    ));
+                }
+
+                if input.peek(Token![~]) && input.peek2(Token![const]) {
+                    return Ok(TraitBoundModifier::TildeConst(
+                        input.parse()?,
+                        input.parse()?,
+                    ));
+                }
+            }
+
             if input.peek(Token![?]) {
                 input.parse().map(TraitBoundModifier::Maybe)
             } else {
@@ -1106,6 +1349,14 @@ mod printing {
             match self {
                 TraitBoundModifier::None => {}
                 TraitBoundModifier::Maybe(t) => t.to_tokens(tokens),
+                TraitBoundModifier::MaybeConst(question, const_token) => {
+                    question.to_tokens(tokens);
+                    const_token.to_tokens(tokens);
+                }
+                TraitBoundModifier::TildeConst(tilde, const_token) => {
+                    tilde.to_tokens(tokens);
+                    const_token.to_tokens(tokens);
+                }
             }
         }
     }
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+
+use crate::visit::Visit;
+use crate::*;
+
+/// Finds the imports of a file whose imported name is not mentioned anywhere
+/// else in the file.
+///
+/// Each unused import is returned as a standalone `UseTree` holding the full
+/// path of that one import, so `c` in `use a::{b, c};` is reported as `a::c`.
+/// Imports are returned in the order they appear in the file.
+///
+/// This is a heuristic based purely on names, not on name resolution. An
+/// import counts as used if an identifier with the same name as the imported
+/// name (or its rename, for `use a::b as c`) appears anywhere in the file
+/// outside of `use` items, including inside of macro invocations and
+/// attributes. In particular:
+///
+/// - Glob imports like `use a::*` are never reported, since the names they
+///   bring into scope are not known.
+/// - Imports renamed to `_`, like `use a::Trait as _`, are never reported,
+///   since they exist only to bring trait methods into scope.
+/// - `self` in a group, like `use a::{self}`, imports the name `a`.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"visit"` features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, unused_imports, File};
+///
+/// let file: File = parse_quote! {
+///     use std::collections::{HashMap, HashSet};
+///     use std::fmt::*;
+///
+///     fn f() -> HashMap<u8, u8> {
+///         HashMap::new()
+///     }
+/// };
+///
+/// let unused = unused_imports(&file);
+/// assert_eq!(unused.len(), 1);
+/// assert_eq!(
+///     quote!(#(#unused)*).to_string(),
+///     quote!(std::collections::HashSet).to_string(),
+/// );
+/// ```
+pub fn unused_imports(file: &File) -> Vec<UseTree> {
+    let mut visitor = ImportVisitor {
+        uses: Vec::new(),
+        used: HashSet::new(),
+    };
+    visitor.visit_file(file);
+
+    let mut unused = Vec::new();
+    for item in visitor.uses {
+        let mut prefix = Vec::new();
+        collect_unused(&item.tree, &mut prefix, &visitor.used, &mut unused);
+    }
+    unused
+}
+
+struct ImportVisitor<'ast> {
+    uses: Vec<&'ast ItemUse>,
+    used: HashSet<String>,
+}
+
+impl<'ast> Visit<'ast> for ImportVisitor<'ast> {
+    fn visit_item_use(&mut self, node: &'ast ItemUse) {
+        self.uses.push(node);
+    }
+
+    fn visit_ident(&mut self, node: &'ast Ident) {
+        self.used.insert(node.to_string());
+    }
+
+    fn visit_token_stream(&mut self, node: &'ast TokenStream) {
+        collect_idents(node.clone(), &mut self.used);
+    }
+}
+
+fn collect_idents(tokens: TokenStream, used: &mut HashSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                used.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), used),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
+
+fn collect_unused<'a>(
+    tree: &'a UseTree,
+    prefix: &mut Vec<&'a Ident>,
+    used: &HashSet<String>,
+    unused: &mut Vec<UseTree>,
+) {
+    match tree {
+        UseTree::Path(path) => {
+            prefix.push(&path.ident);
+            collect_unused(&path.tree, prefix, used, unused);
+            prefix.pop();
+        }
+        UseTree::Name(name) => {
+            if name.ident == "self" {
+                if let Some((last, rest)) = prefix.split_last() {
+                    if !used.contains(&last.to_string()) {
+                        let leaf = UseTree::Name(UseName {
+                            ident: (*last).clone(),
+                        });
+                        unused.push(with_prefix(rest, leaf));
+                    }
+                }
+            } else if !used.contains(&name.ident.to_string()) {
+                let leaf = UseTree::Name(UseName {
+                    ident: name.ident.clone(),
+                });
+                unused.push(with_prefix(prefix, leaf));
+            }
+        }
+        UseTree::Rename(rename) => {
+            if rename.rename != "_" && !used.contains(&rename.rename.to_string()) {
+                let leaf = UseTree::Rename(UseRename {
+                    ident: rename.ident.clone(),
+                    as_token: Token![as](rename.as_token.span),
+                    rename: rename.rename.clone(),
+                });
+                unused.push(with_prefix(prefix, leaf));
+            }
+        }
+        UseTree::Glob(_) => {}
+        UseTree::Group(group) => {
+            for tree in &group.items {
+                collect_unused(tree, prefix, used, unused);
+            }
+        }
+    }
+}
+
+fn with_prefix(prefix: &[&Ident], leaf: UseTree) -> UseTree {
+    prefix.iter().rev().fold(leaf, |tree, ident| {
+        UseTree::Path(UsePath {
+            ident: (*ident).clone(),
+            colon2_token: Token![::](ident.span()),
+            tree: Box::new(tree),
+        })
+    })
+}
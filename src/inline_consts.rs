@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// Replaces uses of the constants defined at the top level of a file with
+/// their values, where those values are literals.
+///
+/// A constant is inlined only if it is initialized by a single literal, as in
+/// `const LIMIT: usize = 64;`, and only if nothing else at the top level of
+/// the file declares a value of the same name, as two constants under
+/// mutually exclusive `cfg` attributes would. Its uses are the expressions
+/// consisting of a path to it by its bare name, like `LIMIT` or the `LIMIT` of
+/// a struct field shorthand `Config { LIMIT }`, including the expressions
+/// nested in patterns and types: the bounds of a range pattern `0..=LIMIT`,
+/// the length of an array type `[u8; LIMIT]`, and a const generic argument in
+/// braces `Buf<{ LIMIT }>`. A pattern that is just the name, a const generic
+/// argument without braces like `Buf<LIMIT>`, which is parsed as a type, and
+/// the tokens of macro invocations are left alone. The inlined literal keeps
+/// the span of the constant's initializer. The definitions themselves are
+/// kept, since other files may refer to them.
+///
+/// An integer or float literal without a suffix is given the constant's type
+/// as its suffix, so that `N.pow(2)` with `const N: u32 = 5;` becomes
+/// `5u32.pow(2)` rather than a call on an integer of ambiguous type. Such a
+/// constant is not inlined if its type is not a primitive numeric type, as
+/// with a type alias.
+///
+/// A name is not inlined where it may refer to something other than the
+/// top-level constant:
+///
+/// - inside of a block that declares an item of the same name in the value
+///   namespace, such as a local `const`, `static`, or `fn`, or that imports
+///   one with `use`, including through a glob import,
+/// - inside of an item that has a const generic parameter of the same name,
+/// - inside of a nested module, which does not see the file's items by their
+///   bare names.
+///
+/// Local variables cannot shadow a constant, since a `let` or closure
+/// parameter naming it is a pattern matching against its value rather than a
+/// new binding.
+///
+/// *This function is available if Syn is built with the `"full"`, `"fold"`,
+/// and `"clone-impls"` features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{inline_consts, parse_quote, File};
+///
+/// let file: File = parse_quote! {
+///     const LIMIT: usize = 64;
+///     const NAME: &str = "buffer";
+///
+///     fn check(len: usize) {
+///         if len > LIMIT {
+///             const LIMIT: usize = 128;
+///             assert!(len <= LIMIT, NAME);
+///         }
+///     }
+/// };
+///
+/// let expected = quote! {
+///     const LIMIT: usize = 64;
+///     const NAME: &str = "buffer";
+///
+///     fn check(len: usize) {
+///         if len > 64usize {
+///             const LIMIT: usize = 128;
+///             assert!(len <= LIMIT, NAME);
+///         }
+///     }
+/// };
+///
+/// let file = inline_consts(file);
+/// assert_eq!(quote!(#file).to_string(), expected.to_string());
+/// ```
+pub fn inline_consts(file: File) -> File {
+    let mut values = HashMap::new();
+    let mut names = Vec::new();
+    for item in &file.items {
+        if let Item::Const(item) = item {
+            if let Expr::Lit(expr) = &*item.expr {
+                if expr.attrs.is_empty() {
+                    if let Some(lit) = typed_lit(&expr.lit, &item.ty) {
+                        values.insert(item.ident.to_string(), lit);
+                    }
+                }
+            }
+        }
+        // Explicitly declared items take precedence over glob imports in
+        // the same module.
+        declare_names(item, &mut names);
+    }
+
+    let mut declarations = HashMap::new();
+    for name in names {
+        *declarations.entry(name).or_insert(0) += 1;
+    }
+    values.retain(|name, _| declarations[name] == 1);
+
+    let mut inliner = ConstInliner {
+        values,
+        shadowed: Vec::new(),
+    };
+    inliner.fold_file(file)
+}
+
+struct ConstInliner {
+    values: HashMap<String, Lit>,
+    shadowed: Vec<String>,
+}
+
+impl ConstInliner {
+    fn lookup(&self, expr: &ExprPath) -> Option<&Lit> {
+        let path = &expr.path;
+        if expr.qself.is_some() || path.leading_colon.is_some() || path.segments.len() != 1 {
+            return None;
+        }
+        let segment = &path.segments[0];
+        if !segment.arguments.is_empty() {
+            return None;
+        }
+        let name = segment.ident.to_string();
+        if self.shadowed.contains(&name) {
+            return None;
+        }
+        self.values.get(&name)
+    }
+
+    fn shadow_generics(&mut self, generics: &Generics) {
+        for param in generics.const_params() {
+            self.shadowed.push(param.ident.to_string());
+        }
+    }
+}
+
+impl Fold for ConstInliner {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        match node {
+            Expr::Path(expr) => match self.lookup(&expr).cloned() {
+                Some(lit) => Expr::Lit(ExprLit {
+                    attrs: expr.attrs,
+                    lit,
+                }),
+                None => fold::fold_expr(self, Expr::Path(expr)),
+            },
+            node => fold::fold_expr(self, node),
+        }
+    }
+
+    fn fold_field_value(&mut self, node: FieldValue) -> FieldValue {
+        let mut node = fold::fold_field_value(self, node);
+        // A shorthand field whose value was inlined needs to be written out
+        // in full.
+        if node.colon_token.is_none() {
+            if let Expr::Lit(_) = node.expr {
+                node.colon_token = Some(<Token![:]>::default());
+            }
+        }
+        node
+    }
+
+    fn fold_block(&mut self, node: Block) -> Block {
+        let len = self.shadowed.len();
+        for stmt in &node.stmts {
+            if let Stmt::Item(item) = stmt {
+                if declare_names(item, &mut self.shadowed) {
+                    // A glob import may bring any of the constants into
+                    // scope.
+                    self.shadowed.extend(self.values.keys().cloned());
+                }
+            }
+        }
+        let node = fold::fold_block(self, node);
+        self.shadowed.truncate(len);
+        node
+    }
+
+    fn fold_item(&mut self, node: Item) -> Item {
+        let generics = match &node {
+            Item::Enum(item) => &item.generics,
+            Item::Existential(item) => &item.generics,
+            Item::Fn(item) => &item.sig.generics,
+            Item::Impl(item) => &item.generics,
+            Item::Struct(item) => &item.generics,
+            Item::Trait(item) => &item.generics,
+            Item::TraitAlias(item) => &item.generics,
+            Item::Type(item) => &item.generics,
+            Item::Union(item) => &item.generics,
+            Item::Mod(_) => return node,
+            _ => return fold::fold_item(self, node),
+        };
+        let len = self.shadowed.len();
+        self.shadow_generics(generics);
+        let node = fold::fold_item(self, node);
+        self.shadowed.truncate(len);
+        node
+    }
+
+    fn fold_impl_item(&mut self, node: ImplItem) -> ImplItem {
+        let generics = match &node {
+            ImplItem::Method(item) => &item.sig.generics,
+            ImplItem::Type(item) => &item.generics,
+            ImplItem::Existential(item) => &item.generics,
+            _ => return fold::fold_impl_item(self, node),
+        };
+        let len = self.shadowed.len();
+        self.shadow_generics(generics);
+        let node = fold::fold_impl_item(self, node);
+        self.shadowed.truncate(len);
+        node
+    }
+
+    fn fold_trait_item(&mut self, node: TraitItem) -> TraitItem {
+        let generics = match &node {
+            TraitItem::Method(item) => &item.sig.generics,
+            TraitItem::Type(item) => &item.generics,
+            _ => return fold::fold_trait_item(self, node),
+        };
+        let len = self.shadowed.len();
+        self.shadow_generics(generics);
+        let node = fold::fold_trait_item(self, node);
+        self.shadowed.truncate(len);
+        node
+    }
+}
+
+// Gives an unsuffixed numeric literal the suffix of the primitive type of the
+// constant it initializes, since its type would otherwise be inferred from
+// each use instead.
+fn typed_lit(lit: &Lit, ty: &Type) -> Option<Lit> {
+    let suffix = match ty {
+        Type::Path(ty) if ty.qself.is_none() => match ty.path.get_ident() {
+            Some(ident) => ident.to_string(),
+            None => String::new(),
+        },
+        _ => String::new(),
+    };
+    match lit {
+        Lit::Int(lit) if lit.suffix().is_empty() => match suffix.as_str() {
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+            | "i128" | "isize" => {
+                let repr = format!("{}{}", lit, suffix);
+                Some(Lit::Int(LitInt::new(&repr, lit.span())))
+            }
+            _ => None,
+        },
+        Lit::Float(lit) if lit.suffix().is_empty() => match suffix.as_str() {
+            "f32" | "f64" => {
+                let mut repr = lit.to_string();
+                // A suffix directly after the dot of `1.` would be parsed as
+                // a field or method.
+                if repr.ends_with('.') {
+                    repr.push('0');
+                }
+                repr.push_str(&suffix);
+                Some(Lit::Float(LitFloat::new(&repr, lit.span())))
+            }
+            _ => None,
+        },
+        lit => Some(lit.clone()),
+    }
+}
+
+// Adds the names that an item declares in the value namespace, erring on the
+// side of including too many. Returns whether the item is a glob import.
+fn declare_names(item: &Item, names: &mut Vec<String>) -> bool {
+    match item {
+        Item::Const(item) => names.push(item.ident.to_string()),
+        Item::Static(item) => names.push(item.ident.to_string()),
+        Item::Fn(item) => names.push(item.sig.ident.to_string()),
+        Item::Struct(item) => names.push(item.ident.to_string()),
+        Item::ForeignMod(item) => {
+            for item in &item.items {
+                match item {
+                    ForeignItem::Fn(item) => names.push(item.sig.ident.to_string()),
+                    ForeignItem::Static(item) => names.push(item.ident.to_string()),
+                    _ => {}
+                }
+            }
+        }
+        Item::Use(item) => return declare_use_names(&item.tree, names),
+        _ => {}
+    }
+    false
+}
+
+fn declare_use_names(tree: &UseTree, names: &mut Vec<String>) -> bool {
+    match tree {
+        UseTree::Path(tree) => declare_use_names(&tree.tree, names),
+        UseTree::Name(tree) => {
+            names.push(tree.ident.to_string());
+            false
+        }
+        UseTree::Rename(tree) => {
+            names.push(tree.rename.to_string());
+            false
+        }
+        UseTree::Glob(_) => true,
+        UseTree::Group(tree) => {
+            let mut glob = false;
+            for tree in &tree.items {
+                glob |= declare_use_names(tree, names);
+            }
+            glob
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// Inserts a statement at the start of the body of every function in a file.
+///
+/// The callback is called with the name of each function that has a body, in
+/// the order they appear, and the statement it returns is inserted ahead of
+/// the existing statements of that function's body, which are otherwise left
+/// as they were. This is intended for instrumentation such as entering a
+/// tracing span named after the function.
+///
+/// Free functions, methods in impls, and trait methods with a default body
+/// are all instrumented, including functions nested inside of other function
+/// bodies. Trait methods declared without a body and functions in `extern`
+/// blocks have no body to instrument and are skipped, as are closures.
+/// Functions inside of macro invocations are not visited, since the tokens of
+/// a macro are not parsed.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{instrument_fns, parse_quote, File};
+///
+/// let file: File = parse_quote! {
+///     fn main() {
+///         run();
+///     }
+/// };
+///
+/// let file = instrument_fns(file, |name| {
+///     let name = name.to_string();
+///     parse_quote! {
+///         let _span = tracing::span!(tracing::Level::TRACE, #name).entered();
+///     }
+/// });
+///
+/// let expected = quote! {
+///     fn main() {
+///         let _span = tracing::span!(tracing::Level::TRACE, "main").entered();
+///         run();
+///     }
+/// };
+/// assert_eq!(quote!(#file).to_string(), expected.to_string());
+/// ```
+pub fn instrument_fns<F>(file: File, prologue: F) -> File
+where
+    F: FnMut(&Ident) -> Stmt,
+{
+    InstrumentFns { prologue }.fold_file(file)
+}
+
+struct InstrumentFns<F> {
+    prologue: F,
+}
+
+impl<F> Fold for InstrumentFns<F>
+where
+    F: FnMut(&Ident) -> Stmt,
+{
+    fn fold_item_fn(&mut self, node: ItemFn) -> ItemFn {
+        let prologue = (self.prologue)(&node.sig.ident);
+        let mut node = fold::fold_item_fn(self, node);
+        node.block.stmts.insert(0, prologue);
+        node
+    }
+
+    fn fold_impl_item_method(&mut self, node: ImplItemMethod) -> ImplItemMethod {
+        let prologue = (self.prologue)(&node.sig.ident);
+        let mut node = fold::fold_impl_item_method(self, node);
+        node.block.stmts.insert(0, prologue);
+        node
+    }
+
+    fn fold_trait_item_method(&mut self, node: TraitItemMethod) -> TraitItemMethod {
+        let prologue = match node.default {
+            Some(_) => Some((self.prologue)(&node.sig.ident)),
+            None => None,
+        };
+        let mut node = fold::fold_trait_item_method(self, node);
+        if let (Some(block), Some(prologue)) = (node.default.as_mut(), prologue) {
+            block.stmts.insert(0, prologue);
+        }
+        node
+    }
+}
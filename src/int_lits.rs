@@ -0,0 +1,187 @@
+use crate::fold::Fold;
+use crate::*;
+
+/// A syntax tree node whose integer literals can be rewritten by
+/// [`canonicalize_int_lits`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"fold"` feature.*
+pub trait CanonicalizeIntLits: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn fold_canonicalize_int_lits(self, folder: &mut IntLitCanonicalizer) -> Self;
+}
+
+/// The base in which [`canonicalize_int_lits_in`] writes integer literals.
+///
+/// *This type is available if Syn is built with the `"fold"` feature.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone, Copy))]
+pub enum Radix {
+    /// Base 2, written with a `0b` prefix.
+    Binary,
+    /// Base 8, written with a `0o` prefix.
+    Octal,
+    /// Base 10, written without a prefix.
+    Decimal,
+    /// Base 16, written with a `0x` prefix and lowercase digits.
+    Hexadecimal,
+}
+
+impl Default for Radix {
+    fn default() -> Self {
+        Radix::Decimal
+    }
+}
+
+/// Rewrites every integer literal in a syntax tree in decimal with no digit
+/// separators, so that `1_000`, `0x3E8`, and `1000` are all written `1000`.
+///
+/// This is [`canonicalize_int_lits_in`] with [`Radix::Decimal`].
+///
+/// *This function is available if Syn is built with the `"fold"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{canonicalize_int_lits, parse_quote, Expr};
+///
+/// let expr: Expr = parse_quote!([1_000, 0x3E8u16, 0b1111_1010_00]);
+/// let expr = canonicalize_int_lits(expr);
+///
+/// let expected = quote!([1000, 1000u16, 1000]);
+/// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+/// ```
+pub fn canonicalize_int_lits<T: CanonicalizeIntLits>(node: T) -> T {
+    canonicalize_int_lits_in(node, Radix::Decimal)
+}
+
+/// Rewrites every integer literal in a syntax tree in the given base with no
+/// digit separators.
+///
+/// The value and suffix of each literal are preserved, as is its span. There
+/// is no limit on the size of the value, so literals beyond the range of
+/// `u128` are converted without loss as well. Integers that are not written
+/// as literals, like the `0` in the tuple field access `t.0`, are left alone,
+/// as are the tokens inside of macro invocations and attributes.
+///
+/// *This function is available if Syn is built with the `"fold"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{canonicalize_int_lits_in, parse_quote, Expr, Radix};
+///
+/// let expr: Expr = parse_quote!(x & 255u8);
+/// let expr = canonicalize_int_lits_in(expr, Radix::Hexadecimal);
+///
+/// let expected = quote!(x & 0xffu8);
+/// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+/// ```
+pub fn canonicalize_int_lits_in<T: CanonicalizeIntLits>(node: T, radix: Radix) -> T {
+    node.fold_canonicalize_int_lits(&mut IntLitCanonicalizer { radix })
+}
+
+#[doc(hidden)]
+pub struct IntLitCanonicalizer {
+    radix: Radix,
+}
+
+impl Fold for IntLitCanonicalizer {
+    fn fold_lit_int(&mut self, node: LitInt) -> LitInt {
+        let digits = node.base10_digits();
+        let (sign, magnitude) = if digits.starts_with('-') {
+            ("-", &digits[1..])
+        } else {
+            ("", digits)
+        };
+        let (prefix, base) = match self.radix {
+            Radix::Binary => ("0b", 2),
+            Radix::Octal => ("0o", 8),
+            Radix::Decimal => ("", 10),
+            Radix::Hexadecimal => ("0x", 16),
+        };
+        let repr = format!(
+            "{}{}{}{}",
+            sign,
+            prefix,
+            to_base(magnitude, base),
+            node.suffix(),
+        );
+        LitInt::new(&repr, node.span())
+    }
+}
+
+// Converts a string of decimal digits into the given base by repeated long
+// division, so that values of any size are converted exactly.
+fn to_base(decimal: &str, base: u32) -> String {
+    if base == 10 {
+        return decimal.to_owned();
+    }
+
+    let mut dividend: Vec<u32> = decimal.bytes().map(|b| u32::from(b - b'0')).collect();
+    let mut digits = Vec::new();
+    while dividend.iter().any(|&d| d != 0) {
+        let mut remainder = 0;
+        for d in &mut dividend {
+            let value = remainder * 10 + *d;
+            *d = value / base;
+            remainder = value % base;
+        }
+        digits.push(std::char::from_digit(remainder, base).unwrap());
+    }
+
+    if digits.is_empty() {
+        "0".to_owned()
+    } else {
+        digits.iter().rev().collect()
+    }
+}
+
+macro_rules! impl_canonicalize_int_lits {
+    ($($(#[$cfg:meta])* $ty:ident => $fold:ident,)*) => {
+        $(
+            $(#[$cfg])*
+            impl private::Sealed for $ty {}
+
+            $(#[$cfg])*
+            impl CanonicalizeIntLits for $ty {
+                fn fold_canonicalize_int_lits(self, folder: &mut IntLitCanonicalizer) -> Self {
+                    folder.$fold(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_canonicalize_int_lits! {
+    #[cfg(feature = "derive")]
+    DeriveInput => fold_derive_input,
+    Expr => fold_expr,
+    Generics => fold_generics,
+    Lit => fold_lit,
+    LitInt => fold_lit_int,
+    Type => fold_type,
+    #[cfg(feature = "full")]
+    Block => fold_block,
+    #[cfg(feature = "full")]
+    File => fold_file,
+    #[cfg(feature = "full")]
+    ImplItem => fold_impl_item,
+    #[cfg(feature = "full")]
+    Item => fold_item,
+    #[cfg(feature = "full")]
+    ItemFn => fold_item_fn,
+    #[cfg(feature = "full")]
+    Pat => fold_pat,
+    #[cfg(feature = "full")]
+    Stmt => fold_stmt,
+    #[cfg(feature = "full")]
+    TraitItem => fold_trait_item,
+}
+
+mod private {
+    pub trait Sealed {}
+}
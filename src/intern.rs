@@ -0,0 +1,128 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::*;
+
+/// An interned [`Type`], as returned by [`TypeInterner::intern`].
+///
+/// Two ids from the same interner are equal if and only if the types they
+/// were interned from are equal, so comparing ids is a substitute for
+/// comparing the types themselves at the cost of an integer comparison. Ids
+/// from different interners are unrelated.
+///
+/// *This type is available if Syn is built with the `"extra-traits"` and
+/// `"clone-impls"` features.*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InternedType(u32);
+
+impl InternedType {
+    /// The index of this id, counting up from 0 in the order in which
+    /// distinct types were first interned.
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+/// Assigns a [`InternedType`] to each distinct [`Type`] in a syntax tree.
+///
+/// Types are considered equal by the same rules as their `Eq` impl, which
+/// compares their structure and ignores spans, so `Vec<u8>` written in two
+/// different places receives the same id. Types that are written differently
+/// but name the same type, like `Vec<u8>` and `std::vec::Vec<u8>` or a type
+/// with and without parentheses, receive different ids.
+///
+/// The interner keeps a copy of each distinct type it has seen, which is what
+/// [`resolve`] returns.
+///
+/// [`resolve`]: TypeInterner::resolve
+///
+/// *This type is available if Syn is built with the `"extra-traits"` and
+/// `"clone-impls"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, Type, TypeInterner};
+///
+/// let mut interner = TypeInterner::new();
+///
+/// let a = interner.intern(&parse_quote!(Vec<u8>));
+/// let b = interner.intern(&parse_quote!(Option<String>));
+/// let c = interner.intern(&parse_quote!(Vec<u8>));
+///
+/// assert_eq!(a, c);
+/// assert_ne!(a, b);
+/// assert_eq!(interner.len(), 2);
+///
+/// let expected: Type = parse_quote!(Option<String>);
+/// assert_eq!(*interner.resolve(b), expected);
+/// ```
+#[derive(Debug, Default)]
+pub struct TypeInterner {
+    // The ids of the interned types by the hash of the type, so that each
+    // type is stored only once, in `types`.
+    ids: HashMap<u64, Vec<InternedType>>,
+    types: Vec<Type>,
+}
+
+impl TypeInterner {
+    /// Creates an interner that has not interned any types.
+    pub fn new() -> Self {
+        TypeInterner::default()
+    }
+
+    /// Returns the id of the given type, assigning it the next unused id if
+    /// no equal type has been interned before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct types are interned.
+    pub fn intern(&mut self, ty: &Type) -> InternedType {
+        let hash = hash(ty);
+        if let Some(id) = self.lookup(hash, ty) {
+            return id;
+        }
+        assert!(self.types.len() < u32::max_value() as usize);
+        let id = InternedType(self.types.len() as u32);
+        self.ids.entry(hash).or_insert_with(Vec::new).push(id);
+        self.types.push(ty.clone());
+        id
+    }
+
+    /// Returns the id of the given type if an equal type has been interned,
+    /// without interning it otherwise.
+    pub fn get(&self, ty: &Type) -> Option<InternedType> {
+        self.lookup(hash(ty), ty)
+    }
+
+    /// Returns the type that was interned as the given id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id was not returned by this interner.
+    pub fn resolve(&self, id: InternedType) -> &Type {
+        &self.types[id.0 as usize]
+    }
+
+    /// The number of distinct types that have been interned.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Determines whether no types have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    fn lookup(&self, hash: u64, ty: &Type) -> Option<InternedType> {
+        let ids = self.ids.get(&hash)?;
+        ids.iter().cloned().find(|id| self.types[id.0 as usize] == *ty)
+    }
+}
+
+fn hash(ty: &Type) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    ty.hash(&mut hasher);
+    hasher.finish()
+}
@@ -192,6 +192,55 @@ ast_struct! {
     }
 }
 
+impl ItemImpl {
+    /// Determines whether this impl implements a trait, as opposed to an
+    /// inherent impl like `impl Data { ... }`.
+    ///
+    /// Negative impls like `impl !Send for Data {}` count as trait impls.
+    pub fn is_trait_impl(&self) -> bool {
+        self.trait_.is_some()
+    }
+
+    /// Determines whether this is a negative trait impl like `impl !Send for
+    /// Data {}`.
+    pub fn is_negative_impl(&self) -> bool {
+        match &self.trait_ {
+            Some((bang, _, _)) => bang.is_some(),
+            None => false,
+        }
+    }
+
+    /// Returns the path of the trait that this impl implements, or `None` for
+    /// an inherent impl.
+    ///
+    /// For a negative impl like `impl !Send for Data {}` this is the path of
+    /// the trait without the `!`, so that it can be grouped with the other
+    /// impls of the same trait. Use [`is_negative_impl`] to tell the two
+    /// apart.
+    ///
+    /// [`is_negative_impl`]: ItemImpl::is_negative_impl
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemImpl, Path};
+    ///
+    /// let item: ItemImpl = parse_quote! {
+    ///     impl<T> fmt::Display for Wrapper<T> {}
+    /// };
+    /// let expected: Path = parse_quote!(fmt::Display);
+    /// assert_eq!(item.trait_path(), Some(&expected));
+    ///
+    /// let item: ItemImpl = parse_quote! {
+    ///     impl<T> Wrapper<T> {}
+    /// };
+    /// assert_eq!(item.trait_path(), None);
+    /// ```
+    pub fn trait_path(&self) -> Option<&Path> {
+        self.trait_.as_ref().map(|(_, path, _)| path)
+    }
+}
+
 ast_struct! {
     /// A macro invocation, which includes `macro_rules!` definitions.
     ///
@@ -2258,6 +2307,7 @@ pub mod parsing {
             let has_generics = input.peek(Token![<])
                 && (input.peek2(Token![>])
                     || input.peek2(Token![#])
+                    || input.peek2(Token![const])
                     || (input.peek2(Ident) || input.peek2(Lifetime))
                         && (input.peek3(Token![:])
                             || input.peek3(Token![,])
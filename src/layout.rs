@@ -0,0 +1,153 @@
+use proc_macro2::{Delimiter, LineColumn, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Prints a syntax tree node as source code, reproducing the line breaks and
+/// indentation of the code it was parsed from wherever that information is
+/// available.
+///
+/// The layout is recovered from the line and column of each token's span, so
+/// it is only available for tokens that were parsed from a string by Syn or
+/// proc-macro2, as in [`parse_file`] or [`parse_str`], and not for tokens
+/// passed to a procedural macro by the compiler. Where two consecutive tokens
+/// both come from the source, they are separated by exactly the whitespace
+/// that separated them originally. Tokens that do not carry a usable location,
+/// such as ones constructed by `quote!` or by hand, are separated from their
+/// neighbors by a single space, except after a joint punctuation character
+/// and just inside of delimiters, similar to the `Display` impl of
+/// `TokenStream`.
+///
+/// This makes it possible for a tool that rewrites part of a file to print the
+/// whole file back without reformatting the parts it did not touch. Note that
+/// comments are not tokens and are not preserved, although doc comments are
+/// printed as the equivalent `#[doc = "..."]` attributes.
+///
+/// [`parse_file`]: crate::parse_file
+/// [`parse_str`]: crate::parse_str
+///
+/// Line and column information is only tracked by proc-macro2 when its
+/// `"span-locations"` feature is enabled, so the `"layout"` feature turns it
+/// on. Note that this makes every `Span` in the build larger, including the
+/// ones in crates that do not use this function.
+///
+/// *This function is available if Syn is built with the `"layout"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{to_string_with_layout, File};
+///
+/// let source = "fn main() {\n    let x = 1;\n\n    f(x);\n}";
+/// let file: File = syn::parse_str(source).unwrap();
+///
+/// assert_eq!(to_string_with_layout(&file), source);
+/// ```
+pub fn to_string_with_layout<T: ToTokens + ?Sized>(node: &T) -> String {
+    let mut printer = Printer {
+        out: String::new(),
+        prev: None,
+        joint: false,
+    };
+    printer.print_stream(node.to_token_stream());
+    printer.out
+}
+
+struct Printer {
+    out: String,
+    // End of the most recent token that had a location.
+    prev: Option<LineColumn>,
+    // Whether the next token is printed without a space if it has no location,
+    // as after a joint punctuation char or an opening delimiter.
+    joint: bool,
+}
+
+impl Printer {
+    fn print_stream(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => {
+                            self.print_stream(group.stream());
+                            continue;
+                        }
+                    };
+                    // The delimiters are located at either end of the group's
+                    // span, since proc-macro2 does not track their spans
+                    // separately outside of a procedural macro. Without a
+                    // location, delimiters hug their contents.
+                    let span = group.span();
+                    let (start, end) = (span.start(), span.end());
+                    let open_end = LineColumn {
+                        line: start.line,
+                        column: start.column + 1,
+                    };
+                    let close_start = LineColumn {
+                        line: end.line,
+                        column: end.column.saturating_sub(1),
+                    };
+                    self.print_token(start, open_end, open, true);
+                    self.print_stream(group.stream());
+                    self.joint = true;
+                    self.print_token(close_start, end, close, false);
+                }
+                TokenTree::Ident(ident) => {
+                    let span = ident.span();
+                    self.print_token(span.start(), span.end(), &ident.to_string(), false);
+                }
+                TokenTree::Punct(punct) => {
+                    let joint = punct.spacing() == Spacing::Joint;
+                    let span = punct.span();
+                    let repr = punct.as_char().to_string();
+                    self.print_token(span.start(), span.end(), &repr, joint);
+                }
+                TokenTree::Literal(literal) => {
+                    let span = literal.span();
+                    self.print_token(span.start(), span.end(), &literal.to_string(), false);
+                }
+            }
+        }
+    }
+
+    fn print_token(&mut self, start: LineColumn, end: LineColumn, repr: &str, joint: bool) {
+        // Tokens without a real location in the source have an empty span,
+        // such as Span::call_site(), or a line number of 0.
+        let located = start.line > 0 && (start.line, start.column) < (end.line, end.column);
+        // A location that lies before the previous one belongs to a different
+        // source, such as a string parsed by `quote!`, and is not followed.
+        let in_order = match self.prev {
+            Some(prev) => (prev.line, prev.column) <= (start.line, start.column),
+            None => true,
+        };
+
+        match self.prev {
+            Some(prev) if located && in_order => {
+                if start.line > prev.line {
+                    for _ in prev.line..start.line {
+                        self.out.push('\n');
+                    }
+                    for _ in 0..start.column {
+                        self.out.push(' ');
+                    }
+                } else {
+                    for _ in prev.column..start.column {
+                        self.out.push(' ');
+                    }
+                }
+            }
+            _ => {
+                if !self.out.is_empty() && !self.joint {
+                    self.out.push(' ');
+                }
+            }
+        }
+
+        self.out.push_str(repr);
+        self.joint = joint;
+        if located && in_order {
+            self.prev = Some(end);
+        }
+    }
+}
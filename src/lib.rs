@@ -231,6 +231,9 @@
 //!   types.
 //! - **`extra-traits`** — Debug, Eq, PartialEq, Hash impls for all syntax tree
 //!   types.
+//! - **`layout`** — Printing of a syntax tree node that reproduces the line
+//!   breaks and indentation of the source code it was parsed from. Enables
+//!   the `span-locations` feature of proc-macro2.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
 //!   dynamic library libproc_macro from rustc toolchain.
 
@@ -315,16 +318,16 @@ pub use crate::data::{
 mod expr;
 #[cfg(feature = "full")]
 pub use crate::expr::{
-    Arm, FieldValue, GenericMethodArgument, Label, MethodTurbofish, RangeLimits,
+    Arm, FieldValue, GenericMethodArgument, Label, MethodTurbofish, PointerMutability, RangeLimits,
 };
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::expr::{
     Expr, ExprArray, ExprAssign, ExprAssignOp, ExprAsync, ExprAwait, ExprBinary, ExprBlock,
-    ExprBox, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprContinue, ExprField, ExprForLoop,
-    ExprGroup, ExprIf, ExprInPlace, ExprIndex, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch,
-    ExprMethodCall, ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn,
-    ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprType, ExprUnary, ExprUnsafe, ExprWhile,
-    ExprYield, Index, Member,
+    ExprBox, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField,
+    ExprForLoop, ExprGroup, ExprIf, ExprInPlace, ExprIndex, ExprLet, ExprLit, ExprLoop, ExprMacro,
+    ExprMatch, ExprMethodCall, ExprParen, ExprPath, ExprRange, ExprRawAddr, ExprReference,
+    ExprRepeat, ExprReturn, ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprType, ExprUnary,
+    ExprUnsafe, ExprWhile, ExprYield, Index, Member,
 };
 
 #[cfg(any(feature = "full", feature = "derive"))]
@@ -374,7 +377,7 @@ pub use crate::mac::{Macro, MacroDelimiter};
 #[cfg(any(feature = "full", feature = "derive"))]
 mod derive;
 #[cfg(feature = "derive")]
-pub use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
+pub use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveFields, DeriveInput};
 
 #[cfg(any(feature = "full", feature = "derive"))]
 mod op;
@@ -437,6 +440,148 @@ pub mod parse_macro_input;
 #[cfg(all(feature = "parsing", feature = "printing"))]
 pub mod spanned;
 
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "visit-mut"))]
+mod clear_spans;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "visit-mut"))]
+pub use crate::clear_spans::{clear_spans, ClearSpans};
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+mod dedent;
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+pub use crate::dedent::dedent_str_lits;
+
+#[cfg(all(feature = "full", feature = "visit"))]
+mod nesting;
+#[cfg(all(feature = "full", feature = "visit"))]
+pub use crate::nesting::max_nesting_depth;
+
+#[cfg(all(feature = "full", feature = "visit"))]
+mod imports;
+#[cfg(all(feature = "full", feature = "visit"))]
+pub use crate::imports::unused_imports;
+
+#[cfg(all(feature = "full", feature = "visit"))]
+mod suspend;
+#[cfg(all(feature = "full", feature = "visit"))]
+pub use crate::suspend::{contains_await, contains_yield};
+
+#[cfg(all(feature = "full", feature = "visit"))]
+mod unsafe_usage;
+#[cfg(all(feature = "full", feature = "visit"))]
+pub use crate::unsafe_usage::{unsafe_usage, UnsafeStats};
+
+#[cfg(all(feature = "full", feature = "visit"))]
+mod method_calls;
+#[cfg(all(feature = "full", feature = "visit"))]
+pub use crate::method_calls::method_calls;
+
+#[cfg(all(feature = "full", feature = "visit", feature = "parsing"))]
+mod todos;
+#[cfg(all(feature = "full", feature = "visit", feature = "parsing"))]
+pub use crate::todos::find_todos;
+
+#[cfg(all(feature = "full", feature = "parsing"))]
+mod macro_rules;
+#[cfg(all(feature = "full", feature = "parsing"))]
+pub use crate::macro_rules::{
+    Fragment, FragmentKind, MacroRule, Matcher, MatcherGroup, Repetition, RepetitionOp,
+};
+
+#[cfg(all(feature = "full", feature = "visit", feature = "printing"))]
+mod fns;
+#[cfg(all(feature = "full", feature = "visit", feature = "printing"))]
+pub use crate::fns::{collect_fns, FnInfo};
+
+#[cfg(all(feature = "full", feature = "visit", feature = "clone-impls"))]
+mod strings;
+#[cfg(all(feature = "full", feature = "visit", feature = "clone-impls"))]
+pub use crate::strings::string_literals;
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "fold"))]
+mod int_lits;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "fold"))]
+pub use crate::int_lits::{
+    canonicalize_int_lits, canonicalize_int_lits_in, CanonicalizeIntLits, Radix,
+};
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod apit;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::apit::desugar_apit;
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod instrument;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::instrument::instrument_fns;
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod signatures;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::signatures::to_signatures;
+
+#[cfg(feature = "full")]
+mod trait_items;
+#[cfg(feature = "full")]
+pub use crate::trait_items::{provided_methods, required_consts, required_methods};
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod unary;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::unary::simplify_unary;
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod blocks;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::blocks::{collapse_trivial_blocks, CollapseTrivialBlocks};
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod tail_return;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::tail_return::elide_tail_return;
+
+#[cfg(all(feature = "full", feature = "fold"))]
+mod desugar_for;
+#[cfg(all(feature = "full", feature = "fold"))]
+pub use crate::desugar_for::desugar_for;
+
+#[cfg(all(feature = "parsing", feature = "full"))]
+mod file_cache;
+#[cfg(all(feature = "parsing", feature = "full"))]
+pub use crate::file_cache::FileCache;
+
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+mod inline_consts;
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+pub use crate::inline_consts::inline_consts;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "extra-traits",
+    feature = "clone-impls"
+))]
+mod intern;
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "extra-traits",
+    feature = "clone-impls"
+))]
+pub use crate::intern::{InternedType, TypeInterner};
+
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+mod substitute;
+#[cfg(all(feature = "full", feature = "fold", feature = "clone-impls"))]
+pub use crate::substitute::{substitute_ident, SubstituteIdent};
+
+#[cfg(feature = "layout")]
+mod layout;
+#[cfg(feature = "layout")]
+pub use crate::layout::to_string_with_layout;
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+mod repr;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+pub use crate::repr::{parse_repr, Repr, ReprKind};
+
 mod gen {
     /// Syntax tree traversal to walk a shared borrow of a syntax tree.
     ///
@@ -778,7 +923,7 @@ mod rustdoc_workaround {
 ////////////////////////////////////////////////////////////////////////////////
 
 mod error;
-pub use crate::error::{Error, Result};
+pub use crate::error::{Error, Errors, Result};
 
 /// Parse tokens of source code into the chosen syntax tree node.
 ///
@@ -916,7 +1061,17 @@ pub fn parse_str<T: parse::Parse>(s: &str) -> Result<T> {
 /// # }
 /// ```
 #[cfg(all(feature = "parsing", feature = "full"))]
-pub fn parse_file(mut content: &str) -> Result<File> {
+pub fn parse_file(content: &str) -> Result<File> {
+    let (shebang, content) = split_shebang(content);
+    let mut file: File = parse_str(content)?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+// Strips the byte order mark and splits off the shebang line of the content
+// of a file, neither of which are tokens.
+#[cfg(all(feature = "parsing", feature = "full"))]
+fn split_shebang(mut content: &str) -> (Option<String>, &str) {
     // Strip the BOM if it is present
     const BOM: &str = "\u{feff}";
     if content.starts_with(BOM) {
@@ -934,7 +1089,5 @@ pub fn parse_file(mut content: &str) -> Result<File> {
         }
     }
 
-    let mut file: File = parse_str(content)?;
-    file.shebang = shebang;
-    Ok(file)
+    (shebang, content)
 }
@@ -63,6 +63,17 @@ impl Lifetime {
             ident: Ident::new(&symbol[1..], span),
         }
     }
+
+    /// Determines whether this is the `'static` lifetime.
+    pub fn is_static(&self) -> bool {
+        self.ident == "static"
+    }
+
+    /// Determines whether this is the elided lifetime `'_`, whose value is
+    /// inferred by the compiler.
+    pub fn is_elided(&self) -> bool {
+        self.ident == "_"
+    }
 }
 
 impl Display for Lifetime {
@@ -59,8 +59,12 @@ ast_enum_of_structs! {
         /// A boolean literal: `true` or `false`.
         Bool(LitBool),
 
-        /// A raw token literal not interpreted by Syn, possibly because it
-        /// represents an integer larger than 64 bits.
+        /// A raw token literal not interpreted by Syn.
+        ///
+        /// Any literal token that Syn does not recognize as one of the other
+        /// kinds is preserved as-is in this variant rather than rejected, so
+        /// that literal syntax added to the language in the future still
+        /// parses and prints back unchanged.
         Verbatim(Literal),
     }
 }
@@ -345,6 +349,11 @@ impl LitStr {
     pub fn suffix(&self) -> &str {
         &self.repr.suffix
     }
+
+    #[cfg(all(feature = "full", feature = "visit-mut"))]
+    pub(crate) fn token(&self) -> &Literal {
+        &self.repr.token
+    }
 }
 
 impl LitByteStr {
@@ -439,6 +448,74 @@ impl LitInt {
     pub fn set_span(&mut self, span: Span) {
         self.repr.token.set_span(span)
     }
+
+    /// Determines whether the value of this literal is in the range of the
+    /// type named by its suffix, so that `255u8` fits but `256u8` does not.
+    ///
+    /// An unsuffixed literal always fits, since its type is inferred, and a
+    /// literal with a suffix that is not an integer type never fits. The
+    /// range of `isize` and `usize` is taken to be that of a 64-bit target.
+    ///
+    /// The minus sign of a negative literal in an expression like `-128i8` is
+    /// not part of the literal, which is `128i8` and does not fit. Code that
+    /// checks expressions needs to allow for this case itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use proc_macro2::Span;
+    /// use syn::LitInt;
+    ///
+    /// assert!(LitInt::new("255u8", Span::call_site()).fits_suffix());
+    /// assert!(!LitInt::new("256u8", Span::call_site()).fits_suffix());
+    /// assert!(!LitInt::new("128i8", Span::call_site()).fits_suffix());
+    /// assert!(LitInt::new("1_000_000", Span::call_site()).fits_suffix());
+    /// ```
+    pub fn fits_suffix(&self) -> bool {
+        let digits = self.base10_digits();
+        match self.suffix() {
+            "" => true,
+            "i8" => digits.parse::<i8>().is_ok(),
+            "i16" => digits.parse::<i16>().is_ok(),
+            "i32" => digits.parse::<i32>().is_ok(),
+            "i64" | "isize" => digits.parse::<i64>().is_ok(),
+            "i128" => digits.parse::<i128>().is_ok(),
+            "u8" => digits.parse::<u8>().is_ok(),
+            "u16" => digits.parse::<u16>().is_ok(),
+            "u32" => digits.parse::<u32>().is_ok(),
+            "u64" | "usize" => digits.parse::<u64>().is_ok(),
+            "u128" => digits.parse::<u128>().is_ok(),
+            _ => false,
+        }
+    }
+
+    /// Returns an error spanning this literal if its value is not in the
+    /// range of the type named by its suffix, as determined by
+    /// [`fits_suffix`].
+    ///
+    /// [`fits_suffix`]: LitInt::fits_suffix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, LitInt};
+    ///
+    /// let lit: LitInt = parse_quote!(256u8);
+    /// let err = lit.check().unwrap_err();
+    /// assert_eq!(err.to_string(), "literal out of range for `u8`");
+    /// ```
+    pub fn check(&self) -> crate::Result<()> {
+        if self.fits_suffix() {
+            return Ok(());
+        }
+        let suffix = self.suffix();
+        let message = match suffix {
+            "i8" | "i16" | "i32" | "i64" | "isize" | "i128" | "u8" | "u16" | "u32" | "u64"
+            | "usize" | "u128" => format!("literal out of range for `{}`", suffix),
+            _ => format!("invalid suffix `{}` for integer literal", suffix),
+        };
+        Err(crate::Error::new(self.span(), message))
+    }
 }
 
 impl From<Literal> for LitInt {
@@ -812,6 +889,9 @@ mod value {
 
     impl Lit {
         /// Interpret a Syn literal from a proc-macro2 literal.
+        ///
+        /// A literal that is not recognized as any of the other kinds becomes
+        /// `Lit::Verbatim`.
         pub fn new(token: Literal) -> Self {
             let repr = token.to_string();
 
@@ -867,7 +947,7 @@ mod value {
                 _ => {}
             }
 
-            panic!("Unrecognized literal: `{}`", repr);
+            Lit::Verbatim(token)
         }
     }
 
@@ -0,0 +1,419 @@
+use std::iter::Peekable;
+
+use proc_macro2::{token_stream, Delimiter, Spacing, Span, TokenStream, TokenTree};
+
+use crate::parse::{Parse, ParseStream};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// One rule of a `macro_rules!` definition: `($a:expr) => { ... }`.
+///
+/// The matcher and transcriber are kept as unparsed tokens. Use
+/// [`parse_matcher`] to interpret the matcher.
+///
+/// [`parse_matcher`]: MacroRule::parse_matcher
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct MacroRule {
+    pub matcher_delimiter: MacroDelimiter,
+    /// The tokens inside of the matcher's delimiters.
+    pub matcher: TokenStream,
+    pub fat_arrow_token: Token![=>],
+    pub transcriber_delimiter: MacroDelimiter,
+    /// The tokens inside of the transcriber's delimiters.
+    pub transcriber: TokenStream,
+}
+
+/// One element of a `macro_rules!` matcher, as returned by
+/// [`MacroRule::parse_matcher`].
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum Matcher {
+    /// An identifier, punctuation character, or literal that must appear
+    /// exactly as written, like the `,` in `$a:expr, $b:expr`.
+    Token(TokenTree),
+    /// A delimited group that must appear with the same delimiters and whose
+    /// contents are matched in turn: `[$($elem:expr),*]`.
+    Group(MatcherGroup),
+    /// A metavariable with a fragment specifier: `$name:ident`.
+    Fragment(Fragment),
+    /// A repetition: `$($key:expr => $value:expr),*`.
+    Repetition(Repetition),
+}
+
+/// A delimited group in a `macro_rules!` matcher.
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct MatcherGroup {
+    pub delimiter: Delimiter,
+    pub span: Span,
+    pub contents: Vec<Matcher>,
+}
+
+/// A metavariable in a `macro_rules!` matcher: `$name:ident`.
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Fragment {
+    pub dollar_span: Span,
+    /// The `name` in `$name:ident`.
+    pub name: Ident,
+    /// The `ident` in `$name:ident`, whose meaning is given by `kind`.
+    pub specifier: Ident,
+    pub kind: FragmentKind,
+}
+
+/// The kind of syntax matched by a metavariable, named by its fragment
+/// specifier.
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone, Copy))]
+pub enum FragmentKind {
+    /// `block`
+    Block,
+    /// `expr`
+    Expr,
+    /// `expr_2021`, which unlike `expr` does not match `const` blocks or
+    /// `_` in the 2024 edition.
+    Expr2021,
+    /// `ident`
+    Ident,
+    /// `item`
+    Item,
+    /// `lifetime`
+    Lifetime,
+    /// `literal`
+    Literal,
+    /// `meta`
+    Meta,
+    /// `pat`
+    Pat,
+    /// `pat_param`, which unlike `pat` does not match top-level or-patterns
+    /// in the 2021 edition.
+    PatParam,
+    /// `path`
+    Path,
+    /// `stmt`
+    Stmt,
+    /// `tt`
+    Tt,
+    /// `ty`
+    Ty,
+    /// `vis`
+    Vis,
+}
+
+/// A repetition in a `macro_rules!` matcher: `$( ... ) sep op`.
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Repetition {
+    pub dollar_span: Span,
+    /// The span of the parentheses around the repeated matchers.
+    pub span: Span,
+    pub contents: Vec<Matcher>,
+    /// The separator between repetitions, like the `,` in `$($x:expr),*`.
+    /// This is usually a single token, but an operator made of several
+    /// punctuation characters like `=>` is kept together.
+    pub separator: Option<TokenStream>,
+    pub op: RepetitionOp,
+}
+
+/// The operator of a repetition in a `macro_rules!` matcher.
+///
+/// *This type is available if Syn is built with the `"full"` and `"parsing"`
+/// features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone, Copy))]
+pub enum RepetitionOp {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
+
+impl ItemMacro {
+    /// Parses the rules of a `macro_rules!` definition.
+    ///
+    /// Returns an error if the item is not a `macro_rules!` definition or if
+    /// its body is not a sequence of `matcher => transcriber` rules separated
+    /// by semicolons.
+    ///
+    /// *This function is available if Syn is built with the `"full"` and
+    /// `"parsing"` features.*
+    pub fn rules(&self) -> Result<Vec<MacroRule>> {
+        if !self.mac.path.is_ident("macro_rules") {
+            return Err(Error::new(
+                self.mac.bang_token.spans[0],
+                "expected a `macro_rules!` definition",
+            ));
+        }
+        let rules = self
+            .mac
+            .parse_body_with(Punctuated::<MacroRule, Token![;]>::parse_terminated)?;
+        Ok(rules.into_iter().collect())
+    }
+
+    /// Determines whether this is a `macro_rules!` definition exported from
+    /// its crate by a `#[macro_export]` attribute, including the
+    /// `#[macro_export(local_inner_macros)]` form.
+    ///
+    /// A `macro_rules!` definition cannot be written with a visibility like
+    /// `pub`. Without `#[macro_export]` it is reachable only within its crate,
+    /// after its definition in textual order or through a `use` of it, such as
+    /// `pub(crate) use example;`, which is a separate `ItemUse`.
+    ///
+    /// *This function is available if Syn is built with the `"full"` and
+    /// `"parsing"` features.*
+    pub fn is_macro_export(&self) -> bool {
+        self.mac.path.is_ident("macro_rules")
+            && self
+                .attrs
+                .iter()
+                .any(|attr| attr.path.is_ident("macro_export"))
+    }
+}
+
+impl MacroRule {
+    /// Parses the matcher of this rule into its literal tokens, metavariables,
+    /// and repetitions.
+    ///
+    /// Returns an error for a metavariable without a valid fragment
+    /// specifier, such as `$x` or `$x:expression`, and for a repetition
+    /// without an operator, with an invalid separator, or with a separator
+    /// before the `?` operator, which does not take one. Whether a fragment
+    /// may be followed by the token after it is not checked.
+    ///
+    /// *This function is available if Syn is built with the `"full"` and
+    /// `"parsing"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, FragmentKind, ItemMacro, Matcher, RepetitionOp};
+    ///
+    /// let item: ItemMacro = parse_quote! {
+    ///     macro_rules! hashmap {
+    ///         ($($key:expr => $value:expr),* $(,)?) => {{
+    ///             let mut map = HashMap::new();
+    ///             $(map.insert($key, $value);)*
+    ///             map
+    ///         }};
+    ///     }
+    /// };
+    ///
+    /// let rules = item.rules().unwrap();
+    /// let matcher = rules[0].parse_matcher().unwrap();
+    ///
+    /// let entries = match &matcher[0] {
+    ///     Matcher::Repetition(rep) => rep,
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(entries.op, RepetitionOp::ZeroOrMore);
+    /// assert_eq!(entries.separator.as_ref().unwrap().to_string(), ",");
+    ///
+    /// match &entries.contents[0] {
+    ///     Matcher::Fragment(fragment) => {
+    ///         assert_eq!(fragment.name, "key");
+    ///         assert_eq!(fragment.kind, FragmentKind::Expr);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn parse_matcher(&self) -> Result<Vec<Matcher>> {
+        parse_matchers(self.matcher.clone())
+    }
+}
+
+impl Parse for MacroRule {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let (matcher_delimiter, matcher) = mac::parse_delimiter(input)?;
+        let fat_arrow_token: Token![=>] = input.parse()?;
+        let (transcriber_delimiter, transcriber) = mac::parse_delimiter(input)?;
+        Ok(MacroRule {
+            matcher_delimiter,
+            matcher,
+            fat_arrow_token,
+            transcriber_delimiter,
+            transcriber,
+        })
+    }
+}
+
+type Tokens = Peekable<token_stream::IntoIter>;
+
+fn parse_matchers(tokens: TokenStream) -> Result<Vec<Matcher>> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut matchers = Vec::new();
+    while let Some(token) = tokens.next() {
+        let matcher = match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == '$' => {
+                parse_metavariable(punct.span(), &mut tokens)?
+            }
+            TokenTree::Group(group) => Matcher::Group(MatcherGroup {
+                delimiter: group.delimiter(),
+                span: group.span(),
+                contents: parse_matchers(group.stream())?,
+            }),
+            token => Matcher::Token(token),
+        };
+        matchers.push(matcher);
+    }
+    Ok(matchers)
+}
+
+fn parse_metavariable(dollar_span: Span, tokens: &mut Tokens) -> Result<Matcher> {
+    match tokens.next() {
+        Some(TokenTree::Ident(name)) => {
+            match tokens.next() {
+                Some(TokenTree::Punct(ref punct)) if punct.as_char() == ':' => {}
+                _ => return Err(Error::new(name.span(), "missing fragment specifier")),
+            }
+            let specifier = match tokens.next() {
+                Some(TokenTree::Ident(specifier)) => specifier,
+                _ => return Err(Error::new(name.span(), "missing fragment specifier")),
+            };
+            let kind = match fragment_kind(&specifier.to_string()) {
+                Some(kind) => kind,
+                None => {
+                    return Err(Error::new(
+                        specifier.span(),
+                        format!("invalid fragment specifier `{}`", specifier),
+                    ))
+                }
+            };
+            Ok(Matcher::Fragment(Fragment {
+                dollar_span,
+                name,
+                specifier,
+                kind,
+            }))
+        }
+        Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let contents = parse_matchers(group.stream())?;
+            let (separator, op) = parse_separator_and_op(group.span(), tokens)?;
+            Ok(Matcher::Repetition(Repetition {
+                dollar_span,
+                span: group.span(),
+                contents,
+                separator,
+                op,
+            }))
+        }
+        _ => Err(Error::new(
+            dollar_span,
+            "expected identifier or `(` after `$`",
+        )),
+    }
+}
+
+fn parse_separator_and_op(
+    span: Span,
+    tokens: &mut Tokens,
+) -> Result<(Option<TokenStream>, RepetitionOp)> {
+    let first = match tokens.next() {
+        Some(token) => token,
+        None => return Err(expected_op(span)),
+    };
+    if let Some(op) = repetition_op(&first) {
+        return Ok((None, op));
+    }
+
+    let mut separator = TokenStream::new();
+    let mut joint = match &first {
+        TokenTree::Punct(punct) => punct.spacing() == Spacing::Joint,
+        TokenTree::Ident(_) | TokenTree::Literal(_) => false,
+        TokenTree::Group(group) => {
+            return Err(Error::new(group.span(), "invalid repetition separator"));
+        }
+    };
+    let mut span = first.span();
+    separator.extend(Some(first));
+
+    // A separator made of several punctuation characters, like `=>`, is one
+    // token to the compiler but several joint `Punct`s here. The operator is
+    // not part of the separator even if it is joint to it, as in `,*`.
+    while joint {
+        let punct = match tokens.peek() {
+            Some(TokenTree::Punct(punct)) => punct.clone(),
+            _ => break,
+        };
+        if repetition_op(&TokenTree::Punct(punct.clone())).is_some() {
+            break;
+        }
+        tokens.next();
+        joint = punct.spacing() == Spacing::Joint;
+        span = punct.span();
+        separator.extend(Some(TokenTree::Punct(punct)));
+    }
+
+    let op = match tokens.next() {
+        Some(token) => match repetition_op(&token) {
+            Some(RepetitionOp::ZeroOrOne) => {
+                return Err(Error::new(
+                    token.span(),
+                    "the `?` macro repetition operator does not take a separator",
+                ));
+            }
+            Some(op) => op,
+            None => return Err(expected_op(token.span())),
+        },
+        None => return Err(expected_op(span)),
+    };
+    Ok((Some(separator), op))
+}
+
+fn expected_op(span: Span) -> Error {
+    Error::new(span, "expected one of: `*`, `+`, or `?`")
+}
+
+fn repetition_op(token: &TokenTree) -> Option<RepetitionOp> {
+    match token {
+        TokenTree::Punct(punct) => match punct.as_char() {
+            '*' => Some(RepetitionOp::ZeroOrMore),
+            '+' => Some(RepetitionOp::OneOrMore),
+            '?' => Some(RepetitionOp::ZeroOrOne),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn fragment_kind(specifier: &str) -> Option<FragmentKind> {
+    let kind = match specifier {
+        "block" => FragmentKind::Block,
+        "expr" => FragmentKind::Expr,
+        "expr_2021" => FragmentKind::Expr2021,
+        "ident" => FragmentKind::Ident,
+        "item" => FragmentKind::Item,
+        "lifetime" => FragmentKind::Lifetime,
+        "literal" => FragmentKind::Literal,
+        "meta" => FragmentKind::Meta,
+        "pat" => FragmentKind::Pat,
+        "pat_param" => FragmentKind::PatParam,
+        "path" => FragmentKind::Path,
+        "stmt" => FragmentKind::Stmt,
+        "tt" => FragmentKind::Tt,
+        "ty" => FragmentKind::Ty,
+        "vis" => FragmentKind::Vis,
+        _ => return None,
+    };
+    Some(kind)
+}
@@ -0,0 +1,96 @@
+use crate::visit::Visit;
+use crate::*;
+
+/// Counts the calls to each method name in a file.
+///
+/// Each distinct method name is returned once along with the number of times
+/// it is called, in the order in which the first call to it appears. Only
+/// method call expressions like `x.len()` or `iter.map::<T, _>(f)` are
+/// counted. Calls to free functions like `len(x)` and to associated functions
+/// through a path like `Vec::new()` or `<T as Default>::default()` are not,
+/// even if they refer to a method. Methods are counted by name alone, so calls
+/// to `len` on unrelated types are tallied together, as are calls to a raw
+/// identifier like `x.r#len()` and the same name written as `x.len()`.
+///
+/// Calls inside of macro invocations like `println!("{}", x.len())` are not
+/// counted, since the tokens of a macro are not parsed as expressions.
+///
+/// *This function is available if Syn is built with the `"full"` and `"visit"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{method_calls, parse_quote, File};
+///
+/// let file: File = parse_quote! {
+///     fn main() {
+///         let mut v = Vec::new();
+///         v.push(1);
+///         v.push(2);
+///         let n = v.iter().map(|x| x.pow(2)).count();
+///     }
+/// };
+///
+/// let calls: Vec<(String, usize)> = method_calls(&file)
+///     .into_iter()
+///     .map(|(ident, count)| (ident.to_string(), count))
+///     .collect();
+///
+/// assert_eq!(
+///     calls,
+///     [
+///         ("push".to_owned(), 2),
+///         ("iter".to_owned(), 1),
+///         ("map".to_owned(), 1),
+///         ("pow".to_owned(), 1),
+///         ("count".to_owned(), 1),
+///     ],
+/// );
+/// ```
+pub fn method_calls(file: &File) -> Vec<(Ident, usize)> {
+    let mut visitor = MethodCalls { calls: Vec::new() };
+    visitor.visit_file(file);
+    visitor.calls
+}
+
+struct MethodCalls {
+    calls: Vec<(Ident, usize)>,
+}
+
+impl<'ast> Visit<'ast> for MethodCalls {
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        // The receiver comes first in the source, so that the calls of a
+        // chain like `v.iter().count()` are recorded from left to right.
+        for attr in &node.attrs {
+            self.visit_attribute(attr);
+        }
+        self.visit_expr(&node.receiver);
+
+        let name = unraw(&node.method);
+        match self
+            .calls
+            .iter_mut()
+            .find(|(ident, _)| unraw(ident) == name)
+        {
+            Some((_, count)) => *count += 1,
+            None => self.calls.push((node.method.clone(), 1)),
+        }
+
+        if let Some(turbofish) = &node.turbofish {
+            self.visit_method_turbofish(turbofish);
+        }
+        for arg in &node.args {
+            self.visit_expr(arg);
+        }
+    }
+}
+
+fn unraw(ident: &Ident) -> String {
+    let name = ident.to_string();
+    if name.starts_with("r#") {
+        name[2..].to_owned()
+    } else {
+        name
+    }
+}
@@ -0,0 +1,123 @@
+use crate::visit::Visit;
+use crate::*;
+
+/// Computes how deeply control flow and blocks nest within the body of a
+/// function.
+///
+/// Every `if`, `match`, `loop`, `while`, `for`, and bare or `unsafe` block
+/// expression adds one level of nesting to everything inside of its body. The
+/// function body itself is at depth 0, so a function containing only
+/// straight-line code has a nesting depth of 0.
+///
+/// - The arms of a `match` are at the same level as one another; a braced arm
+///   body does not add a level of its own.
+/// - An `else if` is at the same level as the `if` it continues, so a chain of
+///   `else if` branches is not counted as increasingly nested.
+/// - Conditions, scrutinees, and loop iterators are counted at the level of
+///   the construct they belong to, not inside of it.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"visit"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{max_nesting_depth, parse_quote, ItemFn};
+///
+/// let item: ItemFn = parse_quote! {
+///     fn f(x: Option<u8>) {
+///         if let Some(x) = x {
+///             for _ in 0..x {}
+///         } else if true {
+///             loop {}
+///         }
+///     }
+/// };
+///
+/// assert_eq!(max_nesting_depth(&item), 2);
+/// ```
+pub fn max_nesting_depth(item: &ItemFn) -> u32 {
+    let mut visitor = NestingDepth { depth: 0, max: 0 };
+    visitor.visit_body(&item.block);
+    visitor.max
+}
+
+struct NestingDepth {
+    depth: u32,
+    max: u32,
+}
+
+impl NestingDepth {
+    fn nested<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        self.depth += 1;
+        if self.depth > self.max {
+            self.max = self.depth;
+        }
+        f(self);
+        self.depth -= 1;
+    }
+
+    fn visit_body(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            self.visit_stmt(stmt);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for NestingDepth {
+    fn visit_expr_block(&mut self, node: &'ast ExprBlock) {
+        self.nested(|v| v.visit_body(&node.block));
+    }
+
+    fn visit_expr_unsafe(&mut self, node: &'ast ExprUnsafe) {
+        self.nested(|v| v.visit_body(&node.block));
+    }
+
+    fn visit_expr_if(&mut self, node: &'ast ExprIf) {
+        self.visit_expr(&node.cond);
+        self.nested(|v| v.visit_body(&node.then_branch));
+        if let Some((_else_token, else_branch)) = &node.else_branch {
+            match &**else_branch {
+                Expr::If(_) => self.visit_expr(else_branch),
+                Expr::Block(block) => self.nested(|v| v.visit_body(&block.block)),
+                other => self.nested(|v| v.visit_expr(other)),
+            }
+        }
+    }
+
+    fn visit_expr_match(&mut self, node: &'ast ExprMatch) {
+        self.visit_expr(&node.expr);
+        self.nested(|v| {
+            for arm in &node.arms {
+                v.visit_pat(&arm.pat);
+                if let Some((_if_token, guard)) = &arm.guard {
+                    v.visit_expr(guard);
+                }
+                match &*arm.body {
+                    Expr::Block(block) if block.label.is_none() => v.visit_body(&block.block),
+                    body => v.visit_expr(body),
+                }
+            }
+        });
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        self.nested(|v| v.visit_body(&node.body));
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        self.visit_expr(&node.cond);
+        self.nested(|v| v.visit_body(&node.body));
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        self.visit_pat(&node.pat);
+        self.visit_expr(&node.expr);
+        self.nested(|v| v.visit_body(&node.body));
+    }
+
+    fn visit_item(&mut self, _node: &'ast Item) {
+        // Nested items are measured on their own and do not contribute to the
+        // nesting depth of the enclosing function.
+    }
+}
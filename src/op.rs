@@ -80,6 +80,93 @@ ast_enum! {
     }
 }
 
+impl BinOp {
+    /// Returns the operator as it is written in source code, such as `"+"` or
+    /// `"<<="`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinOp::Add(_) => "+",
+            BinOp::Sub(_) => "-",
+            BinOp::Mul(_) => "*",
+            BinOp::Div(_) => "/",
+            BinOp::Rem(_) => "%",
+            BinOp::And(_) => "&&",
+            BinOp::Or(_) => "||",
+            BinOp::BitXor(_) => "^",
+            BinOp::BitAnd(_) => "&",
+            BinOp::BitOr(_) => "|",
+            BinOp::Shl(_) => "<<",
+            BinOp::Shr(_) => ">>",
+            BinOp::Eq(_) => "==",
+            BinOp::Lt(_) => "<",
+            BinOp::Le(_) => "<=",
+            BinOp::Ne(_) => "!=",
+            BinOp::Ge(_) => ">=",
+            BinOp::Gt(_) => ">",
+            BinOp::AddEq(_) => "+=",
+            BinOp::SubEq(_) => "-=",
+            BinOp::MulEq(_) => "*=",
+            BinOp::DivEq(_) => "/=",
+            BinOp::RemEq(_) => "%=",
+            BinOp::BitXorEq(_) => "^=",
+            BinOp::BitAndEq(_) => "&=",
+            BinOp::BitOrEq(_) => "|=",
+            BinOp::ShlEq(_) => "<<=",
+            BinOp::ShrEq(_) => ">>=",
+        }
+    }
+
+    /// Whether this is one of the comparison operators `==`, `!=`, `<`, `<=`,
+    /// `>`, or `>=`.
+    pub fn is_comparison(&self) -> bool {
+        match self {
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this is a compound assignment operator like `+=` or `<<=`.
+    ///
+    /// Expressions using these operators are represented as
+    /// [`Expr::AssignOp`] rather than [`Expr::Binary`]. Plain assignment with
+    /// `=` is not a `BinOp` at all and is represented as [`Expr::Assign`].
+    ///
+    /// [`Expr::AssignOp`]: enum.Expr.html#variant.AssignOp
+    /// [`Expr::Binary`]: enum.Expr.html#variant.Binary
+    /// [`Expr::Assign`]: enum.Expr.html#variant.Assign
+    pub fn is_assign(&self) -> bool {
+        match self {
+            BinOp::AddEq(_)
+            | BinOp::SubEq(_)
+            | BinOp::MulEq(_)
+            | BinOp::DivEq(_)
+            | BinOp::RemEq(_)
+            | BinOp::BitXorEq(_)
+            | BinOp::BitAndEq(_)
+            | BinOp::BitOrEq(_)
+            | BinOp::ShlEq(_)
+            | BinOp::ShrEq(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl UnOp {
+    /// Returns the operator as it is written in source code, such as `"!"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnOp::Deref(_) => "*",
+            UnOp::Not(_) => "!",
+            UnOp::Neg(_) => "-",
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
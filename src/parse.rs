@@ -1146,6 +1146,20 @@ where
     }
 }
 
+// Parses a syntax tree node from a buffer whose tokens may be parsed again
+// later, instead of from a token stream that is consumed.
+#[cfg(feature = "full")]
+pub(crate) fn parse_buffer<T: Parse>(tokens: &TokenBuffer) -> Result<T> {
+    let state = tokens_to_parse_buffer(tokens);
+    let node = T::parse(&state)?;
+    state.check_unexpected()?;
+    if state.is_empty() {
+        Ok(node)
+    } else {
+        Err(state.error("unexpected token"))
+    }
+}
+
 pub(crate) fn parse_scoped<F: Parser>(f: F, scope: Span, tokens: TokenStream) -> Result<F::Output> {
     f.__parse_scoped(scope, tokens)
 }
@@ -62,8 +62,9 @@
 /// # Panics
 ///
 /// Panics if the tokens fail to parse as the expected syntax tree type. The
-/// caller is responsible for ensuring that the input tokens are syntactically
-/// valid.
+/// panic message includes the interpolated tokens along with the parse error.
+/// The caller is responsible for ensuring that the input tokens are
+/// syntactically valid.
 //
 // TODO: allow Punctuated to be inferred as intra doc link, currently blocked on
 // https://github.com/rust-lang/rust/issues/62834
@@ -88,9 +89,9 @@ use proc_macro2::TokenStream;
 #[doc(hidden)]
 pub fn parse<T: ParseQuote>(token_stream: TokenStream) -> T {
     let parser = T::parse;
-    match parser.parse2(token_stream) {
+    match parser.parse2(token_stream.clone()) {
         Ok(t) => t,
-        Err(err) => panic!("{}", err),
+        Err(err) => panic!("parse_quote! failed to parse `{}`: {}", token_stream, err),
     }
 }
 
@@ -107,6 +107,33 @@ ast_struct! {
     }
 }
 
+impl PatIdent {
+    /// Determines whether this pattern is the `self` parameter of a method
+    /// with an explicit type, as in `self: Box<Self>`.
+    ///
+    /// The `self` of a method written without a type, like `&mut self`, is
+    /// parsed as a [`Receiver`] instead. A binding with a subpattern is not
+    /// matched, since `self` cannot have one.
+    ///
+    /// [`Receiver`]: struct.Receiver.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, FnArg, Pat};
+    ///
+    /// let arg: FnArg = parse_quote!(self: Box<Self>);
+    /// if let FnArg::Typed(arg) = arg {
+    ///     if let Pat::Ident(pat) = *arg.pat {
+    ///         assert!(pat.is_self_value());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_self_value(&self) -> bool {
+        self.subpat.is_none() && self.ident == "self"
+    }
+}
+
 ast_struct! {
     /// A literal pattern: `0`.
     ///
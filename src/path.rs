@@ -26,6 +26,116 @@ where
     }
 }
 
+impl Path {
+    /// Determines whether two paths name the same target, disregarding any
+    /// generic arguments on their segments.
+    ///
+    /// For them to match, it must be the case that:
+    ///
+    /// - either both paths or neither path has a leading colon,
+    /// - the number of path segments is the same,
+    /// - and the ident of each path segment is equal to the ident of the
+    ///   corresponding segment in the other path.
+    ///
+    /// Angle bracketed and parenthesized path arguments are ignored, so
+    /// `serde::Serialize` matches `serde::Serialize::<T>` and `Fn` matches
+    /// `Fn(u8) -> u8`. This is a coarser notion of equality than the strict
+    /// `==` provided by the `"extra-traits"` feature, which additionally
+    /// requires the path arguments of every segment to be equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Path};
+    ///
+    /// let plain: Path = parse_quote!(serde::Serialize);
+    /// let turbofish: Path = parse_quote!(serde::Serialize::<T>);
+    /// assert!(plain.matches_ignoring_args(&turbofish));
+    ///
+    /// let other: Path = parse_quote!(serde::Deserialize);
+    /// assert!(!plain.matches_ignoring_args(&other));
+    /// ```
+    pub fn matches_ignoring_args(&self, other: &Path) -> bool {
+        self.leading_colon.is_some() == other.leading_colon.is_some()
+            && self.segments.len() == other.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(&other.segments)
+                .all(|(a, b)| a.ident == b.ident)
+    }
+
+    /// Determines whether this path is exactly the `Self` type.
+    ///
+    /// Only the single segment `Self` without a leading colon or path
+    /// arguments is matched. A path that merely starts with `Self`, like
+    /// `Self::Item` or `Self::new`, names something else; check the ident of
+    /// its first segment to find those.
+    ///
+    /// The `self` value is a different path, which this does not match. See
+    /// [`ExprPath::is_self_value`] and [`PatIdent::is_self_value`].
+    ///
+    /// [`ExprPath::is_self_value`]: struct.ExprPath.html#method.is_self_value
+    /// [`PatIdent::is_self_value`]: struct.PatIdent.html#method.is_self_value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Path};
+    ///
+    /// let ty: Path = parse_quote!(Self);
+    /// assert!(ty.is_self_type());
+    ///
+    /// let assoc: Path = parse_quote!(Self::Item);
+    /// assert!(!assoc.is_self_type());
+    ///
+    /// let value: Path = parse_quote!(self);
+    /// assert!(!value.is_self_type());
+    /// ```
+    pub fn is_self_type(&self) -> bool {
+        self.is_ident("Self")
+    }
+
+    /// Determines whether this is a path of length 1 equal to the given
+    /// ident.
+    ///
+    /// For them to compare equal, it must be the case that:
+    ///
+    /// - the path has no leading colon,
+    /// - the number of path segments is 1,
+    /// - the first path segment has no angle bracketed or parenthesized
+    ///   path arguments
+    /// - and the ident of the first path segment is equal to the given one.
+    pub fn is_ident<I: ?Sized>(&self, ident: &I) -> bool
+    where
+        Ident: PartialEq<I>,
+    {
+        match self.get_ident() {
+            Some(id) => id == ident,
+            None => false,
+        }
+    }
+
+    /// If this path consists of a single ident, returns the ident.
+    ///
+    /// A path is considered an ident if:
+    ///
+    /// - the path has no leading colon,
+    /// - the number of path segments is 1, and
+    /// - the first path segment has no angle bracketed or parenthesized
+    ///   path arguments.
+    pub fn get_ident(&self) -> Option<&Ident> {
+        if self.leading_colon.is_none()
+            && self.segments.len() == 1
+            && self.segments[0].arguments.is_none()
+        {
+            Some(&self.segments[0].ident)
+        } else {
+            None
+        }
+    }
+}
+
 ast_struct! {
     /// A segment of a path together with any path arguments on that segment.
     ///
@@ -86,7 +196,90 @@ impl PathArguments {
         }
     }
 
-    #[cfg(feature = "parsing")]
+    /// Returns the arguments if they are angle bracketed, like the `<K, V>`
+    /// in `HashMap<K, V>`.
+    ///
+    /// This does not convert parenthesized arguments. Use
+    /// [`into_angle_bracketed`] to treat `Fn(A) -> B` the same as
+    /// `Fn<(A,), Output = B>`.
+    ///
+    /// [`into_angle_bracketed`]: PathArguments::into_angle_bracketed
+    pub fn as_angle_bracketed(&self) -> Option<&AngleBracketedGenericArguments> {
+        match self {
+            PathArguments::AngleBracketed(bracketed) => Some(bracketed),
+            PathArguments::None | PathArguments::Parenthesized(_) => None,
+        }
+    }
+
+    /// Converts the arguments into angle bracketed form, desugaring the
+    /// parenthesized arguments of an `Fn` trait.
+    ///
+    /// Parenthesized arguments `(A, B) -> C` become `<(A, B), Output = C>`: a
+    /// single tuple type holding the inputs, followed by a binding of the
+    /// `Output` associated type. Without a return type, the output is `()`,
+    /// so `FnMut(A)` is the same as `FnMut<(A,), Output = ()>`.
+    ///
+    /// Angle bracketed arguments are returned unchanged, and `None` is
+    /// returned if there are no arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, TypePath};
+    ///
+    /// let ty: TypePath = parse_quote!(Fn(u8) -> bool);
+    /// let segment = ty.path.segments.into_iter().next().unwrap();
+    /// let args = segment.arguments.into_angle_bracketed().unwrap();
+    ///
+    /// let expected = quote!(<(u8,), Output = bool>);
+    /// assert_eq!(quote!(#args).to_string(), expected.to_string());
+    /// ```
+    pub fn into_angle_bracketed(self) -> Option<AngleBracketedGenericArguments> {
+        match self {
+            PathArguments::None => None,
+            PathArguments::AngleBracketed(bracketed) => Some(bracketed),
+            PathArguments::Parenthesized(parenthesized) => {
+                let span = parenthesized.paren_token.span;
+
+                let mut inputs = parenthesized.inputs;
+                if inputs.len() == 1 && !inputs.trailing_punct() {
+                    inputs.push_punct(Token![,](span));
+                }
+                let inputs = Type::Tuple(TypeTuple {
+                    paren_token: parenthesized.paren_token,
+                    elems: inputs,
+                });
+
+                let (eq_token, output) = match parenthesized.output {
+                    ReturnType::Default => (
+                        Token![=](span),
+                        Type::Tuple(TypeTuple {
+                            paren_token: token::Paren(span),
+                            elems: Punctuated::new(),
+                        }),
+                    ),
+                    ReturnType::Type(arrow, ty) => (Token![=](arrow.spans[0]), *ty),
+                };
+                let output = Binding {
+                    ident: Ident::new("Output", span),
+                    eq_token,
+                    ty: output,
+                };
+
+                let mut args = Punctuated::new();
+                args.push(GenericArgument::Type(inputs));
+                args.push(GenericArgument::Binding(output));
+                Some(AngleBracketedGenericArguments {
+                    colon2_token: None,
+                    lt_token: Token![<](span),
+                    args,
+                    gt_token: Token![>](span),
+                })
+            }
+        }
+    }
+
     fn is_none(&self) -> bool {
         match *self {
             PathArguments::None => true,
@@ -110,10 +303,15 @@ ast_enum! {
         Binding(Binding),
         /// An associated type bound: `Iterator<Item: Display>`.
         Constraint(Constraint),
-        /// A const expression. Must be inside of a block.
+        /// A const expression: a literal like `5` or `5u8`, a negated literal
+        /// like `-1`, or any other expression inside of a block like `{ N *
+        /// 2 }`.
         ///
         /// NOTE: Identity expressions are represented as Type arguments, as
-        /// they are indistinguishable syntactically.
+        /// they are indistinguishable syntactically. In `Foo<N>` the argument
+        /// may be either a type named `N` or a const named `N`, which is only
+        /// resolved later by the compiler, so it is always parsed as
+        /// `GenericArgument::Type`.
         Const(Expr),
     }
 }
@@ -236,10 +434,23 @@ pub mod parsing {
                     return Ok(GenericArgument::Const(Expr::Lit(lit)));
                 }
 
+                if input.peek(Token![-]) && input.peek2(Lit) {
+                    return Ok(GenericArgument::Const(Expr::Unary(ExprUnary {
+                        attrs: Vec::new(),
+                        op: UnOp::Neg(input.parse()?),
+                        expr: Box::new(Expr::Lit(input.parse()?)),
+                    })));
+                }
+
                 if input.peek(token::Brace) {
                     let block = input.call(expr::parsing::expr_block)?;
                     return Ok(GenericArgument::Const(Expr::Block(block)));
                 }
+
+                if input.peek(Token![const]) && input.peek2(token::Brace) {
+                    let block = input.call(expr::parsing::expr_const)?;
+                    return Ok(GenericArgument::Const(Expr::Const(block)));
+                }
             }
 
             input.parse().map(GenericArgument::Type)
@@ -422,29 +633,6 @@ pub mod parsing {
             })
         }
 
-        /// Determines whether this is a path of length 1 equal to the given
-        /// ident.
-        ///
-        /// For them to compare equal, it must be the case that:
-        ///
-        /// - the path has no leading colon,
-        /// - the number of path segments is 1,
-        /// - the first path segment has no angle bracketed or parenthesized
-        ///   path arguments
-        /// - and the ident of the first path segment is equal to the given one.
-        ///
-        /// *This function is available if Syn is built with the `"parsing"`
-        /// feature.*
-        pub fn is_ident<I: ?Sized>(&self, ident: &I) -> bool
-        where
-            Ident: PartialEq<I>,
-        {
-            self.leading_colon.is_none()
-                && self.segments.len() == 1
-                && self.segments[0].arguments.is_none()
-                && self.segments[0].ident == *ident
-        }
-
         fn parse_helper(input: ParseStream, expr_style: bool) -> Result<Self> {
             Ok(Path {
                 leading_colon: input.parse()?,
@@ -562,15 +750,32 @@ mod printing {
                 GenericArgument::Type(ty) => ty.to_tokens(tokens),
                 GenericArgument::Binding(tb) => tb.to_tokens(tokens),
                 GenericArgument::Constraint(tc) => tc.to_tokens(tokens),
-                GenericArgument::Const(e) => match *e {
+                GenericArgument::Const(e) => match e {
                     Expr::Lit(_) => e.to_tokens(tokens),
 
+                    // A negated literal does not need braces either.
+                    Expr::Unary(ExprUnary {
+                        op: UnOp::Neg(_),
+                        expr,
+                        ..
+                    }) if match **expr {
+                        Expr::Lit(_) => true,
+                        _ => false,
+                    } =>
+                    {
+                        e.to_tokens(tokens)
+                    }
+
                     // NOTE: We should probably support parsing blocks with only
                     // expressions in them without the full feature for const
                     // generics.
                     #[cfg(feature = "full")]
                     Expr::Block(_) => e.to_tokens(tokens),
 
+                    // An inline const block does not need braces either.
+                    #[cfg(feature = "full")]
+                    Expr::Const(_) => e.to_tokens(tokens),
+
                     // ERROR CORRECTION: Add braces to make sure that the
                     // generated code is valid.
                     _ => token::Brace::default().surround(tokens, |tokens| {
@@ -94,6 +94,34 @@ impl<T, P> Punctuated<T, P> {
         }
     }
 
+    /// Borrows the syntax tree node at position `index` in this sequence, or
+    /// `None` if `index` is out of bounds.
+    ///
+    /// The index counts only nodes of type `T`, not the punctuation of type
+    /// `P`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if let Some((value, _punct)) = self.inner.get(index) {
+            Some(value)
+        } else if index == self.inner.len() {
+            self.last.as_ref().map(Box::as_ref)
+        } else {
+            None
+        }
+    }
+
+    /// Mutably borrows the syntax tree node at position `index` in this
+    /// sequence, or `None` if `index` is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let inner_len = self.inner.len();
+        if let Some((value, _punct)) = self.inner.get_mut(index) {
+            Some(value)
+        } else if index == inner_len {
+            self.last.as_mut().map(Box::as_mut)
+        } else {
+            None
+        }
+    }
+
     /// Returns an iterator over borrowed syntax tree nodes of type `&T`.
     pub fn iter(&self) -> Iter<T> {
         Iter {
@@ -230,6 +258,52 @@ impl<T, P> Punctuated<T, P> {
         }
     }
 
+    /// Removes and returns the element at position `index`, along with the
+    /// punctuation that follows it.
+    ///
+    /// If the removed element is the last one and there is no trailing
+    /// punctuation, the punctuation between it and the element before it is
+    /// removed instead, so that the sequence does not gain a trailing
+    /// punctuation. A sequence that already has trailing punctuation keeps it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len());
+
+        if index == self.inner.len() {
+            let last = self.last.take().unwrap();
+            self.last = self.inner.pop().map(|(t, _)| Box::new(t));
+            *last
+        } else {
+            self.inner.remove(index).0
+        }
+    }
+
+    /// Retains only the elements for which `f` returns true, along with the
+    /// punctuation that follows each of them.
+    ///
+    /// The elements are visited in order. As with [`remove`], if the last
+    /// element is removed and there is no trailing punctuation, the last
+    /// remaining element loses its punctuation, while a sequence with trailing
+    /// punctuation keeps it as long as any elements remain.
+    ///
+    /// [`remove`]: Punctuated::remove
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner.retain(|(t, _)| f(t));
+        if let Some(last) = self.last.take() {
+            if f(&last) {
+                self.last = Some(last);
+            } else {
+                self.last = self.inner.pop().map(|(t, _)| Box::new(t));
+            }
+        }
+    }
+
     /// Parses zero or more occurrences of `T` separated by punctuation of type
     /// `P`, with optional trailing punctuation.
     ///
@@ -865,6 +939,7 @@ mod printing {
     use super::*;
     use proc_macro2::TokenStream;
     use quote::{ToTokens, TokenStreamExt};
+    use std::ops::{Bound, RangeBounds};
 
     impl<T, P> ToTokens for Punctuated<T, P>
     where
@@ -876,6 +951,68 @@ mod printing {
         }
     }
 
+    impl<T, P> Punctuated<T, P>
+    where
+        T: ToTokens,
+        P: ToTokens,
+    {
+        /// Prints only the syntax tree nodes within the given range of indices,
+        /// together with the punctuation that separates them.
+        ///
+        /// Punctuation is printed between consecutive nodes of the range but
+        /// not after the final node of the range, unless that node is also the
+        /// final node of the whole sequence and the sequence has trailing
+        /// punctuation. Indices count only nodes of type `T`.
+        ///
+        /// *This function is available if Syn is built with the `"printing"`
+        /// feature.*
+        ///
+        /// # Panics
+        ///
+        /// Panics if the start of the range is greater than its end or if the
+        /// end of the range is greater than the number of nodes in this
+        /// sequence.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use proc_macro2::TokenStream;
+        /// use quote::quote;
+        /// use syn::punctuated::Punctuated;
+        /// use syn::{parse_quote, Expr, Token};
+        ///
+        /// let args: Punctuated<Expr, Token![,]> = parse_quote!(a, b, c);
+        ///
+        /// // Forward all but the last argument.
+        /// let mut init = TokenStream::new();
+        /// args.to_tokens_range(..args.len() - 1, &mut init);
+        /// assert_eq!(init.to_string(), quote!(a, b).to_string());
+        /// ```
+        pub fn to_tokens_range<R>(&self, range: R, tokens: &mut TokenStream)
+        where
+            R: RangeBounds<usize>,
+        {
+            let start = match range.start_bound() {
+                Bound::Included(&n) => n,
+                Bound::Excluded(&n) => n.saturating_add(1),
+                Bound::Unbounded => 0,
+            };
+            let end = match range.end_bound() {
+                Bound::Included(&n) => n.saturating_add(1),
+                Bound::Excluded(&n) => n,
+                Bound::Unbounded => self.len(),
+            };
+            assert!(start <= end && end <= self.len());
+
+            for (i, pair) in self.pairs().enumerate().skip(start).take(end - start) {
+                pair.value().to_tokens(tokens);
+                if i + 1 < end || end == self.len() {
+                    pair.punct().to_tokens(tokens);
+                }
+            }
+        }
+    }
+
     impl<T, P> ToTokens for Pair<T, P>
     where
         T: ToTokens,
@@ -0,0 +1,213 @@
+use crate::parse::{Parse, ParseStream};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// The layout requested by the `#[repr(...)]` attributes on a type.
+///
+/// *This type is available if Syn is built with the `"parsing"` feature and
+/// either the `"derive"` or `"full"` feature.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Repr {
+    /// `C`, `Rust`, or `transparent`.
+    pub kind: ReprKind,
+    /// A primitive integer representation such as `u8` or `isize`, which may
+    /// appear on its own or together with `C` on an enum.
+    pub int: Option<Ident>,
+    /// `packed` or `packed(N)`. A bare `packed` is the same as `packed(1)`.
+    pub packed: Option<u32>,
+    /// `align(N)`.
+    pub align: Option<u32>,
+}
+
+/// The representation kind named in a `#[repr(...)]` attribute.
+///
+/// *This type is available if Syn is built with the `"parsing"` feature and
+/// either the `"derive"` or `"full"` feature.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone, Copy))]
+pub enum ReprKind {
+    /// The default layout, used when no kind is given explicitly.
+    Rust,
+    /// `#[repr(C)]`
+    C,
+    /// `#[repr(transparent)]`
+    Transparent,
+}
+
+impl Default for Repr {
+    fn default() -> Self {
+        Repr {
+            kind: ReprKind::Rust,
+            int: None,
+            packed: None,
+            align: None,
+        }
+    }
+}
+
+/// Combines the `#[repr(...)]` attributes among `attrs` into a single [`Repr`].
+///
+/// Attributes other than `repr` are ignored, and a type with no `repr`
+/// attribute has the default `Repr` with kind `Rust`. Hints may be spread
+/// across several attributes, so `#[repr(C)] #[repr(align(8))]` is the same
+/// as `#[repr(C, align(8))]`.
+///
+/// An error is returned for an unrecognized hint, for an alignment that is
+/// not a power of two, for conflicting kinds such as `C` together with
+/// `transparent`, for more than one primitive integer type, and for `packed`
+/// together with `align`.
+///
+/// *This function is available if Syn is built with the `"parsing"` feature
+/// and either the `"derive"` or `"full"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, parse_repr, DeriveInput, ReprKind};
+///
+/// let input: DeriveInput = parse_quote! {
+///     #[derive(Clone)]
+///     #[repr(C, packed(2))]
+///     struct S {
+///         a: u8,
+///         b: u32,
+///     }
+/// };
+///
+/// let repr = parse_repr(&input.attrs).unwrap();
+/// assert_eq!(repr.kind, ReprKind::C);
+/// assert_eq!(repr.packed, Some(2));
+/// assert_eq!(repr.align, None);
+/// ```
+pub fn parse_repr(attrs: &[Attribute]) -> Result<Repr> {
+    let mut repr = Repr::default();
+    let mut kind_hint: Option<Ident> = None;
+
+    for attr in attrs {
+        if !attr.path.is_ident("repr") {
+            continue;
+        }
+        let hints = attr.parse_args_with(Punctuated::<Hint, Token![,]>::parse_terminated)?;
+        for hint in hints {
+            let name = hint.name.to_string();
+            let kind = match name.as_str() {
+                "C" => Some(ReprKind::C),
+                "Rust" => Some(ReprKind::Rust),
+                "transparent" => Some(ReprKind::Transparent),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                hint.expect_no_arg()?;
+                if let Some(prev) = &kind_hint {
+                    if *prev != name {
+                        return Err(Error::new(
+                            hint.name.span(),
+                            "conflicting representation hints",
+                        ));
+                    }
+                }
+                repr.kind = kind;
+                kind_hint = Some(hint.name);
+            } else if is_primitive(&name) {
+                hint.expect_no_arg()?;
+                if repr.int.is_some() {
+                    return Err(Error::new(
+                        hint.name.span(),
+                        "conflicting representation hints",
+                    ));
+                }
+                repr.int = Some(hint.name);
+            } else if name == "packed" {
+                let packed = match &hint.arg {
+                    Some(arg) => power_of_two(arg)?,
+                    None => 1,
+                };
+                if repr.align.is_some() {
+                    return Err(Error::new(
+                        hint.name.span(),
+                        "`packed` and `align` cannot both be used",
+                    ));
+                }
+                repr.packed = Some(packed);
+            } else if name == "align" {
+                let align = match &hint.arg {
+                    Some(arg) => power_of_two(arg)?,
+                    None => {
+                        return Err(Error::new(
+                            hint.name.span(),
+                            "expected an alignment, as in `align(8)`",
+                        ))
+                    }
+                };
+                if repr.packed.is_some() {
+                    return Err(Error::new(
+                        hint.name.span(),
+                        "`packed` and `align` cannot both be used",
+                    ));
+                }
+                repr.align = Some(align);
+            } else {
+                return Err(Error::new(
+                    hint.name.span(),
+                    format!("unrecognized representation hint `{}`", name),
+                ));
+            }
+        }
+    }
+
+    if let (ReprKind::Transparent, Some(_)) = (&repr.kind, &repr.int) {
+        return Err(Error::new(
+            kind_hint.unwrap().span(),
+            "`transparent` cannot be combined with a primitive representation",
+        ));
+    }
+
+    Ok(repr)
+}
+
+struct Hint {
+    name: Ident,
+    arg: Option<LitInt>,
+}
+
+impl Hint {
+    fn expect_no_arg(&self) -> Result<()> {
+        match &self.arg {
+            Some(arg) => Err(Error::new(
+                arg.span(),
+                format!("`{}` does not take an argument", self.name),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Parse for Hint {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        let arg = if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse()?)
+        } else {
+            None
+        };
+        Ok(Hint { name, arg })
+    }
+}
+
+fn is_primitive(name: &str) -> bool {
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" => true,
+        _ => false,
+    }
+}
+
+fn power_of_two(lit: &LitInt) -> Result<u32> {
+    match lit.base10_digits().parse::<u32>() {
+        Ok(n) if n.is_power_of_two() => Ok(n),
+        _ => Err(Error::new(lit.span(), "expected a power of two")),
+    }
+}
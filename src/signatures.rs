@@ -0,0 +1,171 @@
+use proc_macro2::Span;
+
+use crate::fold::{self, Fold};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// Converts an impl block into a trait declaring the same items without their
+/// bodies.
+///
+/// Each method becomes a required trait method with the same signature,
+/// including its generics, where clause, and receiver, and with its body
+/// replaced by `;`. Associated consts and types become declarations without a
+/// value, like `const N: usize;` and `type Item;`, and an existential type
+/// becomes an associated type with the same bounds. Macro invocations in the
+/// impl are dropped.
+///
+/// Since patterns are not allowed in the arguments of a function without a
+/// body, a method argument like `mut x` or `ref x` is declared as just `x`,
+/// and any argument whose pattern is not a single identifier, like `(a, b)`,
+/// is declared as `_`. Likewise `mut self` becomes `self`. Visibility and
+/// `default` qualifiers are removed from every item, and `const` is removed
+/// from methods since a trait method cannot be `const`.
+///
+/// The trait has the attributes, generics, and `unsafe` qualifier of the impl
+/// block. It is named after the trait being implemented if the impl block
+/// implements one, and otherwise after the self type with an `Ext` suffix,
+/// like an extension trait. Set its `ident` and `vis` to name it differently
+/// or to make it public.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, to_signatures, ItemImpl};
+///
+/// let item: ItemImpl = parse_quote! {
+///     impl<T> Stack<T> {
+///         pub fn push(&mut self, mut value: T) where T: Clone {
+///             self.items.push(value);
+///         }
+///     }
+/// };
+///
+/// let expected = quote! {
+///     trait StackExt<T> {
+///         fn push(&mut self, value: T) where T: Clone;
+///     }
+/// };
+///
+/// let item_trait = to_signatures(item);
+/// assert_eq!(quote!(#item_trait).to_string(), expected.to_string());
+/// ```
+pub fn to_signatures(item: ItemImpl) -> ItemTrait {
+    let ident = match &item.trait_ {
+        Some((_, path, _)) => path.segments.last().unwrap().ident.clone(),
+        None => {
+            let name = match &*item.self_ty {
+                Type::Path(ty) => ty.path.segments.last().map(|segment| &segment.ident),
+                _ => None,
+            };
+            match name {
+                Some(name) => Ident::new(&format!("{}Ext", name), name.span()),
+                None => Ident::new("Ext", Span::call_site()),
+            }
+        }
+    };
+
+    let items = item
+        .items
+        .into_iter()
+        .filter_map(|impl_item| match impl_item {
+            ImplItem::Method(method) => Some(TraitItem::Method(TraitItemMethod {
+                attrs: method.attrs,
+                sig: StripPatterns.fold_signature(method.sig),
+                default: None,
+                semi_token: Some(Token![;](method.block.brace_token.span)),
+            })),
+            ImplItem::Const(item) => Some(TraitItem::Const(TraitItemConst {
+                attrs: item.attrs,
+                const_token: item.const_token,
+                ident: item.ident,
+                colon_token: item.colon_token,
+                ty: item.ty,
+                default: None,
+                semi_token: item.semi_token,
+            })),
+            ImplItem::Type(item) => Some(TraitItem::Type(TraitItemType {
+                attrs: item.attrs,
+                type_token: item.type_token,
+                ident: item.ident,
+                generics: item.generics,
+                colon_token: None,
+                bounds: Punctuated::new(),
+                default: None,
+                semi_token: item.semi_token,
+            })),
+            ImplItem::Existential(item) => Some(TraitItem::Type(TraitItemType {
+                attrs: item.attrs,
+                type_token: item.type_token,
+                ident: item.ident,
+                generics: item.generics,
+                colon_token: item.colon_token,
+                bounds: item.bounds,
+                default: None,
+                semi_token: item.semi_token,
+            })),
+            ImplItem::Macro(_) | ImplItem::Verbatim(_) => None,
+            ImplItem::__Nonexhaustive => unreachable!(),
+        })
+        .collect();
+
+    ItemTrait {
+        attrs: item.attrs,
+        vis: Visibility::Inherited,
+        unsafety: item.unsafety,
+        auto_token: None,
+        trait_token: Token![trait](item.impl_token.span),
+        ident,
+        generics: item.generics,
+        colon_token: None,
+        supertraits: Punctuated::new(),
+        brace_token: item.brace_token,
+        items,
+    }
+}
+
+// Rewrites a signature into a form that is allowed in a trait method without
+// a body.
+struct StripPatterns;
+
+impl Fold for StripPatterns {
+    fn fold_signature(&mut self, node: Signature) -> Signature {
+        Signature {
+            constness: None,
+            ..fold::fold_signature(self, node)
+        }
+    }
+
+    fn fold_receiver(&mut self, mut node: Receiver) -> Receiver {
+        if node.reference.is_none() {
+            node.mutability = None;
+        }
+        node
+    }
+
+    fn fold_pat_type(&mut self, node: PatType) -> PatType {
+        let pat = match *node.pat {
+            Pat::Ident(pat) => Pat::Ident(PatIdent {
+                attrs: pat.attrs,
+                by_ref: None,
+                mutability: None,
+                ident: pat.ident,
+                subpat: None,
+            }),
+            _ => Pat::Wild(PatWild {
+                attrs: Vec::new(),
+                underscore_token: Token![_](node.colon_token.spans[0]),
+            }),
+        };
+        fold::fold_pat_type(
+            self,
+            PatType {
+                pat: Box::new(pat),
+                ..node
+            },
+        )
+    }
+}
@@ -82,9 +82,13 @@
 //!
 //! [`syn::Error::new_spanned`]: crate::Error::new_spanned
 
+use std::fmt::Display;
+
 use proc_macro2::Span;
 use quote::spanned::Spanned as ToTokens;
 
+use crate::Error;
+
 /// A trait that can provide the `Span` of the complete contents of a syntax
 /// tree node.
 ///
@@ -112,3 +116,52 @@ impl<T: ?Sized + ToTokens> Spanned for T {
         self.__span()
     }
 }
+
+/// Creates an error with the given message located at the span of a syntax
+/// tree node.
+///
+/// This is shorthand for `Error::new(node.span(), message)`, for pointing an
+/// error at the specific part of the input that is invalid, such as a single
+/// field of a struct. Anything that implements [`Spanned`] is accepted,
+/// including a `Span` itself.
+///
+/// A node that contains no tokens, such as an empty `Generics`, has no span
+/// of its own, and the error is placed at [`Span::call_site()`] instead, which
+/// in a procedural macro is the macro invocation as a whole. The [limitations]
+/// of [`Spanned`] apply as well, so that on a stable compiler the error points
+/// at only the first token of the node. Use [`Error::new_spanned`] to have the
+/// error underline every token of a node on a stable compiler.
+///
+/// [`Span::call_site()`]: proc_macro2::Span::call_site
+/// [limitations]: self#limitations
+/// [`Error::new_spanned`]: crate::Error::new_spanned
+///
+/// *This function is available if Syn is built with both the `"parsing"` and
+/// `"printing"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::spanned::spanned_error;
+/// use syn::{Data, DeriveInput, Error, Fields};
+///
+/// fn reject_tuple_fields(input: &DeriveInput) -> Result<(), Error> {
+///     if let Data::Struct(data) = &input.data {
+///         if let Fields::Unnamed(fields) = &data.fields {
+///             return Err(spanned_error(fields, "tuple structs are not supported"));
+///         }
+///     }
+///     Ok(())
+/// }
+/// #
+/// # let input: DeriveInput = syn::parse_quote!(struct S(u8););
+/// # let err = reject_tuple_fields(&input).unwrap_err();
+/// # assert_eq!(err.to_string(), "tuple structs are not supported");
+/// ```
+pub fn spanned_error<T, U>(node: &T, message: U) -> Error
+where
+    T: ?Sized + Spanned,
+    U: Display,
+{
+    Error::new(node.span(), message)
+}
@@ -39,10 +39,98 @@ ast_struct! {
         pub let_token: Token![let],
         pub pat: Pat,
         pub init: Option<(Token![=], Box<Expr>)>,
+        /// The `else { ... }` block of a `let`-`else` statement: `let Some(x)
+        /// = opt else { return; };`. The expression is always an
+        /// `Expr::Block`.
+        pub diverge: Option<(Token![else], Box<Expr>)>,
         pub semi_token: Token![;],
     }
 }
 
+impl Local {
+    /// Checks that the `else` block of a `let`-`else` statement diverges, as
+    /// the compiler requires.
+    ///
+    /// The parser accepts any block after `else`, so tools that want to report
+    /// a block that does not diverge can call this to get an error pointing at
+    /// the `else`. A `let` without an `else` is always valid.
+    ///
+    /// Without type information this can only be an approximation. The block
+    /// is accepted if its trailing expression, or one of its statements if
+    /// there is no trailing expression, is a `return`, `break`, `continue`, or
+    /// `loop`, or is an `if`-`else`, `match`, or block whose every branch is
+    /// accepted in turn. Function calls, method calls, and macro invocations
+    /// like `panic!(...)` are accepted too, since they may have type `!`.
+    ///
+    /// *This function is available if Syn is built with the `"full"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Stmt};
+    ///
+    /// let stmt: Stmt = parse_quote! {
+    ///     let Some(x) = opt else { return; };
+    /// };
+    /// if let Stmt::Local(local) = &stmt {
+    ///     assert!(local.validate_let_else().is_ok());
+    /// }
+    ///
+    /// let stmt: Stmt = parse_quote! {
+    ///     let Some(x) = opt else { 0 };
+    /// };
+    /// if let Stmt::Local(local) = &stmt {
+    ///     let err = local.validate_let_else().unwrap_err();
+    ///     assert_eq!(err.to_string(), "`else` clause of `let...else` does not diverge");
+    /// }
+    /// ```
+    pub fn validate_let_else(&self) -> Result<()> {
+        match &self.diverge {
+            Some((else_token, diverge)) if !may_diverge(diverge) => Err(Error::new(
+                else_token.span,
+                "`else` clause of `let...else` does not diverge",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn may_diverge(expr: &Expr) -> bool {
+    match expr {
+        Expr::Return(_)
+        | Expr::Break(_)
+        | Expr::Continue(_)
+        | Expr::Loop(_)
+        | Expr::Call(_)
+        | Expr::MethodCall(_)
+        | Expr::Macro(_) => true,
+        Expr::Block(expr) => block_may_diverge(&expr.block),
+        Expr::Unsafe(expr) => block_may_diverge(&expr.block),
+        Expr::If(expr) => match &expr.else_branch {
+            Some((_, else_branch)) => {
+                block_may_diverge(&expr.then_branch) && may_diverge(else_branch)
+            }
+            None => false,
+        },
+        Expr::Match(expr) => expr.arms.iter().all(|arm| may_diverge(&arm.body)),
+        Expr::Paren(expr) => may_diverge(&expr.expr),
+        Expr::Group(expr) => may_diverge(&expr.expr),
+        _ => false,
+    }
+}
+
+fn block_may_diverge(block: &Block) -> bool {
+    match block.stmts.last() {
+        Some(Stmt::Expr(expr)) => may_diverge(expr),
+        _ => block.stmts.iter().any(|stmt| match stmt {
+            Stmt::Expr(expr) | Stmt::Semi(expr, _) => may_diverge(expr),
+            Stmt::Item(Item::Macro(item)) => item.ident.is_none(),
+            Stmt::Local(_) | Stmt::Item(_) => false,
+        }),
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -166,7 +254,7 @@ pub mod parsing {
             || ahead.peek(Token![extern]) && !ahead.peek2(Token![::])
             || ahead.peek(Token![use])
             || ahead.peek(Token![static]) && (ahead.peek2(Token![mut]) || ahead.peek2(Ident))
-            || ahead.peek(Token![const])
+            || ahead.peek(Token![const]) && !ahead.peek2(token::Brace)
             || ahead.peek(Token![unsafe]) && !ahead.peek2(token::Brace)
             || ahead.peek(Token![async])
                 && (ahead.peek2(Token![unsafe])
@@ -261,6 +349,19 @@ pub mod parsing {
                     None
                 }
             },
+            diverge: {
+                if input.peek(Token![else]) {
+                    let else_token: Token![else] = input.parse()?;
+                    let diverge = ExprBlock {
+                        attrs: Vec::new(),
+                        label: None,
+                        block: input.parse()?,
+                    };
+                    Some((else_token, Box::new(Expr::Block(diverge))))
+                } else {
+                    None
+                }
+            },
             semi_token: input.parse()?,
         })
     }
@@ -322,6 +423,10 @@ mod printing {
                 eq_token.to_tokens(tokens);
                 init.to_tokens(tokens);
             }
+            if let Some((else_token, diverge)) = &self.diverge {
+                else_token.to_tokens(tokens);
+                diverge.to_tokens(tokens);
+            }
             self.semi_token.to_tokens(tokens);
         }
     }
@@ -0,0 +1,86 @@
+use proc_macro2::{TokenStream, TokenTree};
+
+use crate::visit::{self, Visit};
+use crate::*;
+
+/// Collects every string literal in a file, in the order they appear.
+///
+/// This includes string literals in expressions, in patterns, and in the
+/// tokens of macro invocations like `println!("...")` and of attributes like
+/// `#[error("...")]`, which are not otherwise parsed into syntax tree nodes.
+/// The span of each string is available from [`LitStr::span`].
+///
+/// Strings in `#[doc]`, `#[cfg]`, and `#[cfg_attr]` attributes, including doc
+/// comments, are skipped unless `include_doc_and_cfg` is true, since those are
+/// usually not user-facing text.
+///
+/// *This function is available if Syn is built with the `"full"`, `"visit"`,
+/// and `"clone-impls"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, string_literals, File};
+///
+/// let file: File = parse_quote! {
+///     /// Prints a greeting.
+///     #[cfg(feature = "greeting")]
+///     fn greet(name: &str) {
+///         println!("Hello, {}!", name);
+///     }
+/// };
+///
+/// let strings: Vec<String> = string_literals(&file, false)
+///     .iter()
+///     .map(|lit| lit.value())
+///     .collect();
+/// assert_eq!(strings, ["Hello, {}!"]);
+/// ```
+pub fn string_literals(file: &File, include_doc_and_cfg: bool) -> Vec<LitStr> {
+    let mut visitor = StringLiterals {
+        include_doc_and_cfg,
+        strings: Vec::new(),
+    };
+    visitor.visit_file(file);
+    visitor.strings
+}
+
+struct StringLiterals {
+    include_doc_and_cfg: bool,
+    strings: Vec<LitStr>,
+}
+
+impl StringLiterals {
+    fn scan_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Literal(literal) => {
+                    if let Lit::Str(lit) = Lit::new(literal) {
+                        self.strings.push(lit);
+                    }
+                }
+                TokenTree::Group(group) => self.scan_tokens(group.stream()),
+                TokenTree::Ident(_) | TokenTree::Punct(_) => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for StringLiterals {
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        let doc_or_cfg = node.path.is_ident("doc")
+            || node.path.is_ident("cfg")
+            || node.path.is_ident("cfg_attr");
+        if self.include_doc_and_cfg || !doc_or_cfg {
+            visit::visit_attribute(self, node);
+        }
+    }
+
+    fn visit_lit_str(&mut self, node: &'ast LitStr) {
+        self.strings.push(node.clone());
+    }
+
+    fn visit_token_stream(&mut self, node: &'ast TokenStream) {
+        self.scan_tokens(node.clone());
+    }
+}
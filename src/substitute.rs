@@ -0,0 +1,244 @@
+use crate::expr::{
+    binary_operand, callee_operand, cast_operand, postfix_operand, range_operand, receiver_operand,
+    unary_operand,
+};
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// A syntax tree node in which identifiers can be replaced by
+/// [`substitute_ident`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"full"`, `"fold"`, and
+/// `"clone-impls"` features.*
+pub trait SubstituteIdent: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn fold_substitute_ident(self, folder: &mut IdentSubstituter<'_>) -> Self;
+}
+
+/// Replaces every use of the identifier `name` as a value with the expression
+/// `replacement`.
+///
+/// Only an expression consisting of nothing but the path `name` is replaced,
+/// as in `name + 1` or `f(name)`. Other occurrences of the identifier are
+/// left alone: patterns that bind `name`, types and type paths like
+/// `name::CONST`, field names and method names like `x.name` or `x.name()`,
+/// and the tokens inside of macro invocations, which are not parsed as
+/// expressions. A struct literal field written in shorthand as `S { name }`
+/// is expanded to `S { name: replacement }`.
+///
+/// The replacement is wrapped in parentheses where precedence requires it so
+/// that the result prints with the intended meaning: as the operand of a
+/// unary, binary, or cast expression, as a bound of a range, and as the
+/// receiver of a call, method call, field access, index, `?`, or `.await`.
+/// For example substituting `a + b` for `x` in `x * 2` gives `(a + b) * 2`,
+/// while in other positions like `f(x)` and `let y = x;` it is inserted as
+/// is.
+///
+/// Scoping is not taken into account, so a use of `name` that refers to a
+/// local variable shadowing it, such as in the body of `|name| name`, is
+/// replaced as well.
+///
+/// *This function is available if Syn is built with the `"full"`, `"fold"`,
+/// and `"clone-impls"` features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, substitute_ident, Expr};
+///
+/// let template: Expr = parse_quote! {
+///     Point { x, y: x * 2 }
+/// };
+///
+/// let expanded = substitute_ident(template, "x", parse_quote!(a + b));
+///
+/// let expected = quote! {
+///     Point { x: a + b, y: (a + b) * 2 }
+/// };
+/// assert_eq!(quote!(#expanded).to_string(), expected.to_string());
+/// ```
+pub fn substitute_ident<T: SubstituteIdent>(node: T, name: &str, replacement: Expr) -> T {
+    node.fold_substitute_ident(&mut IdentSubstituter {
+        name,
+        replacement,
+    })
+}
+
+#[doc(hidden)]
+pub struct IdentSubstituter<'a> {
+    name: &'a str,
+    replacement: Expr,
+}
+
+impl<'a> IdentSubstituter<'a> {
+    fn is_name(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Path(expr) => {
+                expr.attrs.is_empty() && expr.qself.is_none() && expr.path.is_ident(self.name)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Fold for IdentSubstituter<'a> {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        if self.is_name(&node) {
+            self.replacement.clone()
+        } else {
+            fold::fold_expr(self, node)
+        }
+    }
+
+    fn fold_expr_await(&mut self, node: ExprAwait) -> ExprAwait {
+        let replace = self.is_name(&node.base);
+        let mut node = fold::fold_expr_await(self, node);
+        if replace {
+            node.base = postfix_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_binary(&mut self, node: ExprBinary) -> ExprBinary {
+        let replace_left = self.is_name(&node.left);
+        let replace_right = self.is_name(&node.right);
+        let mut node = fold::fold_expr_binary(self, node);
+        if replace_left {
+            node.left = binary_operand(self.replacement.clone(), &node.op, false);
+        }
+        if replace_right {
+            node.right = binary_operand(self.replacement.clone(), &node.op, true);
+        }
+        node
+    }
+
+    fn fold_expr_call(&mut self, node: ExprCall) -> ExprCall {
+        let replace = self.is_name(&node.func);
+        let mut node = fold::fold_expr_call(self, node);
+        // A callee has stricter requirements than other operands, so that
+        // `s.f` is not printed as the method call `s.f(x)`.
+        if replace {
+            node.func = callee_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_cast(&mut self, node: ExprCast) -> ExprCast {
+        let replace = self.is_name(&node.expr);
+        let mut node = fold::fold_expr_cast(self, node);
+        if replace {
+            node.expr = cast_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_field(&mut self, node: ExprField) -> ExprField {
+        let replace = self.is_name(&node.base);
+        let mut node = fold::fold_expr_field(self, node);
+        if replace {
+            node.base = receiver_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_index(&mut self, node: ExprIndex) -> ExprIndex {
+        let replace = self.is_name(&node.expr);
+        let mut node = fold::fold_expr_index(self, node);
+        if replace {
+            node.expr = postfix_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_method_call(&mut self, node: ExprMethodCall) -> ExprMethodCall {
+        let replace = self.is_name(&node.receiver);
+        let mut node = fold::fold_expr_method_call(self, node);
+        if replace {
+            node.receiver = receiver_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_range(&mut self, node: ExprRange) -> ExprRange {
+        let replace_from = node.from.as_ref().map_or(false, |from| self.is_name(from));
+        let replace_to = node.to.as_ref().map_or(false, |to| self.is_name(to));
+        let mut node = fold::fold_expr_range(self, node);
+        if replace_from {
+            node.from = Some(range_operand(self.replacement.clone()));
+        }
+        if replace_to {
+            node.to = Some(range_operand(self.replacement.clone()));
+        }
+        node
+    }
+
+    fn fold_expr_reference(&mut self, node: ExprReference) -> ExprReference {
+        let replace = self.is_name(&node.expr);
+        let mut node = fold::fold_expr_reference(self, node);
+        if replace {
+            node.expr = unary_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_try(&mut self, node: ExprTry) -> ExprTry {
+        let replace = self.is_name(&node.expr);
+        let mut node = fold::fold_expr_try(self, node);
+        if replace {
+            node.expr = postfix_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_expr_unary(&mut self, node: ExprUnary) -> ExprUnary {
+        let replace = self.is_name(&node.expr);
+        let mut node = fold::fold_expr_unary(self, node);
+        if replace {
+            node.expr = unary_operand(self.replacement.clone());
+        }
+        node
+    }
+
+    fn fold_field_value(&mut self, node: FieldValue) -> FieldValue {
+        let shorthand = node.colon_token.is_none() && self.is_name(&node.expr);
+        let mut node = fold::fold_field_value(self, node);
+        // The shorthand `S { name }` needs to be written out in full once its
+        // value has been replaced.
+        if shorthand && !self.is_name(&node.expr) {
+            node.colon_token = Some(<Token![:]>::default());
+        }
+        node
+    }
+}
+
+macro_rules! impl_substitute_ident {
+    ($($ty:ident => $fold:ident,)*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl SubstituteIdent for $ty {
+                fn fold_substitute_ident(self, folder: &mut IdentSubstituter<'_>) -> Self {
+                    folder.$fold(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_substitute_ident! {
+    Block => fold_block,
+    Expr => fold_expr,
+    File => fold_file,
+    ImplItem => fold_impl_item,
+    Item => fold_item,
+    ItemFn => fold_item_fn,
+    Stmt => fold_stmt,
+    TraitItem => fold_trait_item,
+}
+
+mod private {
+    pub trait Sealed {}
+}
@@ -0,0 +1,122 @@
+use crate::visit::{self, Visit};
+use crate::*;
+
+/// Determines whether an expression contains an `.await` that belongs to it
+/// rather than to some nested scope.
+///
+/// The search does not descend into nested closures, `async` blocks, or items
+/// inside of `expr`, since an `.await` in any of those suspends the nested
+/// scope and not the enclosing one. If `expr` is itself a closure or an
+/// `async` block, its body is searched.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"visit"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{contains_await, parse_quote, Expr};
+///
+/// let closure: Expr = parse_quote!(|| fetch().await);
+/// assert!(contains_await(&closure));
+///
+/// let nested: Expr = parse_quote!(|| async { fetch().await });
+/// assert!(!contains_await(&nested));
+/// ```
+pub fn contains_await(expr: &Expr) -> bool {
+    let mut visitor = FindSuspend {
+        kind: Suspend::Await,
+        found: false,
+    };
+    visitor.visit_scope(expr);
+    visitor.found
+}
+
+/// Determines whether an expression contains a `yield` that belongs to it
+/// rather than to some nested scope.
+///
+/// The search does not descend into nested closures, `async` blocks, or items
+/// inside of `expr`, since a `yield` in any of those belongs to the nested
+/// scope and not the enclosing one. If `expr` is itself a closure or an
+/// `async` block, its body is searched.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"visit"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{contains_yield, parse_quote, Expr};
+///
+/// let generator: Expr = parse_quote!(|| { yield 1; yield 2; });
+/// assert!(contains_yield(&generator));
+///
+/// let nested: Expr = parse_quote!(|| { let g = || yield 1; });
+/// assert!(!contains_yield(&nested));
+/// ```
+pub fn contains_yield(expr: &Expr) -> bool {
+    let mut visitor = FindSuspend {
+        kind: Suspend::Yield,
+        found: false,
+    };
+    visitor.visit_scope(expr);
+    visitor.found
+}
+
+#[derive(PartialEq)]
+enum Suspend {
+    Await,
+    Yield,
+}
+
+struct FindSuspend {
+    kind: Suspend,
+    found: bool,
+}
+
+impl FindSuspend {
+    fn visit_scope(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Closure(closure) => self.visit_expr(&closure.body),
+            Expr::Async(async_block) => self.visit_block(&async_block.block),
+            _ => self.visit_expr(expr),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FindSuspend {
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        // No need to keep looking once one has been found.
+        if !self.found {
+            visit::visit_expr(self, node);
+        }
+    }
+
+    fn visit_expr_await(&mut self, node: &'ast ExprAwait) {
+        if self.kind == Suspend::Await {
+            self.found = true;
+        } else {
+            visit::visit_expr_await(self, node);
+        }
+    }
+
+    fn visit_expr_yield(&mut self, node: &'ast ExprYield) {
+        if self.kind == Suspend::Yield {
+            self.found = true;
+        } else {
+            visit::visit_expr_yield(self, node);
+        }
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'ast ExprClosure) {
+        // A nested closure is a separate scope.
+    }
+
+    fn visit_expr_async(&mut self, _node: &'ast ExprAsync) {
+        // A nested async block is a separate scope.
+    }
+
+    fn visit_item(&mut self, _node: &'ast Item) {
+        // A nested item is a separate scope.
+    }
+}
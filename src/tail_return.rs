@@ -0,0 +1,122 @@
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// Replaces a `return` at the end of a function body with the value it
+/// returns, so that `{ f(); return x; }` becomes `{ f(); x }`.
+///
+/// The block is taken to be the body of a function. Only its last statement
+/// is considered, and only if it is a `return` with a value, with or without
+/// a trailing semicolon, and without attributes. Early returns are kept, as
+/// is a `return;` without a value, which would otherwise leave the block
+/// without a tail expression of the right type.
+///
+/// The bodies of functions, methods, closures, and `async` blocks nested
+/// inside of the block are rewritten in the same way, since a `return` at
+/// the end of one of those returns from it. Other blocks are left alone even
+/// when a `return` is their last statement, since there it returns from the
+/// enclosing function rather than producing the value of the block. This
+/// includes the bodies of loops, the branches of an `if` or `match`, and
+/// `unsafe` and plain blocks, including one in tail position.
+///
+/// The rewrite does not always preserve the meaning of the code before the
+/// 2024 edition. Temporaries created in the tail expression of a block are
+/// dropped after the block's local variables, while those created in a
+/// `return` are dropped before them. For example, eliding the `return` in
+/// `let c = RefCell::new(1); return *c.borrow();` makes the `Ref` guard
+/// outlive `c`, which fails to compile, and a temporary with a destructor
+/// that observes the locals may behave differently. Callers that rewrite code
+/// for an earlier edition need to leave such returns alone themselves.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{elide_tail_return, parse_quote, Block};
+///
+/// let block: Block = parse_quote!({
+///     if input.is_empty() {
+///         return None;
+///     }
+///     let parsed = parse(input);
+///     return Some(parsed.map(|x| { return x + 1; }));
+/// });
+///
+/// let expected = quote!({
+///     if input.is_empty() {
+///         return None;
+///     }
+///     let parsed = parse(input);
+///     Some(parsed.map(|x| { x + 1 }))
+/// });
+///
+/// let block = elide_tail_return(block);
+/// assert_eq!(quote!(#block).to_string(), expected.to_string());
+/// ```
+pub fn elide_tail_return(block: Block) -> Block {
+    let mut block = TailReturnElider.fold_block(block);
+    elide(&mut block);
+    block
+}
+
+struct TailReturnElider;
+
+impl Fold for TailReturnElider {
+    fn fold_item_fn(&mut self, node: ItemFn) -> ItemFn {
+        let mut node = fold::fold_item_fn(self, node);
+        elide(&mut node.block);
+        node
+    }
+
+    fn fold_impl_item_method(&mut self, node: ImplItemMethod) -> ImplItemMethod {
+        let mut node = fold::fold_impl_item_method(self, node);
+        elide(&mut node.block);
+        node
+    }
+
+    fn fold_trait_item_method(&mut self, node: TraitItemMethod) -> TraitItemMethod {
+        let mut node = fold::fold_trait_item_method(self, node);
+        if let Some(block) = &mut node.default {
+            elide(block);
+        }
+        node
+    }
+
+    fn fold_expr_closure(&mut self, node: ExprClosure) -> ExprClosure {
+        let mut node = fold::fold_expr_closure(self, node);
+        if let Expr::Block(body) = &mut *node.body {
+            elide(&mut body.block);
+        }
+        node
+    }
+
+    fn fold_expr_async(&mut self, node: ExprAsync) -> ExprAsync {
+        let mut node = fold::fold_expr_async(self, node);
+        elide(&mut node.block);
+        node
+    }
+}
+
+// Moves the value of a trailing `return` into tail position. Before the 2024
+// edition this extends the lifetime of the temporaries in the value past the
+// locals of the block, see the caveat on `elide_tail_return`.
+fn elide(block: &mut Block) {
+    let last = match block.stmts.last_mut() {
+        Some(last) => last,
+        None => return,
+    };
+    let value = match last {
+        Stmt::Expr(Expr::Return(ret)) | Stmt::Semi(Expr::Return(ret), _)
+            if ret.attrs.is_empty() =>
+        {
+            match ret.expr.take() {
+                Some(value) => value,
+                None => return,
+            }
+        }
+        _ => return,
+    };
+    *last = Stmt::Expr(*value);
+}
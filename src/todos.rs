@@ -0,0 +1,70 @@
+use proc_macro2::Span;
+
+use crate::visit::{self, Visit};
+use crate::*;
+
+const MARKERS: &[&str] = &["TODO", "FIXME"];
+
+/// Finds the `TODO` and `FIXME` markers in the documentation of a file.
+///
+/// Ordinary comments are discarded when source code is tokenized, so only doc
+/// comments and `#[doc = "..."]` attributes are searched, using the text they
+/// decode to. Each line of documentation that contains a marker anywhere,
+/// including as part of a longer word, is reported once, in the order the
+/// lines appear in the file. The string reported is the rest of the line
+/// starting from its first marker, as in `"TODO: handle errors"`, and the span
+/// is that of the doc attribute's string, which for a doc comment is the span
+/// of the whole comment.
+///
+/// Attributes are found wherever they are attached in the syntax tree, but
+/// not inside of the tokens of macro invocations, which are not parsed.
+///
+/// *This function is available if Syn is built with the `"full"`, `"visit"`,
+/// and `"parsing"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{find_todos, parse_quote, File};
+///
+/// let file: File = parse_quote! {
+///     /// Reads the configuration.
+///     ///
+///     /// FIXME: this ignores the `HOME` variable.
+///     fn read_config() {}
+///
+///     #[doc = "Writes the configuration. TODO: keep a backup."]
+///     fn write_config() {}
+/// };
+///
+/// let todos: Vec<String> = find_todos(&file)
+///     .into_iter()
+///     .map(|(_span, todo)| todo)
+///     .collect();
+/// assert_eq!(
+///     todos,
+///     ["FIXME: this ignores the `HOME` variable.", "TODO: keep a backup."],
+/// );
+/// ```
+pub fn find_todos(file: &File) -> Vec<(Span, String)> {
+    let mut visitor = FindTodos(Vec::new());
+    visitor.visit_file(file);
+    visitor.0
+}
+
+struct FindTodos(Vec<(Span, String)>);
+
+impl<'ast> Visit<'ast> for FindTodos {
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        if let Some(doc) = node.doc_string() {
+            for line in doc.value().lines() {
+                let marker = MARKERS.iter().filter_map(|marker| line.find(marker)).min();
+                if let Some(start) = marker {
+                    let todo = line[start..].trim_end().to_owned();
+                    self.0.push((doc.span(), todo));
+                }
+            }
+        }
+        visit::visit_attribute(self, node);
+    }
+}
@@ -625,6 +625,7 @@ define_keywords! {
     "override"    pub struct Override     /// `override`
     "priv"        pub struct Priv         /// `priv`
     "pub"         pub struct Pub          /// `pub`
+    "raw"         pub struct Raw          /// `raw`
     "ref"         pub struct Ref          /// `ref`
     "return"      pub struct Return       /// `return`
     "Self"        pub struct SelfType     /// `Self`
@@ -748,6 +749,7 @@ macro_rules! export_token_macro {
             (override)    => { $crate::token::Override };
             (priv)        => { $crate::token::Priv };
             (pub)         => { $crate::token::Pub };
+            (raw)         => { $crate::token::Raw };
             (ref)         => { $crate::token::Ref };
             (return)      => { $crate::token::Return };
             (Self)        => { $crate::token::SelfType };
@@ -0,0 +1,108 @@
+use crate::*;
+
+/// Returns the methods of a trait that do not have a default body, which
+/// every impl of the trait must provide.
+///
+/// A method is required exactly when its [`default`] is `None`, as for
+/// `fn next(&mut self) -> Option<Self::Item>;`. The methods are returned in
+/// the order they are declared. Items produced by macro invocations inside
+/// of the trait are not known without expanding them and are not included.
+///
+/// [`default`]: struct.TraitItemMethod.html#structfield.default
+///
+/// *This function is available if Syn is built with the `"full"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, provided_methods, required_methods, ItemTrait};
+///
+/// let item: ItemTrait = parse_quote! {
+///     trait Shape {
+///         fn area(&self) -> f64;
+///         fn describe(&self) -> String {
+///             format!("a shape of area {}", self.area())
+///         }
+///     }
+/// };
+///
+/// let required: Vec<_> = required_methods(&item)
+///     .into_iter()
+///     .map(|method| method.sig.ident.to_string())
+///     .collect();
+/// assert_eq!(required, ["area"]);
+///
+/// let provided: Vec<_> = provided_methods(&item)
+///     .into_iter()
+///     .map(|method| method.sig.ident.to_string())
+///     .collect();
+/// assert_eq!(provided, ["describe"]);
+/// ```
+pub fn required_methods(item: &ItemTrait) -> Vec<&TraitItemMethod> {
+    item.items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(item) if item.default.is_none() => Some(item),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the methods of a trait that have a default body, which an impl of
+/// the trait may override.
+///
+/// These are the methods whose [`default`] is `Some`, in the order they are
+/// declared. See [`required_methods`] for the rest.
+///
+/// [`default`]: struct.TraitItemMethod.html#structfield.default
+/// [`required_methods`]: fn.required_methods.html
+///
+/// *This function is available if Syn is built with the `"full"` feature.*
+pub fn provided_methods(item: &ItemTrait) -> Vec<&TraitItemMethod> {
+    item.items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(item) if item.default.is_some() => Some(item),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the associated consts of a trait that do not have a default
+/// value, which every impl of the trait must provide.
+///
+/// An associated const is required exactly when its [`default`] is `None`,
+/// as for `const ID: u32;`. The consts are returned in the order they are
+/// declared.
+///
+/// [`default`]: struct.TraitItemConst.html#structfield.default
+///
+/// *This function is available if Syn is built with the `"full"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, required_consts, ItemTrait};
+///
+/// let item: ItemTrait = parse_quote! {
+///     trait Component {
+///         const ID: u32;
+///         const NAME: &'static str = "component";
+///     }
+/// };
+///
+/// let required: Vec<_> = required_consts(&item)
+///     .into_iter()
+///     .map(|item| item.ident.to_string())
+///     .collect();
+/// assert_eq!(required, ["ID"]);
+/// ```
+pub fn required_consts(item: &ItemTrait) -> Vec<&TraitItemConst> {
+    item.items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Const(item) if item.default.is_none() => Some(item),
+            _ => None,
+        })
+        .collect()
+}
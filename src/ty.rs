@@ -392,6 +392,180 @@ ast_enum! {
     }
 }
 
+impl Type {
+    /// Extracts the input types and output type of a function type.
+    ///
+    /// This handles function pointer types like `fn(A, B) -> C` as well as
+    /// trait objects and `impl Trait` types with an `Fn`, `FnMut`, or `FnOnce`
+    /// bound, like `dyn Fn(A) -> B` or `impl FnMut(A) + Send`. The `Fn` bound
+    /// may be written either with the usual parenthesized sugar or in its
+    /// desugared form `Fn<(A,), Output = B>`. Parentheses around the type are
+    /// looked through.
+    ///
+    /// The output is `None` if there is no return type, as in `fn(A)`.
+    /// Returns `None` if the type is not a function type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let ty: Type = parse_quote!(Box<dyn Fn(u8, u16) -> u32>);
+    /// assert!(ty.fn_signature().is_none());
+    ///
+    /// let ty: Type = parse_quote!(dyn Fn(u8, u16) -> u32 + Send);
+    /// let (inputs, output) = ty.fn_signature().unwrap();
+    /// assert_eq!(inputs.len(), 2);
+    /// assert!(output.is_some());
+    /// ```
+    pub fn fn_signature(&self) -> Option<(Vec<&Type>, Option<&Type>)> {
+        fn return_type(output: &ReturnType) -> Option<&Type> {
+            match output {
+                ReturnType::Default => None,
+                ReturnType::Type(_, ty) => Some(ty),
+            }
+        }
+
+        fn fn_trait_signature(
+            bounds: &Punctuated<TypeParamBound, Token![+]>,
+        ) -> Option<(Vec<&Type>, Option<&Type>)> {
+            bounds.iter().find_map(|bound| {
+                let segment = match bound {
+                    TypeParamBound::Trait(bound) => bound.path.segments.last()?,
+                    TypeParamBound::Lifetime(_) => return None,
+                };
+                if segment.ident != "Fn" && segment.ident != "FnMut" && segment.ident != "FnOnce" {
+                    return None;
+                }
+                match &segment.arguments {
+                    PathArguments::Parenthesized(args) => {
+                        Some((args.inputs.iter().collect(), return_type(&args.output)))
+                    }
+                    PathArguments::AngleBracketed(args) => {
+                        let mut inputs = None;
+                        let mut output = None;
+                        for arg in &args.args {
+                            match arg {
+                                GenericArgument::Type(Type::Tuple(tuple)) if inputs.is_none() => {
+                                    inputs = Some(tuple.elems.iter().collect());
+                                }
+                                GenericArgument::Binding(binding)
+                                    if binding.ident == "Output" && output.is_none() =>
+                                {
+                                    output = Some(&binding.ty);
+                                }
+                                _ => return None,
+                            }
+                        }
+                        Some((inputs?, output))
+                    }
+                    PathArguments::None => None,
+                }
+            })
+        }
+
+        match self {
+            Type::BareFn(ty) => Some((
+                ty.inputs.iter().map(|arg| &arg.ty).collect(),
+                return_type(&ty.output),
+            )),
+            Type::TraitObject(ty) => fn_trait_signature(&ty.bounds),
+            Type::ImplTrait(ty) => fn_trait_signature(&ty.bounds),
+            Type::Paren(ty) => ty.elem.fn_signature(),
+            Type::Group(ty) => ty.elem.fn_signature(),
+            _ => None,
+        }
+    }
+
+    /// The number of inputs of a function type, or `None` if this is not a
+    /// function type.
+    ///
+    /// See [`fn_signature`] for the types that are recognized as function
+    /// types.
+    ///
+    /// [`fn_signature`]: Type::fn_signature
+    pub fn arity(&self) -> Option<usize> {
+        self.fn_signature().map(|(inputs, _output)| inputs.len())
+    }
+}
+
+#[cfg(all(
+    feature = "extra-traits",
+    feature = "clone-impls",
+    feature = "visit-mut"
+))]
+impl Type {
+    /// Compares two types for equality after replacing every occurrence of
+    /// `Self` in either of them with `self_ty`.
+    ///
+    /// This is useful for checking a method signature in an impl block against
+    /// the corresponding signature in the trait definition, where the trait
+    /// refers to the implementing type as `Self` but the impl may spell it out.
+    /// `Self` is substituted wherever it appears as a type, including within
+    /// generic arguments, references, pointers, slices, arrays, and tuples.
+    ///
+    /// Only a bare `Self` is substituted. Associated type projections such as
+    /// `Self::Item` are left alone and compare equal only to the same
+    /// projection.
+    ///
+    /// *This method is available if Syn is built with the `"extra-traits"`,
+    /// `"clone-impls"`, and `"visit-mut"` features.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Type};
+    ///
+    /// let self_ty: Type = parse_quote!(MyStruct<T>);
+    ///
+    /// let in_trait: Type = parse_quote!(Option<&Self>);
+    /// let in_impl: Type = parse_quote!(Option<&MyStruct<T>>);
+    /// assert!(in_trait.matches_with_self(&in_impl, &self_ty));
+    ///
+    /// let other: Type = parse_quote!(Option<&mut MyStruct<T>>);
+    /// assert!(!in_trait.matches_with_self(&other, &self_ty));
+    /// ```
+    pub fn matches_with_self(&self, other: &Type, self_ty: &Type) -> bool {
+        use crate::visit_mut::VisitMut;
+
+        let mut substitute = SubstituteSelf(self_ty);
+
+        let mut this = self.clone();
+        substitute.visit_type_mut(&mut this);
+
+        let mut other = other.clone();
+        substitute.visit_type_mut(&mut other);
+
+        this == other
+    }
+}
+
+#[cfg(all(
+    feature = "extra-traits",
+    feature = "clone-impls",
+    feature = "visit-mut"
+))]
+struct SubstituteSelf<'a>(&'a Type);
+
+#[cfg(all(
+    feature = "extra-traits",
+    feature = "clone-impls",
+    feature = "visit-mut"
+))]
+impl<'a> crate::visit_mut::VisitMut for SubstituteSelf<'a> {
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        let is_self = match node {
+            Type::Path(ty) => ty.qself.is_none() && ty.path.is_ident("Self"),
+            _ => false,
+        };
+        if is_self {
+            *node = self.0.clone();
+        } else {
+            crate::visit_mut::visit_type_mut(self, node);
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -481,7 +655,7 @@ pub mod parsing {
                         elems.push_punct(content.parse()?);
                         let rest: Punctuated<Type, Token![,]> =
                             content.parse_terminated(Parse::parse)?;
-                        elems.extend(rest);
+                        elems.extend(rest.into_pairs());
                         elems
                     },
                 }));
@@ -0,0 +1,107 @@
+use crate::fold::{self, Fold};
+use crate::*;
+
+/// Removes pairs of unary operators that cancel each other out.
+///
+/// Exactly three patterns are folded, each to the operand `x`:
+///
+/// - `!!x`, a double logical or bitwise inversion;
+/// - `- -x`, a double negation, which has to be written with a space or with
+///   parentheses as in `-(-x)` since `--` is not an operator in Rust;
+/// - `*&x` and `*&mut x`, a dereference of a fresh reference.
+///
+/// Parentheses between the two operators, as in `!(!x)` or `*(&x)`, are
+/// removed along with them. The operand itself is left as written, so the
+/// result always has the same precedence as the expression it replaces.
+/// Nested pairs are folded from the inside out, so `!!!x` becomes `!x` and
+/// `- - - -x` becomes `x`. Operators that carry attributes are never folded.
+///
+/// The opposite order, `&*x`, is deliberately left alone. It reborrows `x` and
+/// frequently has a different type than `x`, such as `&str` for a `String`
+/// or `&T` for a `Box<T>`, so removing it could change the meaning of the
+/// program. For the same reason mixed pairs like `!-x` are never folded.
+///
+/// The syntax tree carries no type information, so `!!x` and `- -x` are
+/// folded on the assumption that `Not` and `Neg` are their own inverse, as
+/// they are for `bool` and the primitive integer and floating point types.
+/// Note that for a signed integer equal to its type's minimum value, `- -x`
+/// panics on overflow in debug builds while `x` does not.
+///
+/// *This function is available if Syn is built with the `"full"` and `"fold"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::quote;
+/// use syn::{parse_quote, simplify_unary, Expr};
+///
+/// let expr: Expr = parse_quote!(!!ready && - -n > *&limit + !!!x - &*s);
+/// let expr = simplify_unary(expr);
+///
+/// let expected = quote!(ready && n > limit + !x - &*s);
+/// assert_eq!(quote!(#expr).to_string(), expected.to_string());
+/// ```
+pub fn simplify_unary(expr: Expr) -> Expr {
+    SimplifyUnary.fold_expr(expr)
+}
+
+struct SimplifyUnary;
+
+impl Fold for SimplifyUnary {
+    fn fold_expr(&mut self, node: Expr) -> Expr {
+        let node = fold::fold_expr(self, node);
+        if !cancels(&node) {
+            return node;
+        }
+        match node {
+            Expr::Unary(outer) => match unparen(*outer.expr) {
+                Expr::Unary(inner) => *inner.expr,
+                Expr::Reference(inner) => *inner.expr,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+// Whether the expression is a unary operator applied directly, or through
+// parentheses, to an operator that it cancels out.
+fn cancels(expr: &Expr) -> bool {
+    let outer = match expr {
+        Expr::Unary(outer) if outer.attrs.is_empty() => outer,
+        _ => return false,
+    };
+    match (&outer.op, peel(&outer.expr)) {
+        (UnOp::Not(_), Expr::Unary(inner)) => match inner.op {
+            UnOp::Not(_) => inner.attrs.is_empty(),
+            _ => false,
+        },
+        (UnOp::Neg(_), Expr::Unary(inner)) => match inner.op {
+            UnOp::Neg(_) => inner.attrs.is_empty(),
+            _ => false,
+        },
+        (UnOp::Deref(_), Expr::Reference(inner)) => inner.attrs.is_empty(),
+        _ => false,
+    }
+}
+
+fn peel(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) if paren.attrs.is_empty() => peel(&paren.expr),
+        _ => expr,
+    }
+}
+
+fn unparen(expr: Expr) -> Expr {
+    match expr {
+        Expr::Paren(paren) => {
+            if paren.attrs.is_empty() {
+                unparen(*paren.expr)
+            } else {
+                Expr::Paren(paren)
+            }
+        }
+        expr => expr,
+    }
+}
@@ -0,0 +1,95 @@
+use crate::visit::{self, Visit};
+use crate::*;
+
+/// The number of times each kind of `unsafe` code appears in a file, as
+/// counted by [`unsafe_usage`].
+///
+/// *This type is available if Syn is built with the `"full"` and `"visit"`
+/// features.*
+#[derive(Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, Eq, PartialEq, Hash))]
+#[cfg_attr(feature = "clone-impls", derive(Clone, Copy))]
+pub struct UnsafeStats {
+    /// Unsafe block expressions, `unsafe { ... }`.
+    pub blocks: usize,
+    /// Functions and methods declared as `unsafe fn`, with or without a body.
+    pub fns: usize,
+    /// Trait impls declared as `unsafe impl`.
+    pub impls: usize,
+    /// Traits declared as `unsafe trait`.
+    pub traits: usize,
+}
+
+/// Counts the `unsafe` blocks, functions, impls, and traits in a file.
+///
+/// Declaring an `unsafe fn` is counted separately from using `unsafe` code
+/// inside of an `unsafe { ... }` block, so an unsafe function whose body
+/// contains an unsafe block counts once toward each of `fns` and `blocks`.
+/// Functions and methods are counted wherever they appear, including nested
+/// inside of other function bodies, in traits and impls, and in `extern`
+/// blocks.
+///
+/// The `unsafe` qualifier of a function pointer type like `unsafe fn()` is not
+/// counted, since it declares no code. Neither is anything inside of a macro
+/// invocation, whose tokens are not parsed.
+///
+/// *This function is available if Syn is built with the `"full"` and `"visit"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, unsafe_usage, File};
+///
+/// let file: File = parse_quote! {
+///     unsafe fn read(ptr: *const u8) -> u8 {
+///         unsafe { *ptr }
+///     }
+///
+///     fn main() {
+///         let x = 0;
+///         unsafe { read(&x) };
+///     }
+///
+///     unsafe impl Send for Handle {}
+/// };
+///
+/// let stats = unsafe_usage(&file);
+/// assert_eq!(stats.blocks, 2);
+/// assert_eq!(stats.fns, 1);
+/// assert_eq!(stats.impls, 1);
+/// assert_eq!(stats.traits, 0);
+/// ```
+pub fn unsafe_usage(file: &File) -> UnsafeStats {
+    let mut stats = UnsafeStats::default();
+    stats.visit_file(file);
+    stats
+}
+
+impl<'ast> Visit<'ast> for UnsafeStats {
+    fn visit_expr_unsafe(&mut self, node: &'ast ExprUnsafe) {
+        self.blocks += 1;
+        visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_signature(&mut self, node: &'ast Signature) {
+        if node.unsafety.is_some() {
+            self.fns += 1;
+        }
+        visit::visit_signature(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.unsafety.is_some() {
+            self.impls += 1;
+        }
+        visit::visit_item_impl(self, node);
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        if node.unsafety.is_some() {
+            self.traits += 1;
+        }
+        visit::visit_item_trait(self, node);
+    }
+}
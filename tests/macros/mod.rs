@@ -1,8 +1,11 @@
+#![allow(dead_code)]
+
 extern crate proc_macro2;
 
 #[path = "../debug/mod.rs"]
 pub mod debug;
 
+use quote::ToTokens;
 use syn;
 use syn::parse::{Parse, Result};
 
@@ -76,3 +79,25 @@ impl Tokens for proc_macro2::TokenStream {
         syn::parse2(self)
     }
 }
+
+/// Parses `input` as a `T`, rewrites it with `f`, and prints the result.
+pub fn rewrite<T, F>(f: F, input: &str) -> String
+where
+    T: Parse + ToTokens,
+    F: FnOnce(T) -> T,
+{
+    let node: T = syn::parse_str(input).unwrap();
+    f(node).into_token_stream().to_string()
+}
+
+/// Asserts that rewriting `input` with `f` gives the same tokens as
+/// `expected`, regardless of whitespace.
+pub fn assert_rewrite<T, F>(f: F, input: &str, expected: &str)
+where
+    T: Parse + ToTokens,
+    F: FnOnce(T) -> T,
+{
+    let expected: T = syn::parse_str(expected).unwrap();
+    let expected = expected.into_token_stream().to_string();
+    assert_eq!(rewrite(f, input), expected, "{}", input);
+}
@@ -0,0 +1,68 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{desugar_apit, parse_quote, ItemFn};
+
+#[test]
+fn test_without_impl_trait() {
+    let item: ItemFn = parse_quote! {
+        fn f<T: Clone>(t: T) -> impl Clone { t }
+    };
+
+    assert_eq!(desugar_apit(item.clone()), item);
+}
+
+#[test]
+fn test_appends_after_existing_params() {
+    let item: ItemFn = parse_quote! {
+        fn f<'a, T>(x: &'a T, y: impl Into<T> + 'a) where T: Copy {}
+    };
+
+    let expected: ItemFn = parse_quote! {
+        fn f<'a, T, T0>(x: &'a T, y: T0) where T: Copy, T0: Into<T> + 'a {}
+    };
+
+    assert_eq!(desugar_apit(item), expected);
+}
+
+#[test]
+fn test_nested_impl_trait() {
+    let item: ItemFn = parse_quote! {
+        fn f(self, v: Vec<impl Display>, it: impl Iterator<Item = impl Debug>) {}
+    };
+
+    let expected: ItemFn = parse_quote! {
+        fn f<T0, T1, T2>(self, v: Vec<T0>, it: T2)
+        where
+            T0: Display,
+            T1: Debug,
+            T2: Iterator<Item = T1>
+        {}
+    };
+
+    assert_eq!(desugar_apit(item), expected);
+}
+
+#[test]
+fn test_avoids_name_collisions() {
+    let item: ItemFn = parse_quote! {
+        fn f<T0>(a: T0, b: impl Clone, c: T2) {
+            let T3 = impl_trait_in_body();
+        }
+    };
+
+    let expected = quote! {
+        fn f<T0, T1>(a: T0, b: T1, c: T2)
+        where
+            T1: Clone
+        {
+            let T3 = impl_trait_in_body();
+        }
+    };
+
+    let desugared = desugar_apit(item);
+    assert_eq!(quote!(#desugared).to_string(), expected.to_string());
+}
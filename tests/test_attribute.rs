@@ -1,3 +1,4 @@
+extern crate quote;
 extern crate syn;
 
 mod features;
@@ -5,8 +6,9 @@ mod features;
 #[macro_use]
 mod macros;
 
+use quote::quote;
 use syn::parse::Parser;
-use syn::{Attribute, Meta};
+use syn::{Attribute, BinOp, Expr, ExprBinary, Meta};
 
 #[test]
 fn test_meta_item_word() {
@@ -288,6 +290,151 @@ fn test_bool_lit() {
     "###);
 }
 
+#[test]
+fn test_meta_item_multi_segment_path() {
+    let meta = test_round_trip("#[a::b::c(d = 1)]");
+
+    snapshot!(meta, @r###"
+   ⋮Meta::List {
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "a",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮            PathSegment {
+   ⋮                ident: "b",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮            PathSegment {
+   ⋮                ident: "c",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮    nested: [
+   ⋮        Meta(Meta::NameValue {
+   ⋮            path: Path {
+   ⋮                segments: [
+   ⋮                    PathSegment {
+   ⋮                        ident: "d",
+   ⋮                        arguments: None,
+   ⋮                    },
+   ⋮                ],
+   ⋮            },
+   ⋮            lit: 1,
+   ⋮        }),
+   ⋮    ],
+   ⋮}
+    "###);
+}
+
+#[test]
+fn test_meta_item_leading_colon_path() {
+    let meta = test_round_trip("#[::crate_name::attr]");
+
+    snapshot!(meta, @r###"
+   ⋮Path(Path {
+   ⋮    leading_colon: Some,
+   ⋮    segments: [
+   ⋮        PathSegment {
+   ⋮            ident: "crate_name",
+   ⋮            arguments: None,
+   ⋮        },
+   ⋮        PathSegment {
+   ⋮            ident: "attr",
+   ⋮            arguments: None,
+   ⋮        },
+   ⋮    ],
+   ⋮})
+    "###);
+}
+
+#[test]
+fn test_path_is() {
+    let attrs = Attribute::parse_outer
+        .parse_str("#[cfg(test)] #[::cfg] #[a::cfg] #[cfg_attr(x, y)]")
+        .unwrap();
+
+    assert!(attrs[0].path_is("cfg"));
+    assert!(!attrs[1].path_is("cfg"));
+    assert!(!attrs[2].path_is("cfg"));
+    assert!(!attrs[3].path_is("cfg"));
+}
+
+#[test]
+fn test_parse_args_expr() {
+    let attrs = Attribute::parse_outer
+        .parse_str("#[default(42 + 1)] #[default(42 1)] #[default] #[default = 42]")
+        .unwrap();
+
+    let expr: Expr = attrs[0].parse_args().unwrap();
+    match &expr {
+        Expr::Binary(ExprBinary {
+            op: BinOp::Add(_), ..
+        }) => {}
+        other => panic!("expected addition, found {:?}", other),
+    }
+    assert_eq!(quote!(#expr).to_string(), "42 + 1");
+
+    // The arguments must be parsed completely.
+    let err = attrs[1].parse_args::<Expr>().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token");
+
+    let err = attrs[2].parse_args::<Expr>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected attribute arguments: #[default(...)]"
+    );
+
+    let err = attrs[3].parse_args::<Expr>().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token in attribute arguments");
+}
+
+#[test]
+fn test_doc_attributes() {
+    let attrs = Attribute::parse_outer
+        .parse_str(
+            r#"
+            /// Sugared.
+            #[doc = "Explicit."]
+            #[doc(hidden)]
+            #[doc(alias = "x", hidden)]
+            #[doc(alias = "hidden")]
+            #[doc(cfg(unix))]
+            #[doc = "hidden"]
+            #[hidden]
+            #[not_doc = "text"]
+            "#,
+        )
+        .unwrap();
+
+    let docs: Vec<_> = attrs
+        .iter()
+        .map(|attr| attr.doc_string().map(|lit| lit.value()))
+        .collect();
+    assert_eq!(
+        docs,
+        [
+            Some(" Sugared.".to_owned()),
+            Some("Explicit.".to_owned()),
+            None,
+            None,
+            None,
+            None,
+            Some("hidden".to_owned()),
+            None,
+            None,
+        ],
+    );
+
+    let hidden: Vec<_> = attrs.iter().map(Attribute::is_doc_hidden).collect();
+    assert_eq!(
+        hidden,
+        [false, false, true, true, false, false, false, false, false],
+    );
+}
+
 fn test(input: &str) -> Meta {
     let attrs = Attribute::parse_outer.parse_str(input).unwrap();
 
@@ -296,3 +443,22 @@ fn test(input: &str) -> Meta {
 
     attr.parse_meta().unwrap()
 }
+
+fn test_round_trip(input: &str) -> Meta {
+    let attrs = Attribute::parse_outer.parse_str(input).unwrap();
+
+    assert_eq!(attrs.len(), 1);
+    let attr = attrs.into_iter().next().unwrap();
+    let meta = attr.parse_meta().unwrap();
+
+    let reparsed = Attribute::parse_outer
+        .parse2(quote!(#attr))
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(reparsed, attr);
+    assert_eq!(reparsed.parse_meta().unwrap(), meta);
+
+    meta
+}
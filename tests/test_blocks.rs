@@ -0,0 +1,95 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+mod macros;
+
+use crate::macros::{assert_rewrite, rewrite};
+use quote::quote;
+use syn::{collapse_trivial_blocks, Expr, File};
+
+#[test]
+fn test_match_arms() {
+    let input = "match x { A => { a } B => { { b } } C if c => { S { c } }, D => { d } }";
+    let expected = "match x { A => a, B => b, C if c => S { c }, D => d }";
+    assert_rewrite(collapse_trivial_blocks::<Expr>, input, expected);
+
+    let input = "match x { A => { if a { b } else { c } } B => { match b {} } }";
+    let expected = "match x { A => if a { b } else { c }, B => match b {} }";
+    assert_rewrite(collapse_trivial_blocks::<Expr>, input, expected);
+
+    let input = "match x { A => { a } B => { b }, }";
+    let expected = "match x { A => a, B => b, }";
+    assert_rewrite(collapse_trivial_blocks::<Expr>, input, expected);
+
+    // The collapsed arm gets a comma in the syntax tree, not only when printed.
+    let collapsed: Expr = syn::parse_str(&rewrite(
+        collapse_trivial_blocks::<Expr>,
+        "match x { A => { a } B => b }",
+    ))
+    .unwrap();
+    let expr: Expr = syn::parse_str("match x { A => { a } B => b }").unwrap();
+    assert_eq!(collapse_trivial_blocks(expr), collapsed);
+}
+
+#[test]
+fn test_closures() {
+    for &(input, expected) in &[
+        ("|a| { a + 1 }", "|a| a + 1"),
+        ("move || { { x } }", "move || x"),
+        ("|| { || { x } }", "|| || x"),
+        ("f(|a| { a }, |b| -> u8 { b })", "f(|a| a, |b| -> u8 { b })"),
+    ] {
+        assert_rewrite(collapse_trivial_blocks::<Expr>, input, expected);
+    }
+}
+
+#[test]
+fn test_not_collapsed() {
+    for &input in &[
+        "{ x }",
+        "{ v }.len()",
+        "{ a } + b",
+        "f({ x })",
+        "if c { x } else { y }",
+        "loop { x }",
+        "|| -> u8 { x }",
+        "|| { x; }",
+        "|| { let y = x; y }",
+        "|| { fn f() {} f() }",
+        "|| {}",
+        "|| 'a: { x }",
+        "|| #[attr] { x }",
+        "|| { #![attr] x }",
+        "|| { #[attr] x }",
+        "|| unsafe { x }",
+        "|| async { x }",
+        "match x { A => { a; } B => { let b = a; b } }",
+    ] {
+        assert_rewrite(collapse_trivial_blocks::<Expr>, input, input);
+    }
+}
+
+#[test]
+fn test_file() {
+    let file: File = syn::parse_str(
+        "fn f() { let y = { x }; g(|a| { a }); } impl S { fn h(&self) -> u8 { match self.0 { _ => { 1 } } } }",
+    )
+    .unwrap();
+    let file = collapse_trivial_blocks(file);
+
+    let expected = quote! {
+        fn f() {
+            let y = { x };
+            g(|a| a);
+        }
+        impl S {
+            fn h(&self) -> u8 {
+                match self.0 {
+                    _ => 1
+                }
+            }
+        }
+    };
+    assert_eq!(quote!(#file).to_string(), expected.to_string());
+}
@@ -0,0 +1,53 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{clear_spans, File};
+
+#[test]
+fn test_clear_spans_preserves_tokens() {
+    let tokens = quote! {
+        #[derive(Debug)]
+        #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+        struct S<'a, T: 'a> {
+            x: &'a [T; 1],
+        }
+
+        fn f() -> u8 {
+            println!("{} {:?}", 'c', [b'b', 2u8]);
+            1 + 1.0f32 as u8
+        }
+    };
+
+    let original: File = syn::parse2(tokens.clone()).unwrap();
+    let mut cleared = original.clone();
+    clear_spans(&mut cleared);
+
+    assert_eq!(original, cleared);
+    assert_eq!(quote!(#cleared).to_string(), tokens.to_string());
+}
+
+#[cfg(feature = "layout")]
+#[test]
+fn test_clear_spans_resets_locations() {
+    let mut item: syn::ItemFn = syn::parse_str("fn f() {\n    g(x)\n}").unwrap();
+
+    assert_eq!(location(item.sig.ident.span()), ((1, 3), (1, 4)));
+    assert_eq!(location(item.block.brace_token.span), ((1, 7), (3, 1)));
+
+    clear_spans(&mut item);
+
+    let call_site = location(proc_macro2::Span::call_site());
+    assert_eq!(location(item.sig.ident.span()), call_site);
+    assert_eq!(location(item.block.brace_token.span), call_site);
+}
+
+#[cfg(feature = "layout")]
+fn location(span: proc_macro2::Span) -> ((usize, usize), (usize, usize)) {
+    let start = span.start();
+    let end = span.end();
+    ((start.line, start.column), (end.line, end.column))
+}
@@ -0,0 +1,140 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{dedent_str_lits, File, Lit};
+
+// Returns the tokens of each literal in the file, searching inside of groups.
+fn literals(file: &File) -> Vec<String> {
+    fn collect(tokens: TokenStream, out: &mut Vec<String>) {
+        for token in tokens {
+            match token {
+                TokenTree::Literal(literal) => out.push(literal.to_string()),
+                TokenTree::Group(group) => collect(group.stream(), out),
+                _ => {}
+            }
+        }
+    }
+
+    let mut literals = Vec::new();
+    collect(quote!(#file), &mut literals);
+    literals
+}
+
+fn dedent(source: &str) -> Vec<String> {
+    let mut file: File = syn::parse_str(source).unwrap();
+    dedent_str_lits(&mut file, |_| true);
+    literals(&file)
+}
+
+fn value(repr: &str) -> String {
+    match syn::parse_str::<Lit>(repr).unwrap() {
+        Lit::Str(lit) => lit.value(),
+        _ => panic!("expected string literal"),
+    }
+}
+
+#[test]
+fn test_cooked() {
+    let literals = dedent("const S: &str = \"\n    a\n      b\n\n    c\n\";");
+    assert_eq!(literals, ["\"\na\n  b\n\nc\n\""]);
+
+    let literals = dedent(r#"const S: &str = "\n    a \"q\" \\\n    \tb\n";"#);
+    assert_eq!(literals, [r#""\na \"q\" \\\n\tb\n""#]);
+    assert_eq!(value(&literals[0]), "\na \"q\" \\\n\tb\n");
+
+    let literals = dedent(r#"const S: &str = "  a\n  b\u{e9}'\x41";"#);
+    assert_eq!(value(&literals[0]), "a\nb\u{e9}'A");
+}
+
+#[test]
+fn test_raw() {
+    let source = "const S: &str = r##\"\n    a\"#\n    \\n b\n  \"##;";
+    let literals = dedent(source);
+    assert_eq!(literals, ["r##\"\na\"#\n\\n b\n\"##"]);
+    assert_eq!(value(&literals[0]), "\na\"#\n\\n b\n");
+}
+
+#[test]
+fn test_suffix() {
+    // The lexer that proc-macro2 uses outside of a procedural macro splits a
+    // suffixed string into a string and an identifier.
+    let tokens: TokenStream = "\"a\"suffix".parse().unwrap();
+    if tokens.into_iter().count() != 1 {
+        return;
+    }
+
+    let literals = dedent("m!(\"  a\n  b\"suffix);");
+    assert_eq!(literals, ["\"a\nb\"suffix"]);
+}
+
+#[test]
+fn test_unchanged() {
+    for &source in &[
+        "const S: &str = \"a\n  b\";",
+        "const S: &str = \"\\x41\\\n    b\";",
+        "const S: &str = r\"a\";",
+        "const S: &[u8] = b\"  a\n  b\";",
+        "const C: char = ' ';",
+    ] {
+        let file: File = syn::parse_str(source).unwrap();
+        assert_eq!(dedent(source), literals(&file), "{}", source);
+    }
+}
+
+#[test]
+fn test_blank_lines() {
+    let literals = dedent("const S: &str = \"\n    a\n  \n\t\n    b\n    \";");
+    assert_eq!(value(&literals[0]), "\na\n\n\nb\n");
+
+    let literals = dedent("const S: &str = \"\ta\n\t\tb\n    c\";");
+    assert_eq!(value(&literals[0]), "\ta\n\t\tb\n    c");
+}
+
+#[test]
+fn test_attributes_and_macros() {
+    let source = "
+        /// Doc
+        ///     indented
+        #[doc = \"  x\n  y\"]
+        fn f() {
+            println!(\"  {}\n  {}\", \"  z\", [\"  w\n  v\"]);
+        }
+    ";
+    let mut file: File = syn::parse_str(source).unwrap();
+    let mut seen = Vec::new();
+    dedent_str_lits(&mut file, |lit| {
+        seen.push(lit.value());
+        lit.value().contains('\n')
+    });
+    assert_eq!(
+        seen,
+        [
+            " Doc",
+            "     indented",
+            "  x\n  y",
+            "  {}\n  {}",
+            "  z",
+            "  w\n  v"
+        ],
+    );
+
+    let values: Vec<String> = literals(&file).iter().map(|repr| value(repr)).collect();
+    assert_eq!(
+        values,
+        [" Doc", "     indented", "x\ny", "{}\n{}", "  z", "w\nv"]
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let source = "#[doc = \"  a\n  b\"] const S: &str = r\"  c\n  d\";";
+    let mut file: File = syn::parse_str(source).unwrap();
+    dedent_str_lits(&mut file, |_| true);
+    let reparsed: File = syn::parse2(quote!(#file)).unwrap();
+    assert_eq!(reparsed, file);
+}
@@ -895,3 +895,89 @@ fn test_ambiguous_crate() {
    ⋮}
     "###);
 }
+
+#[test]
+fn test_field_helper_attrs() {
+    let input = quote! {
+        struct S {
+            #[serde(rename = "x", with = <T as Trait>::module, 1 + 1)]
+            #[doc = r" documentation"]
+            #[custom @ $ weird { tokens } ]
+            x: u8,
+        }
+    };
+
+    let input: DeriveInput = syn::parse2(input).unwrap();
+    let fields = match input.data {
+        Data::Struct(data) => data.fields,
+        _ => panic!("expected a struct"),
+    };
+    let field = fields.iter().next().unwrap();
+
+    assert_eq!(field.attrs.len(), 3);
+
+    let serde = field.find_attr("serde").unwrap();
+    assert_eq!(
+        serde.tokens.to_string(),
+        quote!((rename = "x", with = <T as Trait>::module, 1 + 1)).to_string(),
+    );
+
+    let custom = field.find_attr("custom").unwrap();
+    assert_eq!(
+        custom.tokens.to_string(),
+        quote!(@ $ weird { tokens }).to_string()
+    );
+
+    assert!(field.find_attr("missing").is_none());
+}
+
+#[test]
+fn test_fields_and_variants() {
+    fn field_names(input: &DeriveInput) -> Vec<String> {
+        input
+            .fields()
+            .map(|field| match &field.ident {
+                Some(ident) => ident.to_string(),
+                None => "_".to_owned(),
+            })
+            .collect()
+    }
+
+    let input = quote! {
+        struct S<T> {
+            a: T,
+            b: u8,
+        }
+    };
+    let input: DeriveInput = syn::parse2(input).unwrap();
+    assert_eq!(field_names(&input), ["a", "b"]);
+    assert!(input.variants().is_none());
+
+    let input = quote! {
+        enum E {
+            Unit,
+            Tuple(u8, u16),
+            Empty {},
+            Named { x: i32 },
+        }
+    };
+    let input: DeriveInput = syn::parse2(input).unwrap();
+    assert_eq!(field_names(&input), ["_", "_", "x"]);
+    assert_eq!(input.variants().unwrap().len(), 4);
+
+    let input = quote! {
+        union U {
+            f: f32,
+            i: u32,
+        }
+    };
+    let input: DeriveInput = syn::parse2(input).unwrap();
+    assert_eq!(field_names(&input), ["f", "i"]);
+    assert!(input.variants().is_none());
+
+    let input = quote! {
+        struct Unit;
+    };
+    let input: DeriveInput = syn::parse2(input).unwrap();
+    assert_eq!(input.fields().count(), 0);
+}
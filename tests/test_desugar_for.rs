@@ -0,0 +1,132 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+mod macros;
+
+use crate::macros::{assert_rewrite, rewrite};
+use quote::quote;
+use syn::{desugar_for, Expr};
+
+#[test]
+fn test_simple() {
+    let input = "for x in v { f(x); }";
+    let expected = "
+        match ::core::iter::IntoIterator::into_iter(v) {
+            mut __iter0 => loop {
+                match ::core::iter::Iterator::next(&mut __iter0) {
+                    ::core::option::Option::Some(x) => { f(x); }
+                    ::core::option::Option::None => break,
+                }
+            }
+        }
+    ";
+    assert_rewrite(desugar_for, input, expected);
+}
+
+#[test]
+fn test_label_and_attrs() {
+    let input =
+        "#[allow(unused)] 'a: for (i, x) in 0..n { #![allow(unreachable_code)] continue 'a; }";
+    let expected = "
+        #[allow(unused)]
+        match ::core::iter::IntoIterator::into_iter(0..n) {
+            mut __iter0 => 'a: loop {
+                match ::core::iter::Iterator::next(&mut __iter0) {
+                    ::core::option::Option::Some((i, x)) => {
+                        #![allow(unreachable_code)]
+                        continue 'a;
+                    }
+                    ::core::option::Option::None => break,
+                }
+            }
+        }
+    ";
+    assert_rewrite(desugar_for, input, expected);
+}
+
+#[test]
+fn test_nested() {
+    let input = "for x in xs { for y in ys(x) { g(x, y); } }";
+    let expected = "
+        match ::core::iter::IntoIterator::into_iter(xs) {
+            mut __iter1 => loop {
+                match ::core::iter::Iterator::next(&mut __iter1) {
+                    ::core::option::Option::Some(x) => {
+                        match ::core::iter::IntoIterator::into_iter(ys(x)) {
+                            mut __iter0 => loop {
+                                match ::core::iter::Iterator::next(&mut __iter0) {
+                                    ::core::option::Option::Some(y) => { g(x, y); }
+                                    ::core::option::Option::None => break,
+                                }
+                            }
+                        }
+                    }
+                    ::core::option::Option::None => break,
+                }
+            }
+        }
+    ";
+    assert_rewrite(desugar_for, input, expected);
+
+    // Loops in the iterator expression are lowered too.
+    let input = "for x in { for y in ys {} v } {}";
+    let desugared = rewrite(desugar_for, input);
+    assert!(!desugared.contains("for"));
+    assert!(desugared.contains("__iter0") && desugared.contains("__iter1"));
+}
+
+#[test]
+fn test_fresh_idents() {
+    let input = "for x in v { __iter0 + __iter2; println!(\"{}\", __iter1); }";
+    let expected = "
+        match ::core::iter::IntoIterator::into_iter(v) {
+            mut __iter3 => loop {
+                match ::core::iter::Iterator::next(&mut __iter3) {
+                    ::core::option::Option::Some(x) => {
+                        __iter0 + __iter2;
+                        println!(\"{}\", __iter1);
+                    }
+                    ::core::option::Option::None => break,
+                }
+            }
+        }
+    ";
+    assert_rewrite(desugar_for, input, expected);
+}
+
+#[test]
+fn test_no_loops() {
+    let input = "while let Some(x) = it.next() { loop { f(x) } }";
+    assert_rewrite(desugar_for, input, input);
+}
+
+#[test]
+fn test_reparse() {
+    let input = "|v: Vec<u8>| { let mut sum = 0; 'a: for x in v { for _ in 0..x { sum += 1; break 'a; } } sum }";
+    let desugared = rewrite(desugar_for, input);
+    let reparsed: Expr = syn::parse_str(&desugared).unwrap();
+    assert_eq!(quote!(#reparsed).to_string(), desugared);
+}
+
+#[test]
+fn test_verbatim() {
+    // Trees that do not parse back from their tokens are moved over as is.
+    let mut expr: Expr = syn::parse_str("for x in v { f(x); }").unwrap();
+    if let Expr::ForLoop(node) = &mut expr {
+        *node.expr = Expr::Verbatim(quote!(not an expression));
+    }
+    let expr = desugar_for(expr);
+
+    let iterable = match &expr {
+        Expr::Match(node) => match &*node.expr {
+            Expr::Call(call) => &call.args[0],
+            _ => panic!("expected call"),
+        },
+        _ => panic!("expected match"),
+    };
+    assert_eq!(
+        quote!(#iterable).to_string(),
+        quote!(not an expression).to_string(),
+    );
+}
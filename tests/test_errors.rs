@@ -0,0 +1,67 @@
+extern crate proc_macro2;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Span;
+use syn::{Error, Errors, Type};
+
+#[test]
+fn test_display_joins_messages() {
+    let mut errors = Errors::new();
+    assert!(errors.is_empty());
+    assert_eq!(errors.to_string(), "");
+
+    errors.push(Error::new(Span::call_site(), "first"));
+    errors.extend(vec![
+        Error::new(Span::call_site(), "second"),
+        Error::new(Span::call_site(), "third"),
+    ]);
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors.to_string(), "first\nsecond\nthird");
+}
+
+#[test]
+fn test_into_iter() {
+    let errors: Errors = vec!["a", "b"]
+        .into_iter()
+        .map(|message| Error::new(Span::call_site(), message))
+        .collect();
+
+    let borrowed: Vec<String> = (&errors).into_iter().map(Error::to_string).collect();
+    assert_eq!(borrowed, ["a", "b"]);
+
+    let owned: Vec<String> = errors.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(owned, ["a", "b"]);
+}
+
+#[test]
+fn test_to_compile_error() {
+    let errors = Errors::from(vec![
+        Error::new(Span::call_site(), "a"),
+        Error::new(Span::call_site(), "b"),
+    ]);
+
+    let expected = "compile_error ! { \"a\" } compile_error ! { \"b\" }";
+    assert_eq!(errors.to_compile_error().to_string(), expected);
+}
+
+#[test]
+fn test_parse_error_span() {
+    let inputs = [
+        ("HashMap<String,\n        = u8>", (2, 8)),
+        ("fn(u8) -> ;", (1, 10)),
+        ("Vec<u8> extra", (1, 8)),
+    ];
+    for &(input, _position) in &inputs {
+        let result = syn::parse_str::<Type>(input);
+        assert!(result.is_err(), "expected an error parsing {:?}", input);
+
+        #[cfg(feature = "layout")]
+        {
+            let start = result.err().unwrap().span().start();
+            assert_eq!((start.line, start.column), _position, "{:?}", input);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 extern crate proc_macro2;
+extern crate quote;
 extern crate syn;
 
 #[macro_use]
@@ -7,7 +8,12 @@ mod macros;
 use std::str::FromStr;
 
 use proc_macro2::TokenStream;
-use syn::{Expr, ExprRange};
+use quote::quote;
+use syn::{
+    Block, Expr, ExprAssignOp, ExprBinary, ExprBlock, ExprBreak, ExprClosure, ExprConst, ExprLit,
+    ExprRange, ExprRawAddr, ExprReference, ExprUnary, GenericArgument, Item, Lit, PathArguments,
+    PointerMutability, Stmt, Type,
+};
 
 #[test]
 fn test_expr_parse() {
@@ -38,3 +44,479 @@ fn test_await() {
    ⋮}
     "###);
 }
+
+#[test]
+fn test_postfix_chain() {
+    // Collects the postfix operators from outermost to innermost.
+    fn chain(mut expr: &Expr) -> Vec<&'static str> {
+        let mut ops = Vec::new();
+        loop {
+            expr = match expr {
+                Expr::Field(e) => {
+                    ops.push("field");
+                    &e.base
+                }
+                Expr::Index(e) => {
+                    ops.push("index");
+                    &e.expr
+                }
+                Expr::MethodCall(e) => {
+                    ops.push("method");
+                    &e.receiver
+                }
+                Expr::Call(e) => {
+                    ops.push("call");
+                    &e.func
+                }
+                Expr::Try(e) => {
+                    ops.push("try");
+                    &e.expr
+                }
+                Expr::Await(e) => {
+                    ops.push("await");
+                    &e.base
+                }
+                Expr::Path(_) => return ops,
+                _ => panic!("unexpected expression in postfix chain"),
+            };
+        }
+    }
+
+    let code = "obj.field[idx].method()?.other";
+    let expr = syn::parse_str::<Expr>(code).unwrap();
+    assert_eq!(chain(&expr), ["field", "try", "method", "index", "field"]);
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+
+    let code = "a[0]?";
+    let expr = syn::parse_str::<Expr>(code).unwrap();
+    assert_eq!(chain(&expr), ["try", "index"]);
+
+    let code = "a.b?.c";
+    let expr = syn::parse_str::<Expr>(code).unwrap();
+    assert_eq!(chain(&expr), ["field", "try", "field"]);
+
+    let code = "foo().await?.bar().await[0]";
+    let expr = syn::parse_str::<Expr>(code).unwrap();
+    assert_eq!(
+        chain(&expr),
+        ["index", "await", "method", "try", "await", "call"],
+    );
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+}
+
+#[test]
+fn test_array_and_repeat() {
+    let expr = syn::parse_str::<Expr>("[0u8; N]").unwrap();
+    match &expr {
+        Expr::Repeat(repeat) => {
+            match &*repeat.expr {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(int), ..
+                }) => {
+                    assert_eq!(int.base10_digits(), "0");
+                    assert_eq!(int.suffix(), "u8");
+                }
+                _ => panic!("expected integer literal element"),
+            }
+            match &*repeat.len {
+                Expr::Path(path) => assert!(path.path.is_ident("N")),
+                _ => panic!("expected path length"),
+            }
+        }
+        _ => panic!("expected Expr::Repeat"),
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+
+    let expr = syn::parse_str::<Expr>("[x; 3]").unwrap();
+    match &expr {
+        Expr::Repeat(repeat) => match &*repeat.len {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => {
+                assert_eq!(int.base10_digits(), "3");
+            }
+            _ => panic!("expected integer literal length"),
+        },
+        _ => panic!("expected Expr::Repeat"),
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+
+    let expr = syn::parse_str::<Expr>("[a, b, c]").unwrap();
+    match &expr {
+        Expr::Array(array) => assert_eq!(array.elems.len(), 3),
+        _ => panic!("expected Expr::Array"),
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+}
+
+#[test]
+fn test_qualified_path() {
+    let expr: Expr = syn::parse_str("<Vec<u8> as Default>::default::<>()").unwrap();
+
+    let func = match &expr {
+        Expr::Call(call) => &call.func,
+        _ => panic!("expected call expression"),
+    };
+    let path = match &**func {
+        Expr::Path(path) => path,
+        _ => panic!("expected path expression"),
+    };
+
+    // The qself position counts the `Default` segment as part of the trait.
+    let qself = path.qself.as_ref().unwrap();
+    assert!(qself.as_token.is_some());
+    assert_eq!(qself.position, 1);
+    assert_eq!(path.path.segments.len(), 2);
+    assert_eq!(path.path.segments[0].ident, "Default");
+    assert_eq!(path.path.segments[1].ident, "default");
+
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+}
+
+#[test]
+fn test_builders() {
+    let recv: Expr = syn::parse_str("a + b").unwrap();
+    let method = syn::parse_str("pow").unwrap();
+    let arg: Expr = syn::parse_str("2").unwrap();
+    let call = Expr::method_call(recv, method, vec![arg]);
+    let expected: Expr = syn::parse_str("(a + b).pow(2)").unwrap();
+    assert_eq!(call, expected);
+
+    let func: Expr = syn::parse_str("|x| x").unwrap();
+    let call = Expr::call(
+        func,
+        vec![Expr::field(
+            Expr::path(syn::parse_str::<syn::Path>("s").unwrap()),
+            1,
+        )],
+    );
+    let expected: Expr = syn::parse_str("(|x| x)(s.1)").unwrap();
+    assert_eq!(call, expected);
+
+    let path: syn::Path = syn::parse_str("std::mem::take").unwrap();
+    let field = syn::parse_str::<syn::Ident>("inner").unwrap();
+    let call = Expr::call(Expr::path(path), Vec::new());
+    let expr = Expr::field(call, field);
+    let expected: Expr = syn::parse_str("std::mem::take().inner").unwrap();
+    assert_eq!(expr, expected);
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+}
+
+#[test]
+fn test_builders_round_trip() {
+    // A field callee must not print as a method call `s.f(x)`.
+    let field = syn::parse_str::<syn::Ident>("f").unwrap();
+    let func = Expr::field(Expr::path(syn::parse_str::<syn::Path>("s").unwrap()), field);
+    let call = Expr::call(func, vec![syn::parse_str("x").unwrap()]);
+    assert_eq!(quote!(#call).to_string(), quote!((s.f)(x)).to_string());
+    assert_eq!(syn::parse2::<Expr>(quote!(#call)).unwrap(), call);
+
+    // An integer base must not print as the float `1.0`.
+    let expr = Expr::field(syn::parse_str("1").unwrap(), 0);
+    assert_eq!(quote!(#expr).to_string(), quote!((1).0).to_string());
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+}
+
+#[test]
+fn test_tuple_multi_index() {
+    for &input in &["t.0.1.2", "t.0.1 .2", "t.0 .1.2", "t .0 .1 .2"] {
+        let expr: Expr = syn::parse_str(input).unwrap();
+        assert_eq!(quote!(#expr).to_string(), "t . 0 . 1 . 2", "{}", input);
+    }
+
+    snapshot!("t.0.0" as Expr, @r###"
+   ⋮Expr::Field {
+   ⋮    base: Expr::Field {
+   ⋮        base: Expr::Path {
+   ⋮            path: Path {
+   ⋮                segments: [
+   ⋮                    PathSegment {
+   ⋮                        ident: "t",
+   ⋮                        arguments: None,
+   ⋮                    },
+   ⋮                ],
+   ⋮            },
+   ⋮        },
+   ⋮        member: Unnamed(Index {
+   ⋮            index: 0,
+   ⋮        }),
+   ⋮    },
+   ⋮    member: Unnamed(Index {
+   ⋮        index: 0,
+   ⋮    }),
+   ⋮}
+    "###);
+
+    assert!(syn::parse_str::<Expr>("t.0.1e1").is_err());
+    assert!(syn::parse_str::<Expr>("t.0.1f32").is_err());
+}
+
+#[test]
+fn test_operators() {
+    let ops = |input: &str| match syn::parse_str(input).unwrap() {
+        Expr::Binary(ExprBinary { op, .. }) | Expr::AssignOp(ExprAssignOp { op, .. }) => op,
+        other => panic!("expected binary expression, found {:?}", other),
+    };
+
+    for &input in &[
+        "a + b", "a && b", "a << b", "a == b", "a >= b", "a += b", "a <<= b",
+    ] {
+        let op = ops(input);
+        assert_eq!(format!("a {} b", op.as_str()), input);
+    }
+
+    assert!(ops("a < b").is_comparison());
+    assert!(ops("a != b").is_comparison());
+    assert!(!ops("a & b").is_comparison());
+    assert!(!ops("a <<= b").is_comparison());
+
+    assert!(ops("a -= b").is_assign());
+    assert!(ops("a >>= b").is_assign());
+    assert!(!ops("a - b").is_assign());
+    assert!(!ops("a <= b").is_assign());
+
+    let ops: Vec<_> = ["!a", "-a", "*a"]
+        .iter()
+        .map(|input| match syn::parse_str(input).unwrap() {
+            Expr::Unary(ExprUnary { op, .. }) => op.as_str(),
+            other => panic!("expected unary expression, found {:?}", other),
+        })
+        .collect();
+    assert_eq!(ops, ["!", "-", "*"]);
+}
+
+#[test]
+fn test_labeled_block() {
+    let expr: Expr = syn::parse_str("'a: { break 'a 5; }").unwrap();
+    let block = match &expr {
+        Expr::Block(ExprBlock {
+            label: Some(label),
+            block,
+            ..
+        }) => {
+            assert_eq!(label.name.ident, "a");
+            block
+        }
+        other => panic!("expected labeled block, found {:?}", other),
+    };
+    match &block.stmts[0] {
+        Stmt::Semi(Expr::Break(ExprBreak { label, expr, .. }), _) => {
+            assert_eq!(label.as_ref().unwrap().ident, "a");
+            assert_eq!(quote!(#expr).to_string(), "5");
+        }
+        other => panic!("expected break, found {:?}", other),
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+}
+
+#[test]
+fn test_async_move_closure() {
+    let expr: Expr = syn::parse_str("async move |x| { x.await }").unwrap();
+    match &expr {
+        Expr::Closure(ExprClosure {
+            asyncness: Some(_),
+            capture: Some(_),
+            inputs,
+            ..
+        }) => assert_eq!(inputs.len(), 1),
+        other => panic!("expected async move closure, found {:?}", other),
+    }
+    let tokens = quote!(#expr);
+    assert_eq!(
+        tokens.to_string(),
+        quote!(async move |x| { x.await }).to_string()
+    );
+
+    assert!(syn::parse_str::<Expr>("move async |x| x").is_err());
+}
+
+#[test]
+fn test_inline_const() {
+    let block = quote!(const { N * 2 });
+
+    let expr: Expr = syn::parse2(block.clone()).unwrap();
+    match &expr {
+        Expr::Const(ExprConst { block, .. }) => assert_eq!(block.stmts.len(), 1),
+        other => panic!("expected inline const, found {:?}", other),
+    }
+
+    // The same block as an array length, a repeat length, and a generic
+    // argument all parse to the same expression.
+    let ty: Type = syn::parse2(quote!([u8; #block])).unwrap();
+    match &ty {
+        Type::Array(ty) => assert_eq!(ty.len, expr),
+        other => panic!("expected array type, found {:?}", other),
+    }
+    let repeat: Expr = syn::parse2(quote!([0; #block])).unwrap();
+    match &repeat {
+        Expr::Repeat(repeat) => assert_eq!(*repeat.len, expr),
+        other => panic!("expected repeat, found {:?}", other),
+    }
+    let ty: Type = syn::parse2(quote!(Foo<#block>)).unwrap();
+    let arguments = match &ty {
+        Type::Path(ty) => &ty.path.segments[0].arguments,
+        other => panic!("expected path, found {:?}", other),
+    };
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => match &arguments.args[0] {
+            GenericArgument::Const(arg) => assert_eq!(*arg, expr),
+            other => panic!("expected const argument, found {:?}", other),
+        },
+        other => panic!("expected angle bracketed arguments, found {:?}", other),
+    }
+
+    for node in &[quote!(#expr), quote!(#ty), quote!(#repeat)] {
+        assert_eq!(node.to_string().matches("const").count(), 1);
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+    assert_eq!(syn::parse2::<Type>(quote!(#ty)).unwrap(), ty);
+
+    // As a statement, an inline const block does not need a semicolon, while
+    // a const item is still parsed as an item.
+    let block: Block =
+        syn::parse_str("{ const { assert!(N > 0) } const M: usize = N; M }").unwrap();
+    match &block.stmts[..] {
+        [Stmt::Expr(Expr::Const(_)), Stmt::Item(Item::Const(_)), Stmt::Expr(_)] => {}
+        other => panic!("unexpected statements {:?}", other),
+    }
+}
+
+#[test]
+fn test_raw_addr() {
+    let expr: Expr = syn::parse_str("&raw const x.field").unwrap();
+    match &expr {
+        Expr::RawAddr(ExprRawAddr {
+            mutability: PointerMutability::Const(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Field(_) => {}
+            other => panic!("expected field access, found {:?}", other),
+        },
+        other => panic!("expected raw borrow, found {:?}", other),
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+
+    let expr: Expr = syn::parse_str("&raw mut *ptr").unwrap();
+    match &expr {
+        Expr::RawAddr(ExprRawAddr {
+            mutability: PointerMutability::Mut(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Unary(_) => {}
+            other => panic!("expected dereference, found {:?}", other),
+        },
+        other => panic!("expected raw borrow, found {:?}", other),
+    }
+    assert_eq!(syn::parse2::<Expr>(quote!(#expr)).unwrap(), expr);
+
+    // Without a following `const` or `mut`, `raw` is an ordinary variable.
+    for input in &["&raw", "&mut raw", "&raw.field", "&raw as *const u8"] {
+        let expr: Expr = syn::parse_str(input).unwrap();
+        let reference = match &expr {
+            Expr::Reference(reference) => reference,
+            Expr::Cast(cast) => match &*cast.expr {
+                Expr::Reference(reference) => reference,
+                other => panic!("expected reference, found {:?}", other),
+            },
+            other => panic!("expected reference, found {:?}", other),
+        };
+        let ExprReference { expr, .. } = reference;
+        assert!(quote!(#expr).to_string().starts_with("raw"));
+    }
+}
+
+#[test]
+fn test_postfix_chain_round_trip() {
+    let chains = [
+        quote!(a().await?.b().await?),
+        quote!(a.b?.c().await.d[0]?.e),
+        quote!(client.get(url).send().await?.json::<T>().await?[0].name),
+        quote!(x?.0.await[i]?.1?),
+        quote!(f()?()?.await),
+        quote!(self.inner.lock().await.items[idx]?.value.await?),
+        quote!((a + b).await?.c),
+        quote!((*ptr).field.await?),
+        quote!(async { x }.await?.y),
+        quote!([a, b][i].await?.len()),
+        quote!(m!(x).await?[0]),
+    ];
+    for tokens in &chains {
+        let expr: Expr = syn::parse2(tokens.clone()).unwrap();
+        let printed = quote!(#expr);
+        assert_eq!(printed.to_string(), tokens.to_string());
+        assert_eq!(syn::parse2::<Expr>(printed).unwrap(), expr);
+    }
+
+    // The chain associates to the left, so the outermost node is the last
+    // postfix operator.
+    let expr: Expr = syn::parse2(quote!(a().await?.b().await?)).unwrap();
+    let mut ops = Vec::new();
+    let mut expr = &expr;
+    loop {
+        expr = match expr {
+            Expr::Try(e) => {
+                ops.push("?");
+                &e.expr
+            }
+            Expr::Await(e) => {
+                ops.push("await");
+                &e.base
+            }
+            Expr::MethodCall(e) => {
+                ops.push("method");
+                &e.receiver
+            }
+            Expr::Call(e) => {
+                ops.push("call");
+                &e.func
+            }
+            Expr::Path(_) => break,
+            other => panic!("unexpected expression {:?}", other),
+        };
+    }
+    assert_eq!(ops, ["?", "await", "method", "?", "await", "call"]);
+}
+
+#[test]
+fn test_chained_comparison() {
+    let chained = [
+        ("a < b < c", (1, 0)),
+        ("a == b == c", (1, 0)),
+        ("a < b > c", (1, 0)),
+        ("a <= b + 1 != c", (1, 0)),
+        ("a < b < c < d", (1, 0)),
+        ("f(x, y < z < w)", (1, 5)),
+        ("if ok {\n    a\n} else {\n    x != y == z\n}", (4, 4)),
+    ];
+    for &(input, _position) in &chained {
+        let expr: Expr = syn::parse_str(input).unwrap();
+        let err = match expr.check_chained_comparison() {
+            Ok(()) => panic!("expected chained comparison in {:?}", input),
+            Err(err) => err,
+        };
+        let message = err.to_string();
+        assert!(message.starts_with("comparison operators cannot be chained"));
+
+        #[cfg(feature = "layout")]
+        {
+            let start = err.span().start();
+            assert_eq!((start.line, start.column), _position, "{:?}", input);
+        }
+    }
+
+    let unchained = [
+        "a < b",
+        "(a < b) == c",
+        "a == (b < c)",
+        "a < b && b < c",
+        "a + b < c * d",
+        "a < b || c == d",
+        "f(a < b, c > d)",
+    ];
+    for input in &unchained {
+        let expr: Expr = syn::parse_str(input).unwrap();
+        assert!(expr.check_chained_comparison().is_ok(), "{:?}", input);
+    }
+}
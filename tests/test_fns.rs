@@ -0,0 +1,134 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{collect_fns, parse_quote, File};
+
+#[test]
+fn test_paths() {
+    let file: File = parse_quote! {
+        fn top() {}
+
+        mod a {
+            fn in_a() {}
+
+            mod b {
+                struct S<T>(T);
+
+                impl<T> S<T> {
+                    fn new(t: T) -> Self { S(t) }
+                }
+
+                impl<T: Clone> std::clone::Clone for S<T> {
+                    fn clone(&self) -> Self { S(self.0.clone()) }
+                }
+            }
+
+            fn after_b() {}
+        }
+
+        trait Shape {
+            fn area(&self) -> f64;
+            fn name(&self) -> &str { "shape" }
+        }
+
+        impl<'a, T> Shape for &'a [T] {
+            fn area(&self) -> f64 { 0.0 }
+        }
+
+        extern "C" {
+            fn abs(x: i32) -> i32;
+        }
+
+        mod external;
+    };
+
+    let paths: Vec<String> = collect_fns(&file)
+        .into_iter()
+        .map(|info| info.path)
+        .collect();
+
+    // Types other than paths are printed the way proc-macro2 prints them.
+    let slice_area = format!("<{} as Shape>::area", quote!(&'a [T]));
+
+    assert_eq!(
+        paths,
+        [
+            "top",
+            "a::in_a",
+            "a::b::S::new",
+            "a::b::<S as std::clone::Clone>::clone",
+            "a::after_b",
+            "Shape::area",
+            "Shape::name",
+            slice_area.as_str(),
+            "abs",
+        ],
+    );
+}
+
+#[test]
+fn test_nested_in_expressions() {
+    let file: File = parse_quote! {
+        fn outer() {
+            fn inner() {}
+            let f = || {
+                fn in_closure() {}
+            };
+        }
+
+        const C: () = {
+            fn in_const() {}
+        };
+
+        struct Array([u8; { fn len() -> usize { 1 } len() }]);
+    };
+
+    let paths: Vec<String> = collect_fns(&file)
+        .into_iter()
+        .map(|info| info.path)
+        .collect();
+
+    assert_eq!(paths, ["outer"]);
+}
+
+#[test]
+fn test_signature() {
+    let file: File = parse_quote! {
+        impl<T> Wrapper<T> {
+            fn map<U, F>(self, f: F) -> Wrapper<U>
+            where
+                F: FnOnce(T) -> U,
+            {
+                Wrapper(f(self.0))
+            }
+        }
+    };
+
+    let fns = collect_fns(&file);
+    assert_eq!(fns.len(), 1);
+
+    let info = &fns[0];
+    assert_eq!(info.path, "Wrapper::map");
+
+    let generics = info.generics;
+    let inputs = info.inputs;
+    let output = info.output;
+    assert_eq!(quote!(#generics).to_string(), quote!(<U, F>).to_string(),);
+    assert_eq!(quote!(#inputs).to_string(), quote!(self, f: F).to_string(),);
+    assert_eq!(
+        quote!(#output).to_string(),
+        quote!(-> Wrapper<U>).to_string(),
+    );
+    assert_eq!(
+        info.generics
+            .where_clause
+            .as_ref()
+            .unwrap()
+            .predicates
+            .len(),
+        1
+    );
+}
@@ -7,7 +7,11 @@ mod features;
 mod macros;
 
 use quote::quote;
-use syn::{DeriveInput, ItemFn, TypeParamBound, WhereClause, WherePredicate};
+use syn::{
+    parse_quote, DeriveInput, GenericArgument, Generics, ItemFn, ItemStruct, ItemTrait,
+    PathArguments, Signature, TraitItem, Type, TypeParam, TypeParamBound, WhereClause,
+    WherePredicate,
+};
 
 #[test]
 fn test_split_for_impl() {
@@ -286,3 +290,379 @@ fn test_where_clause_at_end_of_input() {
 
     assert_eq!(input.predicates.len(), 0);
 }
+
+#[test]
+fn test_where_clause_on_gat_methods() {
+    let input = quote! {
+        trait Foo
+        where
+            Self: Sized,
+        {
+            type Item<T>;
+            fn foo<T>(&self) -> Self::Item<T> where T: Clone;
+            fn bar<T, U>(&self, t: T) -> Self::Item<U> where T: Into<U>, U: Default {}
+        }
+    };
+
+    let input: ItemTrait = syn::parse2(input).unwrap();
+
+    let where_clause = input.generics.where_clause.as_ref().unwrap();
+    assert_eq!(quote!(#where_clause).to_string(), "where Self : Sized ,");
+
+    let methods: Vec<&Signature> = input
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(method) => Some(&method.sig),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(methods.len(), 2);
+
+    let where_clause = methods[0].generics.where_clause.as_ref().unwrap();
+    assert_eq!(quote!(#where_clause).to_string(), "where T : Clone");
+
+    let where_clause = methods[1].generics.where_clause.as_ref().unwrap();
+    assert_eq!(
+        quote!(#where_clause).to_string(),
+        "where T : Into < U > , U : Default",
+    );
+}
+
+#[test]
+fn test_introspection() {
+    let empty = Generics::default();
+    assert!(empty.is_empty());
+    assert!(!empty.has_type_params());
+    assert!(!empty.has_where_clause());
+
+    let mut lifetimes_only: Generics = parse_quote!(<'a, 'b: 'a>);
+    assert!(!lifetimes_only.is_empty());
+    assert!(!lifetimes_only.has_type_params());
+    assert!(!lifetimes_only.has_where_clause());
+
+    lifetimes_only.make_where_clause();
+    assert!(!lifetimes_only.has_where_clause());
+
+    let mut no_params = Generics::default();
+    no_params
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(String: Clone));
+    assert!(!no_params.is_empty());
+    assert!(no_params.has_where_clause());
+
+    let item: ItemStruct = parse_quote! {
+        struct S<'a, T, const N: usize> where T: 'a;
+    };
+    assert!(!item.generics.is_empty());
+    assert!(item.generics.has_type_params());
+    assert!(item.generics.has_where_clause());
+}
+
+#[test]
+fn test_lifetime_predicate_with_multiple_bounds() {
+    let input = quote! {
+        where 'a: 'b + 'static
+    };
+
+    snapshot!(input as WhereClause, @r###"
+   ⋮WhereClause {
+   ⋮    predicates: [
+   ⋮        Lifetime(PredicateLifetime {
+   ⋮            lifetime: Lifetime {
+   ⋮                ident: "a",
+   ⋮            },
+   ⋮            bounds: [
+   ⋮                Lifetime {
+   ⋮                    ident: "b",
+   ⋮                },
+   ⋮                Lifetime {
+   ⋮                    ident: "static",
+   ⋮                },
+   ⋮            ],
+   ⋮        }),
+   ⋮    ],
+   ⋮}
+    "###);
+
+    let reparsed: WhereClause = syn::parse2(quote!(#input)).unwrap();
+    assert_eq!(reparsed, input);
+}
+
+#[test]
+fn test_const_trait_bound_modifiers() {
+    let tokens = quote!(~const Clone);
+    snapshot!(tokens as TypeParamBound, @r###"
+   ⋮Trait(TraitBound {
+   ⋮    modifier: TildeConst,
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "Clone",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮})
+    "###);
+
+    let tokens = quote!(?const PartialEq);
+    snapshot!(tokens as TypeParamBound, @r###"
+   ⋮Trait(TraitBound {
+   ⋮    modifier: MaybeConst,
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "PartialEq",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮})
+    "###);
+
+    let tokens = quote!((~const Default));
+    snapshot!(tokens as TypeParamBound, @r###"
+   ⋮Trait(TraitBound {
+   ⋮    paren_token: Some,
+   ⋮    modifier: TildeConst,
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "Default",
+   ⋮                arguments: None,
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮})
+    "###);
+
+    let tokens = quote!(T: Clone + ~const Default);
+    snapshot!(tokens as TypeParam, @r###"
+   ⋮TypeParam {
+   ⋮    ident: "T",
+   ⋮    colon_token: Some,
+   ⋮    bounds: [
+   ⋮        Trait(TraitBound {
+   ⋮            modifier: None,
+   ⋮            path: Path {
+   ⋮                segments: [
+   ⋮                    PathSegment {
+   ⋮                        ident: "Clone",
+   ⋮                        arguments: None,
+   ⋮                    },
+   ⋮                ],
+   ⋮            },
+   ⋮        }),
+   ⋮        Trait(TraitBound {
+   ⋮            modifier: TildeConst,
+   ⋮            path: Path {
+   ⋮                segments: [
+   ⋮                    PathSegment {
+   ⋮                        ident: "Default",
+   ⋮                        arguments: None,
+   ⋮                    },
+   ⋮                ],
+   ⋮            },
+   ⋮        }),
+   ⋮    ],
+   ⋮}
+    "###);
+}
+
+#[test]
+fn test_add_trait_bound() {
+    let mut generics: Generics = parse_quote! {
+        <'a, T, U: Clone, V: Debug + ?Sized, W: fmt::Debug, X, const N: usize>
+    };
+    generics.where_clause = Some(parse_quote!(where X: Debug, Vec<T>: Debug));
+    generics.add_trait_bound(&parse_quote!(Debug), false);
+    let where_clause = &generics.where_clause;
+    let expected = quote! {
+        <'a, T: Debug, U: Clone + Debug, V: Debug + ?Sized, W: fmt::Debug + Debug, X, const N: usize>
+        where X: Debug, Vec<T>: Debug
+    };
+    assert_eq!(
+        quote!(#generics #where_clause).to_string(),
+        expected.to_string(),
+    );
+
+    // Adding the same bound again changes nothing.
+    let before = generics.clone();
+    generics.add_trait_bound(&parse_quote!(Debug), false);
+    assert_eq!(generics, before);
+    generics.add_trait_bound(&parse_quote!(Debug), true);
+    assert_eq!(generics, before);
+}
+
+#[test]
+fn test_add_trait_bound_in_where_clause() {
+    let mut generics: Generics = parse_quote!(<'a, T: Clone, U>);
+    generics.add_trait_bound(&parse_quote!(Send), true);
+    generics.add_trait_bound(&parse_quote!(Sync), true);
+    let where_clause = &generics.where_clause;
+    let expected = quote! {
+        <'a, T: Clone, U> where T: Send, U: Send, T: Sync, U: Sync
+    };
+    assert_eq!(
+        quote!(#generics #where_clause).to_string(),
+        expected.to_string(),
+    );
+
+    // No where clause is created if there are no type parameters.
+    let mut generics: Generics = parse_quote!(<'a, const N: usize>);
+    generics.add_trait_bound(&parse_quote!(Send), true);
+    assert!(generics.where_clause.is_none());
+}
+
+#[test]
+fn test_add_lifetime() {
+    let mut generics: Generics = parse_quote!(<T: Clone, const N: usize>);
+    generics.add_lifetime(&parse_quote!('a), false);
+    generics.add_lifetime(&parse_quote!('b), false);
+    assert_eq!(
+        quote!(#generics).to_string(),
+        quote!(<'a, 'b, T: Clone, const N: usize>).to_string(),
+    );
+
+    // An empty list of parameters gains its angle brackets when printed.
+    let mut generics = Generics::default();
+    generics.add_lifetime(&parse_quote!('a), false);
+    assert_eq!(quote!(#generics).to_string(), quote!(<'a>).to_string());
+
+    // A lifetime that is already declared is not added again, though the
+    // other lifetimes are still bounded by it.
+    let mut generics: Generics = parse_quote!(<'a, 'b, T>);
+    generics.add_lifetime(&parse_quote!('b), true);
+    assert_eq!(
+        quote!(#generics).to_string(),
+        quote!(<'a: 'b, 'b, T>).to_string(),
+    );
+
+    // Bounding twice does not duplicate the bound.
+    let mut generics: Generics = parse_quote!(<'a: 'x, 'b, T: 'a>);
+    generics.add_lifetime(&parse_quote!('x), true);
+    generics.add_lifetime(&parse_quote!('x), true);
+    assert_eq!(
+        quote!(#generics).to_string(),
+        quote!(<'a: 'x, 'b: 'x, 'x, T: 'a>).to_string(),
+    );
+}
+
+#[test]
+fn test_where_clause_bounded_ty() {
+    let where_clause: WhereClause = parse_quote! {
+        where
+            Box<dyn Fn() -> T>: Clone,
+            dyn Fn(u8) -> T + Send: Sync,
+            for<'a> &'a dyn Trait: Send,
+            (impl Fn(), [T; 2]): Copy,
+            fn(T) -> U: Clone,
+            <T as Iterator>::Item: Debug,
+    };
+
+    let bounded: Vec<_> = where_clause
+        .predicates
+        .iter()
+        .map(|predicate| match predicate {
+            WherePredicate::Type(predicate) => match &predicate.bounded_ty {
+                Type::Path(_) => "path",
+                Type::TraitObject(_) => "trait object",
+                Type::Reference(_) => "reference",
+                Type::Tuple(_) => "tuple",
+                Type::BareFn(_) => "fn",
+                other => panic!("unexpected type {:?}", other),
+            },
+            other => panic!("unexpected predicate {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        bounded,
+        ["path", "trait object", "reference", "tuple", "fn", "path"],
+    );
+
+    let tokens = quote!(#where_clause);
+    assert_eq!(syn::parse2::<WhereClause>(tokens).unwrap(), where_clause);
+}
+
+#[test]
+fn test_where_clause_associated_type_bounds() {
+    let where_clause: WhereClause = parse_quote! {
+        where
+            T: Iterator<Item: Clone + Send>,
+            U: IntoIterator<Item: Iterator<Item: Debug>, IntoIter: 'static>,
+    };
+
+    fn constraints(predicate: &WherePredicate) -> Vec<(String, String)> {
+        let bound = match predicate {
+            WherePredicate::Type(predicate) => match &predicate.bounds[0] {
+                TypeParamBound::Trait(bound) => bound,
+                other => panic!("unexpected bound {:?}", other),
+            },
+            other => panic!("unexpected predicate {:?}", other),
+        };
+        match &bound.path.segments[0].arguments {
+            PathArguments::AngleBracketed(arguments) => arguments
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    GenericArgument::Constraint(constraint) => {
+                        let bounds = &constraint.bounds;
+                        (constraint.ident.to_string(), quote!(#bounds).to_string())
+                    }
+                    other => panic!("unexpected argument {:?}", other),
+                })
+                .collect(),
+            other => panic!("unexpected arguments {:?}", other),
+        }
+    }
+
+    let predicates = &where_clause.predicates;
+    assert_eq!(
+        constraints(&predicates[0]),
+        [("Item".to_owned(), quote!(Clone + Send).to_string())],
+    );
+    assert_eq!(
+        constraints(&predicates[1]),
+        [
+            ("Item".to_owned(), quote!(Iterator<Item: Debug>).to_string()),
+            ("IntoIter".to_owned(), quote!('static).to_string()),
+        ],
+    );
+
+    let tokens = quote!(#where_clause);
+    assert_eq!(syn::parse2::<WhereClause>(tokens).unwrap(), where_clause);
+}
+
+#[test]
+fn test_bounds_with_defaults() {
+    let input: ItemStruct = parse_quote! {
+        struct Map<K: Hash, V: Default = (), S: Clone + Send = u8>;
+    };
+
+    let params: Vec<_> = input.generics.type_params().collect();
+    assert_eq!(params.len(), 3);
+    for (param, bounds, default) in vec![
+        (params[0], quote!(Hash), None),
+        (params[1], quote!(Default), Some(quote!(()))),
+        (params[2], quote!(Clone + Send), Some(quote!(u8))),
+    ] {
+        let actual = &param.bounds;
+        assert_eq!(quote!(#actual).to_string(), bounds.to_string());
+        let actual = param.default.as_ref().map(|ty| quote!(#ty).to_string());
+        assert_eq!(actual, default.map(|ty| ty.to_string()));
+    }
+
+    let tokens = quote!(#input);
+    assert_eq!(syn::parse2::<ItemStruct>(tokens).unwrap(), input);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generated = quote! {
+        impl #impl_generics MyTrait for Map #ty_generics #where_clause {}
+    };
+    let expected = quote! {
+        impl<K: Hash, V: Default, S: Clone + Send> MyTrait for Map<K, V, S> {}
+    };
+    assert_eq!(generated.to_string(), expected.to_string());
+}
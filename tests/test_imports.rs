@@ -0,0 +1,66 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, unused_imports, File};
+
+fn unused(file: File) -> Vec<String> {
+    unused_imports(&file)
+        .into_iter()
+        .map(|tree| quote!(#tree).to_string())
+        .collect()
+}
+
+#[test]
+fn test_unused_imports() {
+    let file: File = parse_quote! {
+        use a::b::{c, d as e, f::{self, g}};
+        use h::i;
+
+        fn main() {
+            c();
+            println!("{}", i);
+        }
+    };
+
+    let expected = vec![
+        quote!(a::b::d as e).to_string(),
+        quote!(a::b::f).to_string(),
+        quote!(a::b::f::g).to_string(),
+    ];
+    assert_eq!(unused(file), expected);
+}
+
+#[test]
+fn test_never_flagged() {
+    let file: File = parse_quote! {
+        use a::*;
+        use b::Trait as _;
+        use c::{self as d};
+    };
+
+    let expected = vec![quote!(c::self as d).to_string()];
+    assert_eq!(unused(file), expected);
+}
+
+#[test]
+fn test_used_in_attribute_and_nested_module() {
+    let file: File = parse_quote! {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct S;
+
+        mod m {
+            use super::S;
+            use super::T;
+
+            fn f(_: S) {}
+        }
+    };
+
+    let expected = vec![quote!(super::T).to_string()];
+    assert_eq!(unused(file), expected);
+}
@@ -0,0 +1,124 @@
+extern crate syn;
+
+mod features;
+mod macros;
+
+use crate::macros::assert_rewrite;
+use syn::inline_consts;
+
+#[test]
+fn test_inline() {
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 1; fn f() -> u8 { N + N }",
+        "const N: u8 = 1; fn f() -> u8 { 1u8 + 1u8 }",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const S: &str = \"s\"; static T: [u8; N] = [0; N]; const N: usize = 4;",
+        "const S: &str = \"s\"; static T: [u8; 4usize] = [0; 4usize]; const N: usize = 4;",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 1; impl T for S { fn f(&self) { g(N, |x| x + N) } }",
+        "const N: u8 = 1; impl T for S { fn f(&self) { g(1u8, |x| x + 1u8) } }",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 1; fn f() -> S { S { N, M: N } }",
+        "const N: u8 = 1; fn f() -> S { S { N: 1u8, M: 1u8 } }",
+    );
+
+    // Expressions inside of patterns and types.
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 9; fn f(x: u8) -> bool { match x { 0..=N => true, _ => false } }",
+        "const N: u8 = 9; fn f(x: u8) -> bool { match x { 0..=9u8 => true, _ => false } }",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const N: usize = 4; fn f(x: [u8; N]) -> Buf<{ N }> { Buf::new(x) }",
+        "const N: usize = 4; fn f(x: [u8; 4usize]) -> Buf<{ 4usize }> { Buf::new(x) }",
+    );
+
+    // Only the initializers that were literals to begin with are inlined.
+    assert_rewrite(
+        inline_consts,
+        "const M: u8 = 1; const N: u8 = M; fn f() -> u8 { M + N }",
+        "const M: u8 = 1; const N: u8 = 1u8; fn f() -> u8 { 1u8 + N }",
+    );
+}
+
+#[test]
+fn test_typed() {
+    // Without a suffix the method would be called on an integer of ambiguous
+    // type.
+    assert_rewrite(
+        inline_consts,
+        "const N: u32 = 5; fn f() -> u32 { let x = N; x.pow(2) + N.pow(2) }",
+        "const N: u32 = 5; fn f() -> u32 { let x = 5u32; x.pow(2) + 5u32.pow(2) }",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const X: f64 = 1.; const Y: f32 = 2.5e3; fn f() { (X, Y) }",
+        "const X: f64 = 1.; const Y: f32 = 2.5e3; fn f() { (1.0f64, 2.5e3f32) }",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 0xff_u8; fn f() -> u8 { N }",
+        "const N: u8 = 0xff_u8; fn f() -> u8 { 0xff_u8 }",
+    );
+}
+
+#[test]
+fn test_not_inlined() {
+    let unchanged = [
+        // Initializers other than a single literal.
+        "const N: u8 = 1 + 1; fn f() -> u8 { N }",
+        "const N: i8 = -1; fn f() -> i8 { N }",
+        // Numbers whose type is not a primitive that can be a suffix.
+        "type T = u8; const N: T = 1; fn f() -> T { N }",
+        // Paths that are not the bare name.
+        "const N: u8 = 1; fn f() -> u8 { self::N + crate::N + <S>::N + S::N }",
+        // Patterns, types, and macros.
+        "const N: u8 = 1; fn f(x: u8) { match x { N => {} _ => {} } }",
+        "const N: u8 = 1; fn f() -> S<N> { g!(N) }",
+        // Declared more than once.
+        "#[cfg(a)] const N: u8 = 1; #[cfg(not(a))] const N: u8 = 2; fn f() -> u8 { N }",
+        "const N: u8 = 1; #[cfg(a)] fn N() {} fn f() -> u8 { N }",
+    ];
+    for input in &unchanged {
+        assert_rewrite(inline_consts, input, input);
+    }
+}
+
+#[test]
+fn test_shadowing() {
+    let unchanged = [
+        "const N: u8 = 1; fn f() -> u8 { const N: u8 = 2; N }",
+        "const N: u8 = 1; fn f() -> u8 { let x = N; static N: u8 = 2; x }",
+        "const N: u8 = 1; fn f() -> u8 { fn N() {} N }",
+        "const N: u8 = 1; fn f() -> u8 { use a::N; N }",
+        "const N: u8 = 1; fn f() -> u8 { use a::{b, c::M as N}; N }",
+        "const N: u8 = 1; fn f() -> u8 { use a::*; N }",
+        "const N: u8 = 1; fn f<const N: u8>() -> u8 { N }",
+        "const N: u8 = 1; struct S<const N: usize>([u8; N]);",
+        "const N: u8 = 1; impl<const N: u8> S<N> { fn f() -> u8 { N } }",
+        "const N: u8 = 1; mod m { fn f() -> u8 { N } }",
+    ];
+    for input in &unchanged {
+        assert_rewrite(inline_consts, input, input);
+    }
+
+    // Shadowing lasts only for the block that declares the item.
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 1; fn f() -> u8 { let x = { const N: u8 = 2; N }; x + N }",
+        "const N: u8 = 1; fn f() -> u8 { let x = { const N: u8 = 2; N }; x + 1u8 }",
+    );
+    assert_rewrite(
+        inline_consts,
+        "const N: u8 = 1; fn f<const M: u8>() -> u8 { M + N }",
+        "const N: u8 = 1; fn f<const M: u8>() -> u8 { M + 1u8 }",
+    );
+}
@@ -0,0 +1,87 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{instrument_fns, parse_quote, File, Stmt};
+
+fn trace(name: &Ident) -> Stmt {
+    let name = Ident::new(&format!("enter_{}", name), Span::call_site());
+    parse_quote!(#name();)
+}
+
+#[test]
+fn test_instrument() {
+    let file: File = parse_quote! {
+        fn outer() -> u8 {
+            fn inner() {}
+            let f = |x| x;
+            1
+        }
+
+        impl S {
+            fn method(&self) {
+                self.run();
+            }
+        }
+
+        trait T {
+            fn required(&self);
+            fn provided(&self) {}
+        }
+
+        extern "C" {
+            fn external();
+        }
+
+        mod m {
+            fn nested() {}
+        }
+    };
+
+    let mut names = Vec::new();
+    let file = instrument_fns(file, |name| {
+        names.push(name.to_string());
+        trace(name)
+    });
+    assert_eq!(names, ["outer", "inner", "method", "provided", "nested"]);
+
+    let expected = quote! {
+        fn outer() -> u8 {
+            enter_outer();
+            fn inner() {
+                enter_inner();
+            }
+            let f = |x| x;
+            1
+        }
+
+        impl S {
+            fn method(&self) {
+                enter_method();
+                self.run();
+            }
+        }
+
+        trait T {
+            fn required(&self);
+            fn provided(&self) {
+                enter_provided();
+            }
+        }
+
+        extern "C" {
+            fn external();
+        }
+
+        mod m {
+            fn nested() {
+                enter_nested();
+            }
+        }
+    };
+    assert_eq!(quote!(#file).to_string(), expected.to_string());
+}
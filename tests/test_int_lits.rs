@@ -0,0 +1,94 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{canonicalize_int_lits, canonicalize_int_lits_in, parse_quote, Expr, File, Lit, Radix};
+
+fn canonical(radix: Radix, input: &str) -> String {
+    let lit: Lit = syn::parse_str(input).unwrap();
+    let lit = canonicalize_int_lits_in(lit, radix);
+    quote!(#lit).to_string()
+}
+
+#[test]
+fn test_decimal() {
+    for &input in &[
+        "1000",
+        "1_000",
+        "0x3E8",
+        "0x3e8",
+        "0o1750",
+        "0b1111101000",
+        "001000",
+    ] {
+        assert_eq!(canonical(Radix::Decimal, input), "1000", "{}", input);
+    }
+    assert_eq!(canonical(Radix::Decimal, "0"), "0");
+    assert_eq!(canonical(Radix::Decimal, "0x0_u8"), "0u8");
+    assert_eq!(canonical(Radix::Decimal, "0xFF_i64"), "255i64");
+}
+
+#[test]
+fn test_other_radixes() {
+    assert_eq!(canonical(Radix::Binary, "1_000"), "0b1111101000");
+    assert_eq!(canonical(Radix::Octal, "1_000"), "0o1750");
+    assert_eq!(canonical(Radix::Hexadecimal, "1_000usize"), "0x3e8usize");
+    assert_eq!(canonical(Radix::Hexadecimal, "0"), "0x0");
+    assert_eq!(canonical(Radix::Binary, "0b0000_0101"), "0b101");
+}
+
+#[test]
+fn test_large_values() {
+    let max = "340282366920938463463374607431768211455u128";
+    let hex = "0xffffffffffffffffffffffffffffffffu128";
+    assert_eq!(canonical(Radix::Hexadecimal, max), hex);
+    assert_eq!(canonical(Radix::Decimal, hex), max);
+
+    // Larger than u128::MAX, which is not a valid literal but can be parsed.
+    let big = "0x1_0000_0000_0000_0000_0000_0000_0000_0000";
+    assert_eq!(
+        canonical(Radix::Decimal, big),
+        "340282366920938463463374607431768211456",
+    );
+    assert_eq!(
+        canonical(Radix::Hexadecimal, big),
+        "0x100000000000000000000000000000000",
+    );
+}
+
+#[test]
+fn test_file() {
+    let file: File = parse_quote! {
+        const N: usize = 0x10;
+        fn f(x: [u8; 1_6]) -> u32 {
+            match x[0b1] {
+                0x0..=0xF => 1_000u32,
+                _ => t.0 + 2.5e3 as u32,
+            }
+        }
+    };
+    let expected = quote! {
+        const N: usize = 16;
+        fn f(x: [u8; 16]) -> u32 {
+            match x[1] {
+                0..=15 => 1000u32,
+                _ => t.0 + 2.5e3 as u32,
+            }
+        }
+    };
+    let file = canonicalize_int_lits(file);
+    assert_eq!(quote!(#file).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_default_radix() {
+    let expr: Expr = parse_quote!(0x3E8);
+    let expected: Expr = parse_quote!(1000);
+    assert_eq!(
+        canonicalize_int_lits_in(expr.clone(), Radix::default()),
+        expected,
+    );
+    assert_eq!(canonicalize_int_lits(expr), expected);
+}
@@ -0,0 +1,51 @@
+extern crate syn;
+
+mod features;
+
+use syn::{parse_quote, Type, TypeInterner};
+
+#[test]
+fn test_intern() {
+    let mut interner = TypeInterner::new();
+    assert!(interner.is_empty());
+
+    let types: Vec<Type> = vec![
+        parse_quote!(u8),
+        parse_quote!(Vec<u8>),
+        parse_quote!(u8),
+        parse_quote!(std::vec::Vec<u8>),
+        parse_quote!(Vec<u8>),
+        parse_quote!((u8)),
+        parse_quote!(&'a str),
+        parse_quote!(&'b str),
+        parse_quote!(&'a str),
+    ];
+    let ids: Vec<u32> = types.iter().map(|ty| interner.intern(ty).index()).collect();
+    assert_eq!(ids, [0, 1, 0, 2, 1, 3, 4, 5, 4]);
+    assert_eq!(interner.len(), 6);
+
+    for ty in &types {
+        let id = interner.get(ty).unwrap();
+        assert_eq!(interner.resolve(id), ty);
+        assert_eq!(interner.intern(ty), id);
+    }
+    assert_eq!(interner.len(), 6);
+    assert_eq!(interner.get(&parse_quote!(u16)), None);
+}
+
+#[test]
+fn test_intern_ignores_spans() {
+    let compact: Type = syn::parse_str("Option<T>").unwrap();
+    let spread: Type = syn::parse_str("Option <\n    T\n>").unwrap();
+
+    let mut interner = TypeInterner::new();
+    assert_eq!(interner.intern(&compact), interner.intern(&spread));
+}
+
+#[test]
+#[should_panic]
+fn test_resolve_foreign_id() {
+    let mut interner = TypeInterner::new();
+    let id = interner.intern(&parse_quote!(u8));
+    TypeInterner::new().resolve(id);
+}
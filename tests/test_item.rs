@@ -0,0 +1,317 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{
+    AttrStyle, Attribute, BinOp, Expr, ExprBinary, Fields, File, ImplItem, Item, ItemEnum,
+    ItemImpl, ItemStruct, ItemType, Lit, Meta, Path, TraitItem, Type,
+};
+
+#[test]
+fn test_struct_forms() {
+    let named: ItemStruct = syn::parse_str("struct S<T> where T: Clone { a: T }").unwrap();
+    let tuple: ItemStruct = syn::parse_str("struct Pair<T>(T, T) where T: Clone;").unwrap();
+    let unit: ItemStruct = syn::parse_str("struct Unit;").unwrap();
+
+    match &named.fields {
+        Fields::Named(fields) => assert_eq!(fields.named.len(), 1),
+        _ => panic!("expected named fields"),
+    }
+    assert!(named.semi_token.is_none());
+    assert!(named.generics.where_clause.is_some());
+
+    match &tuple.fields {
+        Fields::Unnamed(fields) => assert_eq!(fields.unnamed.len(), 2),
+        _ => panic!("expected unnamed fields"),
+    }
+    assert!(tuple.semi_token.is_some());
+    assert!(tuple.generics.where_clause.is_some());
+
+    match &unit.fields {
+        Fields::Unit => {}
+        _ => panic!("expected unit struct"),
+    }
+    assert!(unit.semi_token.is_some());
+    assert!(unit.generics.where_clause.is_none());
+
+    for item in &[named, tuple, unit] {
+        assert_eq!(syn::parse2::<ItemStruct>(quote!(#item)).unwrap(), *item);
+    }
+}
+
+#[test]
+fn test_tuple_struct_where_clause_position() {
+    let item: ItemStruct = syn::parse_str("struct Pair<T>(T, T) where T: Clone;").unwrap();
+
+    // The where clause is printed after the tuple body, not before it.
+    let expected = quote!(
+        struct Pair<T>(T, T)
+        where
+            T: Clone;
+    );
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_doc_comment_attachment() {
+    let source = r#"
+        //! Crate docs.
+
+        /// Module docs outside.
+        mod m {
+            //! Module docs inside.
+
+            /// Function docs.
+            fn f() {}
+        }
+
+        /// Struct docs.
+        struct S;
+    "#;
+
+    fn docs(attrs: &[Attribute]) -> Vec<(AttrStyle, String)> {
+        attrs
+            .iter()
+            .map(|attr| match attr.parse_meta().unwrap() {
+                Meta::NameValue(meta) => match meta.lit {
+                    Lit::Str(lit) => (attr.style, lit.value()),
+                    _ => panic!("expected string literal"),
+                },
+                _ => panic!("expected doc attribute"),
+            })
+            .collect()
+    }
+
+    let file: File = syn::parse_str(source).unwrap();
+    let reparsed: File = syn::parse2(quote!(#file)).unwrap();
+    assert_eq!(reparsed, file);
+
+    for file in &[file, reparsed] {
+        assert_eq!(
+            docs(&file.attrs),
+            [(
+                AttrStyle::Inner(Default::default()),
+                " Crate docs.".to_owned()
+            )]
+        );
+        assert_eq!(file.items.len(), 2);
+
+        let module = match &file.items[0] {
+            Item::Mod(item) => item,
+            _ => panic!("expected module"),
+        };
+        assert_eq!(
+            docs(&module.attrs),
+            [
+                (AttrStyle::Outer, " Module docs outside.".to_owned()),
+                (
+                    AttrStyle::Inner(Default::default()),
+                    " Module docs inside.".to_owned()
+                ),
+            ],
+        );
+
+        let content = &module.content.as_ref().unwrap().1;
+        match &content[..] {
+            [Item::Fn(item)] => {
+                assert_eq!(
+                    docs(&item.attrs),
+                    [(AttrStyle::Outer, " Function docs.".to_owned())]
+                );
+            }
+            _ => panic!("expected one function"),
+        }
+
+        match &file.items[1] {
+            Item::Struct(item) => {
+                assert_eq!(
+                    docs(&item.attrs),
+                    [(AttrStyle::Outer, " Struct docs.".to_owned())]
+                );
+            }
+            _ => panic!("expected struct"),
+        }
+    }
+}
+
+#[test]
+fn test_cfg_attribute_attachment() {
+    let source = r#"
+        #[cfg(feature = "x")]
+        use foo::Bar;
+
+        #[cfg(feature = "x")]
+        extern crate foo;
+
+        #[cfg(feature = "x")]
+        mod m;
+
+        #[cfg(feature = "x")]
+        const N: usize = 1;
+
+        #[cfg(feature = "x")]
+        m!();
+    "#;
+
+    let file: File = syn::parse_str(source).unwrap();
+    let reparsed: File = syn::parse2(quote!(#file)).unwrap();
+    assert_eq!(reparsed, file);
+
+    for file in &[file, reparsed] {
+        let attrs: Vec<&[Attribute]> = file
+            .items
+            .iter()
+            .map(|item| match item {
+                Item::Use(item) => &item.attrs[..],
+                Item::ExternCrate(item) => &item.attrs[..],
+                Item::Mod(item) => &item.attrs[..],
+                Item::Const(item) => &item.attrs[..],
+                Item::Macro(item) => &item.attrs[..],
+                _ => panic!("unexpected item"),
+            })
+            .collect();
+        assert_eq!(attrs.len(), 5);
+
+        for attrs in attrs {
+            match &attrs[..] {
+                [attr] => {
+                    assert_eq!(attr.style, AttrStyle::Outer);
+                    assert!(attr.path.is_ident("cfg"));
+                    assert_eq!(attr.tokens.to_string(), quote!((feature = "x")).to_string());
+                }
+                _ => panic!("expected one attribute"),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_enum_discriminants() {
+    let item: ItemEnum =
+        syn::parse_str("#[repr(u8)] enum Flags { A = 1, B = 1 << 1, C = 1 << 2, D, E = 0xff }")
+            .unwrap();
+
+    let discriminants: Vec<_> = item
+        .variants
+        .iter()
+        .map(|variant| variant.discriminant().map(|expr| quote!(#expr).to_string()))
+        .collect();
+    assert_eq!(
+        discriminants,
+        [
+            Some("1".to_owned()),
+            Some("1 << 1".to_owned()),
+            Some("1 << 2".to_owned()),
+            None,
+            Some("0xff".to_owned()),
+        ],
+    );
+
+    match item.variants[2].discriminant() {
+        Some(Expr::Binary(ExprBinary {
+            op: BinOp::Shl(_), ..
+        })) => {}
+        other => panic!("expected shift, found {:?}", other),
+    }
+
+    let tokens = quote!(#item);
+    assert_eq!(syn::parse2::<ItemEnum>(tokens).unwrap(), item);
+}
+
+#[test]
+fn test_impl_item_type_generics() {
+    let item: ImplItem = syn::parse_str("type Item<'a> = &'a str;").unwrap();
+    let item = match item {
+        ImplItem::Type(item) => item,
+        other => panic!("expected associated type, found {:?}", other),
+    };
+    assert_eq!(item.generics.params.len(), 1);
+    assert!(item.generics.where_clause.is_none());
+    let tokens = quote!(#item);
+    assert_eq!(
+        tokens.to_string(),
+        quote!(
+            type Item<'a> = &'a str;
+        )
+        .to_string()
+    );
+}
+
+#[test]
+fn test_async_methods() {
+    let item: TraitItem =
+        syn::parse_str("async fn call<T>(&self, value: T) -> T where T: Send;").unwrap();
+    let method = match item {
+        TraitItem::Method(method) => method,
+        other => panic!("expected method, found {:?}", other),
+    };
+    assert!(method.sig.asyncness.is_some());
+    assert!(method.default.is_none());
+    let where_clause = method.sig.generics.where_clause.as_ref().unwrap();
+    assert_eq!(where_clause.predicates.len(), 1);
+    let tokens = quote!(#method);
+    assert_eq!(
+        syn::parse2::<TraitItem>(tokens).unwrap(),
+        TraitItem::Method(method)
+    );
+
+    let item: ImplItem =
+        syn::parse_str("pub async fn call<T>(&self, value: T) -> T where T: Send { value }")
+            .unwrap();
+    let method = match item {
+        ImplItem::Method(method) => method,
+        other => panic!("expected method, found {:?}", other),
+    };
+    assert!(method.sig.asyncness.is_some());
+    assert!(method.sig.generics.where_clause.is_some());
+    assert_eq!(method.block.stmts.len(), 1);
+    let tokens = quote!(#method);
+    assert_eq!(
+        syn::parse2::<ImplItem>(tokens).unwrap(),
+        ImplItem::Method(method)
+    );
+}
+
+#[test]
+fn test_impl_trait_path() {
+    let item: ItemImpl = syn::parse_str("impl<T: Clone> Clone for Wrapper<T> {}").unwrap();
+    let expected: Path = syn::parse_str("Clone").unwrap();
+    assert!(item.is_trait_impl());
+    assert!(!item.is_negative_impl());
+    assert_eq!(item.trait_path(), Some(&expected));
+
+    let item: ItemImpl = syn::parse_str("unsafe impl !Send for Wrapper {}").unwrap();
+    let expected: Path = syn::parse_str("Send").unwrap();
+    assert!(item.is_trait_impl());
+    assert!(item.is_negative_impl());
+    assert_eq!(item.trait_path(), Some(&expected));
+
+    let item: ItemImpl =
+        syn::parse_str("impl<'a> ::std::iter::Iterator<Item = &'a u8> for Iter<'a> {}").unwrap();
+    let expected: Path = syn::parse_str("::std::iter::Iterator<Item = &'a u8>").unwrap();
+    assert_eq!(item.trait_path(), Some(&expected));
+
+    for input in &[
+        "impl Wrapper {}",
+        "impl<T> Wrapper<T> where T: Clone {}",
+        "impl dyn Trait {}",
+    ] {
+        let item: ItemImpl = syn::parse_str(input).unwrap();
+        assert!(!item.is_trait_impl());
+        assert!(!item.is_negative_impl());
+        assert_eq!(item.trait_path(), None);
+    }
+}
+
+#[test]
+fn test_type_alias_impl_trait() {
+    let item: ItemType = syn::parse_str("type Ret = impl Iterator<Item = u8> + Send;").unwrap();
+    match &*item.ty {
+        Type::ImplTrait(ty) => assert_eq!(ty.bounds.len(), 2),
+        other => panic!("expected impl trait, found {:?}", other),
+    }
+    let tokens = quote!(#item);
+    assert_eq!(syn::parse2::<ItemType>(tokens).unwrap(), item);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "layout")]
+
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, to_string_with_layout, Expr, File, Item, Stmt};
+
+#[test]
+fn test_preserves_layout() {
+    let source = "\
+use std::fmt;
+
+struct S {
+    a: u8,  // comments are dropped
+    b: Vec<(u8, u16)>,
+}
+
+impl fmt::Debug for S {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct(\"S\")
+            .field(\"a\", &self.a)
+            .finish()
+    }
+}";
+
+    let expected = source.replace("  // comments are dropped", "");
+    let file: File = syn::parse_str(source).unwrap();
+    assert_eq!(to_string_with_layout(&file), expected);
+}
+
+#[test]
+fn test_synthesized_tokens() {
+    let expr: Expr = parse_quote!(a + b::c);
+    assert_eq!(to_string_with_layout(&expr), "a + b :: c");
+}
+
+#[test]
+fn test_mixed_tokens() {
+    let source = "fn f() {\n    let x  =  1;\n}";
+    let mut file: File = syn::parse_str(source).unwrap();
+
+    let stmt: Stmt = parse_quote!(g(x););
+    match &mut file.items[0] {
+        Item::Fn(item) => item.block.stmts.push(stmt),
+        _ => unreachable!(),
+    }
+
+    let expected = "fn f() {\n    let x  =  1; g (x) ;\n}";
+    assert_eq!(to_string_with_layout(&file), expected);
+
+    let reparsed: File = syn::parse_str(&to_string_with_layout(&file)).unwrap();
+    assert_eq!(quote!(#reparsed).to_string(), quote!(#file).to_string());
+}
@@ -0,0 +1,53 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, Expr, Lifetime, Type, TypeReference};
+
+#[test]
+fn test_special_lifetimes() {
+    let elided: Lifetime = parse_quote!('_);
+    assert!(elided.is_elided());
+    assert!(!elided.is_static());
+
+    let static_lifetime: Lifetime = parse_quote!('static);
+    assert!(static_lifetime.is_static());
+    assert!(!static_lifetime.is_elided());
+
+    let named: Lifetime = parse_quote!('a);
+    assert!(!named.is_static());
+    assert!(!named.is_elided());
+}
+
+#[test]
+fn test_reference_round_trip() {
+    let tokens = quote!(&'_ T);
+    let ty: TypeReference = syn::parse2(tokens.clone()).unwrap();
+    assert!(ty.lifetime.as_ref().unwrap().is_elided());
+    assert_eq!(quote!(#ty).to_string(), tokens.to_string());
+
+    let tokens = quote!(&'static str);
+    let ty: TypeReference = syn::parse2(tokens.clone()).unwrap();
+    assert!(ty.lifetime.as_ref().unwrap().is_static());
+    assert_eq!(quote!(#ty).to_string(), tokens.to_string());
+
+    let tokens = quote!(Cow<'_, str>);
+    let ty: Type = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(quote!(#ty).to_string(), tokens.to_string());
+}
+
+#[test]
+fn test_label_round_trip() {
+    let tokens = quote!('outer: loop {
+        break 'outer;
+    });
+    let expr: Expr = syn::parse2(tokens.clone()).unwrap();
+    let label = match &expr {
+        Expr::Loop(expr) => &expr.label.as_ref().unwrap().name,
+        _ => panic!("expected loop"),
+    };
+    assert!(!label.is_static() && !label.is_elided());
+    assert_eq!(quote!(#expr).to_string(), tokens.to_string());
+}
@@ -163,6 +163,50 @@ fn ints() {
     test_int("0o__7__________________3u32", 59, "u32");
 }
 
+#[test]
+fn int_suffix_ranges() {
+    fn fits(s: &str) -> bool {
+        match lit(s) {
+            Lit::Int(lit) => {
+                assert_eq!(lit.check().is_ok(), lit.fits_suffix());
+                lit.fits_suffix()
+            }
+            wrong => panic!("{:?}", wrong),
+        }
+    }
+
+    assert!(fits("255u8"));
+    assert!(!fits("256u8"));
+    assert!(fits("0xFFu8"));
+    assert!(!fits("0x1_00u8"));
+    assert!(fits("127i8"));
+    assert!(!fits("128i8"));
+    assert!(fits("65535u16"));
+    assert!(!fits("32768i16"));
+    assert!(fits("4294967295u32"));
+    assert!(!fits("2147483648i32"));
+    assert!(fits("18446744073709551615u64"));
+    assert!(fits("18446744073709551615usize"));
+    assert!(!fits("9223372036854775808isize"));
+    assert!(fits("170141183460469231731687303715884105727i128"));
+    assert!(!fits("170141183460469231731687303715884105728i128"));
+    assert!(fits("340282366920938463463374607431768211455u128"));
+    assert!(!fits("340282366920938463463374607431768211456u128"));
+    assert!(fits("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffffu128"));
+
+    // Unsuffixed literals are not checked.
+    assert!(fits("0"));
+    assert!(fits("340282366920938463463374607431768211456"));
+
+    match lit("1000i8") {
+        Lit::Int(lit) => {
+            let err = lit.check().unwrap_err();
+            assert_eq!(err.to_string(), "literal out of range for `i8`");
+        }
+        wrong => panic!("{:?}", wrong),
+    }
+}
+
 #[test]
 fn floats() {
     #[cfg_attr(feature = "cargo-clippy", allow(float_cmp))]
@@ -186,3 +230,26 @@ fn floats() {
     test_float("1.0__3e-12", 1.03e-12, "");
     test_float("1.03e+12", 1.03e12, "");
 }
+
+#[test]
+fn verbatim() {
+    for &s in &["c\"foo\"", "cr\"foo\"", "cr#\"foo\"#"] {
+        // The lexer that proc-macro2 uses outside of a procedural macro does
+        // not know C string literals and splits them into an identifier and a
+        // string.
+        if TokenStream::from_str(s).unwrap().into_iter().count() != 1 {
+            continue;
+        }
+
+        let parsed: Lit = syn::parse_str(s).unwrap();
+        match &parsed {
+            Lit::Verbatim(lit) => assert_eq!(lit.to_string(), s),
+            wrong => panic!("{:?}", wrong),
+        }
+        assert_eq!(parsed.into_token_stream().to_string(), s);
+        match lit(s) {
+            Lit::Verbatim(_) => {}
+            wrong => panic!("{:?}", wrong),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Delimiter;
+use quote::quote;
+use syn::{FragmentKind, ItemMacro, Matcher, RepetitionOp};
+
+fn matcher(source: &str) -> Vec<Matcher> {
+    let item: ItemMacro = syn::parse_str(source).unwrap();
+    let rules = item.rules().unwrap();
+    assert_eq!(rules.len(), 1);
+    rules[0].parse_matcher().unwrap()
+}
+
+fn matcher_error(source: &str) -> String {
+    let item: ItemMacro = syn::parse_str(source).unwrap();
+    let rules = item.rules().unwrap();
+    rules[0].parse_matcher().unwrap_err().to_string()
+}
+
+fn fragment(matcher: &Matcher) -> (String, FragmentKind) {
+    match matcher {
+        Matcher::Fragment(fragment) => (fragment.name.to_string(), fragment.kind),
+        other => panic!("expected fragment, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_rules() {
+    let item: ItemMacro =
+        syn::parse_str("macro_rules! m { () => {}; ($a:expr) => ($a); [$b:ty] => [Vec<$b>] }")
+            .unwrap();
+    let rules = item.rules().unwrap();
+    assert_eq!(rules.len(), 3);
+    assert!(rules[0].matcher.is_empty());
+    assert_eq!(rules[1].transcriber.to_string(), "$ a");
+    assert_eq!(rules[2].matcher.to_string(), "$ b : ty");
+
+    let item: ItemMacro = syn::parse_str("println!(\"{}\", x);").unwrap();
+    assert!(item.rules().is_err());
+}
+
+#[test]
+fn test_macro_export() {
+    for &(source, exported) in &[
+        ("#[macro_export] macro_rules! m { () => {} }", true),
+        (
+            "#[doc(hidden)] #[macro_export(local_inner_macros)] macro_rules! m { () => {} }",
+            true,
+        ),
+        ("macro_rules! m { () => {} }", false),
+        ("#[macro_use] macro_rules! m { () => {} }", false),
+        ("#[macro_export] m! { () => {} }", false),
+    ] {
+        let item: ItemMacro = syn::parse_str(source).unwrap();
+        assert_eq!(item.is_macro_export(), exported, "{}", source);
+
+        let reparsed: ItemMacro = syn::parse2(quote!(#item)).unwrap();
+        assert_eq!(reparsed, item);
+        assert_eq!(reparsed.is_macro_export(), exported, "{}", source);
+    }
+}
+
+#[test]
+fn test_fragments() {
+    let matcher = matcher(
+        "macro_rules! m {
+            (
+                $b:block $e:expr $e2:expr_2021 $i:ident $it:item $l:lifetime
+                $lit:literal $m:meta $p:pat $pp:pat_param $pa:path $s:stmt $t:tt
+                $ty:ty $v:vis
+            ) => {};
+        }",
+    );
+    let fragments: Vec<_> = matcher.iter().map(fragment).collect();
+    let expected = vec![
+        ("b", FragmentKind::Block),
+        ("e", FragmentKind::Expr),
+        ("e2", FragmentKind::Expr2021),
+        ("i", FragmentKind::Ident),
+        ("it", FragmentKind::Item),
+        ("l", FragmentKind::Lifetime),
+        ("lit", FragmentKind::Literal),
+        ("m", FragmentKind::Meta),
+        ("p", FragmentKind::Pat),
+        ("pp", FragmentKind::PatParam),
+        ("pa", FragmentKind::Path),
+        ("s", FragmentKind::Stmt),
+        ("t", FragmentKind::Tt),
+        ("ty", FragmentKind::Ty),
+        ("v", FragmentKind::Vis),
+    ];
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(name, kind)| (name.to_owned(), kind))
+        .collect();
+    assert_eq!(fragments, expected);
+}
+
+#[test]
+fn test_repetitions() {
+    let matcher = matcher(
+        "macro_rules! m {
+            (struct $name:ident { $($field:ident : $ty:ty),+ $(,)? } $($rest:tt)*) => {};
+        }",
+    );
+    assert_eq!(matcher.len(), 4);
+
+    match &matcher[0] {
+        Matcher::Token(token) => assert_eq!(token.to_string(), "struct"),
+        other => panic!("expected token, found {:?}", other),
+    }
+    assert_eq!(
+        fragment(&matcher[1]),
+        ("name".to_owned(), FragmentKind::Ident)
+    );
+
+    let body = match &matcher[2] {
+        Matcher::Group(group) => group,
+        other => panic!("expected group, found {:?}", other),
+    };
+    assert_eq!(body.delimiter, Delimiter::Brace);
+    assert_eq!(body.contents.len(), 2);
+
+    match &body.contents[0] {
+        Matcher::Repetition(rep) => {
+            assert_eq!(rep.op, RepetitionOp::OneOrMore);
+            assert_eq!(rep.separator.as_ref().unwrap().to_string(), ",");
+            assert_eq!(rep.contents.len(), 3);
+            assert_eq!(
+                fragment(&rep.contents[0]),
+                ("field".to_owned(), FragmentKind::Ident)
+            );
+            assert_eq!(
+                fragment(&rep.contents[2]),
+                ("ty".to_owned(), FragmentKind::Ty)
+            );
+        }
+        other => panic!("expected repetition, found {:?}", other),
+    }
+    match &body.contents[1] {
+        Matcher::Repetition(rep) => {
+            assert_eq!(rep.op, RepetitionOp::ZeroOrOne);
+            assert!(rep.separator.is_none());
+            assert_eq!(rep.contents.len(), 1);
+        }
+        other => panic!("expected repetition, found {:?}", other),
+    }
+
+    match &matcher[3] {
+        Matcher::Repetition(rep) => {
+            assert_eq!(rep.op, RepetitionOp::ZeroOrMore);
+            assert!(rep.separator.is_none());
+        }
+        other => panic!("expected repetition, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_repetitions_and_separators() {
+    let matcher = matcher(
+        "macro_rules! m {
+            ($($k:expr => $($v:expr);*)=>*  $($x:ident)and+ $($y:ident),*$($z:ident)++) => {};
+        }",
+    );
+    // The first `+` after a repetition is always its operator, so the second
+    // one is matched literally.
+    assert_eq!(matcher.len(), 5);
+
+    let outer = match &matcher[0] {
+        Matcher::Repetition(rep) => rep,
+        other => panic!("expected repetition, found {:?}", other),
+    };
+    assert_eq!(outer.op, RepetitionOp::ZeroOrMore);
+    assert_eq!(outer.separator.as_ref().unwrap().to_string(), "=>");
+    // The `=>` inside of the repetition is two `Punct` tokens.
+    match &outer.contents[3] {
+        Matcher::Repetition(inner) => {
+            assert_eq!(inner.op, RepetitionOp::ZeroOrMore);
+            assert_eq!(inner.separator.as_ref().unwrap().to_string(), ";");
+            assert_eq!(
+                fragment(&inner.contents[0]),
+                ("v".to_owned(), FragmentKind::Expr)
+            );
+        }
+        other => panic!("expected repetition, found {:?}", other),
+    }
+
+    let separators: Vec<_> = matcher[1..4]
+        .iter()
+        .map(|matcher| match matcher {
+            Matcher::Repetition(rep) => (rep.separator.as_ref().map(ToString::to_string), rep.op),
+            other => panic!("expected repetition, found {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        separators,
+        [
+            (Some("and".to_owned()), RepetitionOp::OneOrMore),
+            (Some(",".to_owned()), RepetitionOp::ZeroOrMore),
+            (None, RepetitionOp::OneOrMore),
+        ],
+    );
+    match &matcher[4] {
+        Matcher::Token(token) => assert_eq!(token.to_string(), "+"),
+        other => panic!("expected token, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_errors() {
+    let cases = [
+        (
+            "macro_rules! m { ($x) => {} }",
+            "missing fragment specifier",
+        ),
+        (
+            "macro_rules! m { ($x:) => {} }",
+            "missing fragment specifier",
+        ),
+        (
+            "macro_rules! m { ($x:expression) => {} }",
+            "invalid fragment specifier `expression`",
+        ),
+        (
+            "macro_rules! m { ($) => {} }",
+            "expected identifier or `(` after `$`",
+        ),
+        (
+            "macro_rules! m { ($($x:tt)) => {} }",
+            "expected one of: `*`, `+`, or `?`",
+        ),
+        (
+            "macro_rules! m { ($($x:tt),) => {} }",
+            "expected one of: `*`, `+`, or `?`",
+        ),
+        (
+            "macro_rules! m { ($($x:tt),?) => {} }",
+            "the `?` macro repetition operator does not take a separator",
+        ),
+        (
+            "macro_rules! m { ($($x:tt)[]*) => {} }",
+            "invalid repetition separator",
+        ),
+    ];
+    for &(source, message) in &cases {
+        assert_eq!(matcher_error(source), message, "{}", source);
+    }
+}
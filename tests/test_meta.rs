@@ -341,3 +341,25 @@ fn test_parse_nested_meta() {
    ⋮})
     "###);
 }
+
+#[test]
+fn test_nested_words() {
+    fn words(input: &str) -> Vec<String> {
+        let meta: Meta = syn::parse_str(input).unwrap();
+        meta.nested_words()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    assert_eq!(words("inline(always)"), ["always"]);
+    assert_eq!(words("derive(Clone, Debug)"), ["Clone", "Debug"]);
+    assert_eq!(
+        words("deprecated(since = \"1.0\", note, \"x\", serde::Serialize, list(a))"),
+        ["note"],
+    );
+    assert!(words("derive()").is_empty());
+    assert!(words("must_use").is_empty());
+    assert!(words("non_exhaustive").is_empty());
+    assert!(words("path = \"a.rs\"").is_empty());
+}
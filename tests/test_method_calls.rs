@@ -0,0 +1,69 @@
+extern crate syn;
+
+mod features;
+
+use syn::{method_calls, parse_quote, File};
+
+fn calls(file: &File) -> Vec<(String, usize)> {
+    method_calls(file)
+        .into_iter()
+        .map(|(ident, count)| (ident.to_string(), count))
+        .collect()
+}
+
+#[test]
+fn test_only_method_calls() {
+    let file: File = parse_quote! {
+        fn f(x: Vec<u8>) -> usize {
+            let a = Vec::new();
+            let b = <u8 as Default>::default();
+            let c = len(&x);
+            let d = (x.len)();
+            x.len() + x.iter().len() + Vec::len(&x) + x.r#len()
+        }
+    };
+    assert_eq!(
+        calls(&file),
+        [("len".to_owned(), 3), ("iter".to_owned(), 1)]
+    );
+}
+
+#[test]
+fn test_nested() {
+    let file: File = parse_quote! {
+        impl Foo {
+            fn f(&self) {
+                self.g(|x| x.h::<u8>());
+                let _ = async { self.g(0).await };
+                println!("{}", self.not_counted());
+            }
+        }
+
+        trait Bar {
+            const N: usize = self::X.h();
+        }
+
+        mod m {
+            static S: () = { Y.g(); };
+        }
+    };
+    assert_eq!(calls(&file), [("g".to_owned(), 3), ("h".to_owned(), 2)]);
+}
+
+#[test]
+fn test_source_order() {
+    let file: File = parse_quote! {
+        fn f(v: Vec<u8>, w: Vec<u8>) -> usize {
+            v.iter().map(|x| w.get(*x)).count()
+        }
+    };
+    assert_eq!(
+        calls(&file),
+        [
+            ("iter".to_owned(), 1),
+            ("map".to_owned(), 1),
+            ("get".to_owned(), 1),
+            ("count".to_owned(), 1),
+        ],
+    );
+}
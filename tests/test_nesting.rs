@@ -0,0 +1,79 @@
+extern crate syn;
+
+mod features;
+
+use syn::{max_nesting_depth, parse_quote, ItemFn};
+
+#[test]
+fn test_straight_line() {
+    let item: ItemFn = parse_quote! {
+        fn f() {
+            let x = 1;
+            g(x);
+        }
+    };
+    assert_eq!(max_nesting_depth(&item), 0);
+}
+
+#[test]
+fn test_nested_blocks_and_loops() {
+    let item: ItemFn = parse_quote! {
+        fn f() {
+            loop {
+                while g() {
+                    for x in y {
+                        unsafe {
+                            {}
+                        }
+                    }
+                }
+            }
+        }
+    };
+    assert_eq!(max_nesting_depth(&item), 5);
+}
+
+#[test]
+fn test_match_arms() {
+    let item: ItemFn = parse_quote! {
+        fn f() {
+            match x {
+                A => {}
+                B => {
+                    g();
+                }
+                C => if y { z() } else { w() },
+            }
+        }
+    };
+    assert_eq!(max_nesting_depth(&item), 2);
+}
+
+#[test]
+fn test_else_if() {
+    let item: ItemFn = parse_quote! {
+        fn f() {
+            if a {
+            } else if b {
+            } else if c {
+            } else {
+            }
+        }
+    };
+    assert_eq!(max_nesting_depth(&item), 1);
+}
+
+#[test]
+fn test_nested_item() {
+    let item: ItemFn = parse_quote! {
+        fn f() {
+            fn g() {
+                loop {
+                    loop {}
+                }
+            }
+            if a {}
+        }
+    };
+    assert_eq!(max_nesting_depth(&item), 1);
+}
@@ -0,0 +1,87 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::FileCache;
+
+#[test]
+fn test_cached_matches_uncached() {
+    let content = "#!/usr/bin/env run-cargo-script\n\
+                   //! Docs.\n\
+                   use std::fmt;\n\
+                   fn main() { println!(\"{}\", 1 + 1); }\n";
+
+    let expected = syn::parse_file(content).unwrap();
+    let mut cache = FileCache::new();
+    for _ in 0..3 {
+        let file = cache.parse_file(content).unwrap();
+        assert_eq!(file, expected);
+        assert_eq!(
+            file.shebang.as_ref().unwrap(),
+            "#!/usr/bin/env run-cargo-script"
+        );
+    }
+    assert_eq!(cache.len(), 1);
+
+    // The byte order mark and shebang are not part of the cached tokens.
+    let without_shebang =
+        "\u{feff}\n//! Docs.\nuse std::fmt;\nfn main() { println!(\"{}\", 1 + 1); }\n";
+    let file = cache.parse_file(without_shebang).unwrap();
+    assert!(file.shebang.is_none());
+    assert_eq!(quote!(#file).to_string(), quote!(#expected).to_string());
+    assert_eq!(cache.len(), 1);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_cached_errors() {
+    let mut cache = FileCache::new();
+
+    // Content that fails to tokenize is not cached.
+    let unterminated = "fn f() { \"unterminated }";
+    for _ in 0..2 {
+        assert!(syn::parse_file(unterminated).is_err());
+        assert!(cache.parse_file(unterminated).is_err());
+    }
+    assert!(cache.is_empty());
+
+    // Content that tokenizes is cached even if it fails to parse.
+    let missing_type = "fn f() -> {}";
+    let expected = syn::parse_file(missing_type).unwrap_err().to_string();
+    for _ in 0..2 {
+        let err = cache.parse_file(missing_type).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_crate_features() {
+    let file = syn::parse_file(
+        "#![feature(a, b,)]\n\
+         #![cfg_attr(test, feature(c))]\n\
+         #![feature()]\n\
+         #![doc = \"feature(d)\"]\n\
+         #![feature(e, f = \"g\", h::i, j(k))]\n\
+         #![feature(r#l)]\n\
+         #[feature(m)]\n\
+         mod n {\n\
+             #![feature(o)]\n\
+         }",
+    )
+    .unwrap();
+
+    let features: Vec<String> = file
+        .crate_features()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(features, ["a", "b", "e", "r#l"]);
+
+    let file = syn::parse_file("fn main() {}").unwrap();
+    assert!(file.crate_features().is_empty());
+}
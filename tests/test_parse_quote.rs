@@ -0,0 +1,20 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, Type};
+
+#[test]
+fn test_interpolation() {
+    let elem: Type = parse_quote!(u8);
+    let ty: Type = parse_quote!(Vec<#elem>);
+    assert_eq!(quote!(#ty).to_string(), quote!(Vec<u8>).to_string());
+}
+
+#[test]
+#[should_panic(expected = "parse_quote! failed to parse")]
+fn test_parse_failure() {
+    let _: Type = parse_quote!(Vec < u8);
+}
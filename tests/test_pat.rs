@@ -4,7 +4,7 @@ extern crate syn;
 mod features;
 
 use quote::quote;
-use syn::Pat;
+use syn::{FnArg, Pat};
 
 #[test]
 fn test_pat_ident() {
@@ -21,3 +21,29 @@ fn test_pat_path() {
         value => panic!("expected PatPath, got {:?}", value),
     }
 }
+
+#[test]
+fn test_pat_ident_self_value() {
+    let arg: FnArg = syn::parse2(quote!(self: Box<Self>)).unwrap();
+    let pat = match arg {
+        FnArg::Typed(arg) => arg.pat,
+        arg => panic!("expected PatType, got {:?}", arg),
+    };
+    match *pat {
+        Pat::Ident(pat) => assert!(pat.is_self_value()),
+        pat => panic!("expected PatIdent, got {:?}", pat),
+    }
+
+    for pat in &[quote!(this), quote!(self_), quote!(self @ _)] {
+        match syn::parse2(pat.clone()).unwrap() {
+            Pat::Ident(pat) => assert!(!pat.is_self_value()),
+            pat => panic!("expected PatIdent, got {:?}", pat),
+        }
+    }
+
+    // The `Self` type is not an identifier pattern at all.
+    match syn::parse2(quote!(Self)).unwrap() {
+        Pat::Path(pat) => assert!(pat.path.is_self_type()),
+        pat => panic!("expected PatPath, got {:?}", pat),
+    }
+}
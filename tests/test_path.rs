@@ -0,0 +1,229 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+#[macro_use]
+mod macros;
+
+use quote::quote;
+use syn::{parse_quote, Expr, ExprPath, Path, Type, TypePath};
+
+#[test]
+fn test_matches_ignoring_args() {
+    let plain: Path = parse_quote!(serde::Serialize);
+    let angle: Path = parse_quote!(serde::Serialize::<T>);
+
+    assert!(plain.matches_ignoring_args(&angle));
+    assert!(angle.matches_ignoring_args(&plain));
+
+    // Strict equality takes the arguments into account.
+    assert!(plain != angle);
+    assert!(plain == parse_quote!(serde::Serialize));
+}
+
+#[test]
+fn test_matches_ignoring_parenthesized_args() {
+    let plain: Path = parse_quote!(Fn);
+    let paren: TypePath = parse_quote!(Fn(u8) -> u8);
+
+    assert!(plain.matches_ignoring_args(&paren.path));
+    assert!(plain != paren.path);
+}
+
+#[test]
+fn test_matches_ignoring_args_mismatch() {
+    let path: Path = parse_quote!(serde::Serialize);
+
+    let different_ident: Path = parse_quote!(serde::Deserialize);
+    assert!(!path.matches_ignoring_args(&different_ident));
+
+    let leading_colon: Path = parse_quote!(::serde::Serialize);
+    assert!(!path.matches_ignoring_args(&leading_colon));
+
+    let shorter: Path = parse_quote!(Serialize);
+    assert!(!path.matches_ignoring_args(&shorter));
+
+    let longer: Path = parse_quote!(serde::ser::Serialize);
+    assert!(!path.matches_ignoring_args(&longer));
+}
+
+#[test]
+fn test_binding() {
+    let tokens = quote!(Iterator<Item = u8>);
+    let ty = snapshot!(tokens.clone() as Type, @r###"
+   ⋮Type::Path {
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "Iterator",
+   ⋮                arguments: PathArguments::AngleBracketed {
+   ⋮                    args: [
+   ⋮                        Binding(Binding {
+   ⋮                            ident: "Item",
+   ⋮                            ty: Type::Path {
+   ⋮                                path: Path {
+   ⋮                                    segments: [
+   ⋮                                        PathSegment {
+   ⋮                                            ident: "u8",
+   ⋮                                            arguments: None,
+   ⋮                                        },
+   ⋮                                    ],
+   ⋮                                },
+   ⋮                            },
+   ⋮                        }),
+   ⋮                    ],
+   ⋮                },
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮}
+    "###);
+    assert_eq!(quote!(#ty).to_string(), tokens.to_string());
+}
+
+#[test]
+fn test_constraint() {
+    let tokens = quote!(Iterator<Item: Clone + 'static>);
+    let ty = snapshot!(tokens.clone() as Type, @r###"
+   ⋮Type::Path {
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "Iterator",
+   ⋮                arguments: PathArguments::AngleBracketed {
+   ⋮                    args: [
+   ⋮                        Constraint(Constraint {
+   ⋮                            ident: "Item",
+   ⋮                            bounds: [
+   ⋮                                Trait(TraitBound {
+   ⋮                                    modifier: None,
+   ⋮                                    path: Path {
+   ⋮                                        segments: [
+   ⋮                                            PathSegment {
+   ⋮                                                ident: "Clone",
+   ⋮                                                arguments: None,
+   ⋮                                            },
+   ⋮                                        ],
+   ⋮                                    },
+   ⋮                                }),
+   ⋮                                Lifetime(Lifetime {
+   ⋮                                    ident: "static",
+   ⋮                                }),
+   ⋮                            ],
+   ⋮                        }),
+   ⋮                    ],
+   ⋮                },
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮}
+    "###);
+    assert_eq!(quote!(#ty).to_string(), tokens.to_string());
+}
+
+#[test]
+fn test_negative_const_arg() {
+    let tokens = quote!(Foo<-1i32>);
+    let ty = snapshot!(tokens.clone() as Type, @r###"
+   ⋮Type::Path {
+   ⋮    path: Path {
+   ⋮        segments: [
+   ⋮            PathSegment {
+   ⋮                ident: "Foo",
+   ⋮                arguments: PathArguments::AngleBracketed {
+   ⋮                    args: [
+   ⋮                        Const(Expr::Unary {
+   ⋮                            op: Neg,
+   ⋮                            expr: Expr::Lit {
+   ⋮                                lit: 1i32,
+   ⋮                            },
+   ⋮                        }),
+   ⋮                    ],
+   ⋮                },
+   ⋮            },
+   ⋮        ],
+   ⋮    },
+   ⋮}
+    "###);
+    assert_eq!(syn::parse2::<Type>(quote!(#ty)).unwrap(), ty);
+}
+
+#[test]
+fn test_into_angle_bracketed() {
+    let pairs: Vec<(Type, Type)> = vec![
+        (
+            parse_quote!(Fn(A, B) -> C),
+            parse_quote!(Fn<(A, B), Output = C>),
+        ),
+        (
+            parse_quote!(FnMut(A)),
+            parse_quote!(FnMut<(A,), Output = ()>),
+        ),
+        (
+            parse_quote!(FnOnce()),
+            parse_quote!(FnOnce<(), Output = ()>),
+        ),
+        (parse_quote!(Vec<T>), parse_quote!(Vec<T>)),
+    ];
+    for (sugared, desugared) in pairs {
+        let sugared = match sugared {
+            Type::Path(TypePath { path, .. }) => path.segments.into_iter().next().unwrap(),
+            _ => unreachable!(),
+        };
+        let desugared = match desugared {
+            Type::Path(TypePath { path, .. }) => path.segments.into_iter().next().unwrap(),
+            _ => unreachable!(),
+        };
+        let expected = desugared.arguments.as_angle_bracketed().cloned();
+        assert!(expected.is_some());
+        assert_eq!(sugared.arguments.into_angle_bracketed(), expected);
+    }
+
+    let ty: TypePath = parse_quote!(Fn(u8) -> bool);
+    let arguments = &ty.path.segments[0].arguments;
+    assert!(arguments.as_angle_bracketed().is_none());
+
+    let path: Path = parse_quote!(Default);
+    let arguments = path.segments[0].arguments.clone();
+    assert!(arguments.as_angle_bracketed().is_none());
+    assert!(arguments.into_angle_bracketed().is_none());
+}
+
+#[test]
+fn test_self_type_and_value() {
+    let ty: TypePath = parse_quote!(Self);
+    assert!(ty.path.is_self_type());
+
+    let expr: ExprPath = parse_quote!(Self);
+    assert!(expr.path.is_self_type());
+    assert!(!expr.is_self_value());
+
+    let expr: ExprPath = parse_quote!(self);
+    assert!(expr.is_self_value());
+    assert!(!expr.path.is_self_type());
+
+    // Paths that only begin with `Self` or `self` name something else.
+    for path in &[
+        quote!(Self::new),
+        quote!(Self::Item),
+        quote!(self::module),
+        quote!(<Self>::new),
+        quote!(::Self),
+        quote!(Self::<T>),
+    ] {
+        let expr: ExprPath = syn::parse2(path.clone()).unwrap();
+        assert!(!expr.is_self_value(), "{}", path);
+        assert!(!expr.path.is_self_type(), "{}", path);
+    }
+
+    // A field access has the `self` value as its receiver.
+    let expr: Expr = parse_quote!(self.x);
+    match expr {
+        Expr::Field(field) => match *field.base {
+            Expr::Path(base) => assert!(base.is_self_value()),
+            base => panic!("expected ExprPath, got {:?}", base),
+        },
+        expr => panic!("expected ExprField, got {:?}", expr),
+    }
+}
@@ -0,0 +1,212 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use std::str::FromStr;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, Parser};
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, Expr, Item, Token, Type};
+
+#[test]
+fn test_get() {
+    let mut list: Punctuated<Type, Token![,]> = parse_quote!(A, B, C);
+
+    assert_eq!(list.get(0), Some(&parse_quote!(A)));
+    assert_eq!(list.get(2), Some(&parse_quote!(C)));
+    assert_eq!(list.get(3), None);
+
+    *list.get_mut(1).unwrap() = parse_quote!(D);
+    assert_eq!(list, parse_quote!(A, D, C));
+}
+
+#[test]
+fn test_get_trailing_punct() {
+    let list: Punctuated<Type, Token![,]> = parse_quote!(A, B,);
+
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.first(), Some(&parse_quote!(A)));
+    assert_eq!(list.last(), Some(&parse_quote!(B)));
+    assert_eq!(list.get(1), Some(&parse_quote!(B)));
+    assert_eq!(list.get(2), None);
+}
+
+#[test]
+fn test_get_empty() {
+    let list: Punctuated<Type, Token![,]> = Punctuated::new();
+
+    assert!(list.is_empty());
+    assert_eq!(list.first(), None);
+    assert_eq!(list.get(0), None);
+}
+
+#[test]
+fn test_to_tokens_range() {
+    let list: Punctuated<Type, Token![,]> = parse_quote!(A, B, C);
+
+    let print = |range: std::ops::Range<usize>| {
+        let mut tokens = TokenStream::new();
+        list.to_tokens_range(range, &mut tokens);
+        tokens.to_string()
+    };
+
+    assert_eq!(print(0..2), quote!(A, B).to_string());
+    assert_eq!(print(1..3), quote!(B, C).to_string());
+    assert_eq!(print(1..1), "");
+    assert_eq!(print(0..3), quote!(A, B, C).to_string());
+}
+
+#[test]
+fn test_to_tokens_range_trailing_punct() {
+    let list: Punctuated<Type, Token![,]> = parse_quote!(A, B, C,);
+
+    let mut init = TokenStream::new();
+    list.to_tokens_range(..2, &mut init);
+    assert_eq!(init.to_string(), quote!(A, B).to_string());
+
+    let mut tail = TokenStream::new();
+    list.to_tokens_range(1.., &mut tail);
+    assert_eq!(tail.to_string(), quote!(B, C,).to_string());
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn test_to_tokens_range_max_bound() {
+    let list: Punctuated<Type, Token![,]> = parse_quote!(A, B);
+    list.to_tokens_range(..=std::usize::MAX, &mut TokenStream::new());
+}
+
+#[test]
+fn test_trailing_commas_round_trip() {
+    // Compares tokens one at a time, ignoring the spacing of punctuation.
+    fn flatten(tokens: TokenStream, out: &mut Vec<String>) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Group(group) => {
+                    out.push(format!("{:?}", group.delimiter()));
+                    flatten(group.stream(), out);
+                    out.push("end".to_owned());
+                }
+                TokenTree::Punct(punct) => out.push(punct.as_char().to_string()),
+                tt => out.push(tt.to_string()),
+            }
+        }
+    }
+
+    fn round_trip<T: Parse + ToTokens>(input: &str) {
+        let tokens = TokenStream::from_str(input).unwrap();
+        let node: T = syn::parse2(tokens.clone()).unwrap();
+        let (mut expected, mut actual) = (Vec::new(), Vec::new());
+        flatten(tokens, &mut expected);
+        flatten(quote!(#node), &mut actual);
+        assert_eq!(actual, expected, "{}", input);
+    }
+
+    for &input in &[
+        "f(a, b,)",
+        "f(a, b)",
+        "x.f::<A, B,>(a,)",
+        "(1,)",
+        "(1, 2,)",
+        "[1, 2,]",
+        "S { x: 1, }",
+        "S { x: 1, y }",
+        "S { x, ..base }",
+        "match x { A => 1, B => { 2 } C => 3, }",
+        "match x { A => 1, B => 2 }",
+        "|a, b,| a",
+    ] {
+        round_trip::<Expr>(input);
+    }
+
+    for &input in &[
+        "(u8, u16,)",
+        "(u8,)",
+        "(u8, u16)",
+        "fn(u8, u16,) -> u32",
+        "Vec<u8,>",
+        "HashMap<K, V>",
+        "dyn Fn(u8, u16,) -> u32",
+    ] {
+        round_trip::<Type>(input);
+    }
+
+    for &input in &[
+        "fn f<T, U,>(a: T, b: U,) where T: Clone, U: Copy, {}",
+        "fn f<T>(a: T) where T: Clone {}",
+        "struct S { a: u8, b: u16, }",
+        "struct S(u8, u16,);",
+        "enum E { A, B(u8,), C { x: u8 }, }",
+        "use a::{b, c::{d, e,},};",
+        "impl<T,> Trait<A, B,> for S<T,> {}",
+        "fn f() { let (a, b,) = x; let S(c,) = y; let S { d, e, } = z; }",
+        "#[derive(Clone, Copy,)] struct S;",
+    ] {
+        round_trip::<Item>(input);
+    }
+}
+
+#[test]
+fn test_remove() {
+    let mut list: Punctuated<Type, Token![,]> = parse_quote!(A, B, C);
+    assert_eq!(list.remove(1), parse_quote!(B));
+    assert_eq!(list, parse_quote!(A, C));
+    assert_eq!(list.remove(1), parse_quote!(C));
+    assert_eq!(list, parse_quote!(A));
+    assert!(!list.trailing_punct());
+    assert_eq!(list.remove(0), parse_quote!(A));
+    assert!(list.is_empty());
+
+    let mut list: Punctuated<Type, Token![,]> = parse_quote!(A, B, C,);
+    assert_eq!(list.remove(2), parse_quote!(C));
+    assert_eq!(list, parse_quote!(A, B,));
+    assert_eq!(list.remove(0), parse_quote!(A));
+    assert_eq!(list, parse_quote!(B,));
+    assert_eq!(list.remove(0), parse_quote!(B));
+    assert!(list.is_empty());
+    assert!(!list.trailing_punct());
+}
+
+#[test]
+#[should_panic]
+fn test_remove_out_of_bounds() {
+    let mut list: Punctuated<Type, Token![,]> = parse_quote!(A, B);
+    list.remove(2);
+}
+
+#[test]
+fn test_retain() {
+    let keep = |ty: &Type| quote!(#ty).to_string() != "B";
+
+    for &(input, expected) in &[
+        ("A, B, C", "A, C"),
+        ("A, B, C,", "A, C,"),
+        ("A, B", "A"),
+        ("A, B,", "A,"),
+        ("B, A", "A"),
+        ("B", ""),
+        ("B,", ""),
+        ("B, B,", ""),
+        ("", ""),
+    ] {
+        let parser = Punctuated::<Type, Token![,]>::parse_terminated;
+        let mut list = parser.parse_str(input).unwrap();
+        list.retain(keep);
+        let expected: TokenStream = expected.parse().unwrap();
+        assert_eq!(quote!(#list).to_string(), expected.to_string(), "{}", input);
+    }
+
+    let mut list: Punctuated<Type, Token![,]> = parse_quote!(A, B, C, D);
+    let mut seen = Vec::new();
+    list.retain(|ty| {
+        seen.push(quote!(#ty).to_string());
+        seen.len() % 2 == 1
+    });
+    assert_eq!(seen, ["A", "B", "C", "D"]);
+    assert_eq!(list, parse_quote!(A, C));
+    assert!(!list.trailing_punct());
+}
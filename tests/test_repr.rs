@@ -0,0 +1,80 @@
+extern crate syn;
+
+mod features;
+
+use syn::parse::Parser;
+use syn::{parse_repr, Attribute, Repr, ReprKind};
+
+fn repr(input: &str) -> syn::Result<Repr> {
+    let attrs = Attribute::parse_outer.parse_str(input).unwrap();
+    parse_repr(&attrs)
+}
+
+#[test]
+fn test_default() {
+    let repr = repr("#[derive(Clone)] #[doc = \"...\"]").unwrap();
+    assert_eq!(repr, Repr::default());
+    assert_eq!(repr.kind, ReprKind::Rust);
+}
+
+#[test]
+fn test_kinds() {
+    assert_eq!(repr("#[repr(C)]").unwrap().kind, ReprKind::C);
+    assert_eq!(repr("#[repr(Rust)]").unwrap().kind, ReprKind::Rust);
+    assert_eq!(
+        repr("#[repr(transparent)]").unwrap().kind,
+        ReprKind::Transparent
+    );
+}
+
+#[test]
+fn test_primitive() {
+    let repr_u8 = repr("#[repr(u8)]").unwrap();
+    assert_eq!(repr_u8.kind, ReprKind::Rust);
+    assert_eq!(repr_u8.int.unwrap(), "u8");
+
+    let repr_c_i32 = repr("#[repr(C, i32)]").unwrap();
+    assert_eq!(repr_c_i32.kind, ReprKind::C);
+    assert_eq!(repr_c_i32.int.unwrap(), "i32");
+}
+
+#[test]
+fn test_packed_and_align() {
+    let packed = repr("#[repr(C, packed)]").unwrap();
+    assert_eq!(packed.kind, ReprKind::C);
+    assert_eq!(packed.packed, Some(1));
+
+    let packed_2 = repr("#[repr(C, packed(2))]").unwrap();
+    assert_eq!(packed_2.packed, Some(2));
+    assert_eq!(packed_2.align, None);
+
+    let align = repr("#[repr(C)] #[repr(align(16))]").unwrap();
+    assert_eq!(align.kind, ReprKind::C);
+    assert_eq!(align.align, Some(16));
+}
+
+#[test]
+fn test_errors() {
+    let cases = [
+        (
+            "#[repr(C, transparent)]",
+            "conflicting representation hints",
+        ),
+        ("#[repr(u8, u16)]", "conflicting representation hints"),
+        (
+            "#[repr(packed, align(4))]",
+            "`packed` and `align` cannot both be used",
+        ),
+        ("#[repr(align(3))]", "expected a power of two"),
+        ("#[repr(align)]", "expected an alignment, as in `align(8)`"),
+        ("#[repr(C(1))]", "`C` does not take an argument"),
+        ("#[repr(simd)]", "unrecognized representation hint `simd`"),
+        (
+            "#[repr(transparent, u8)]",
+            "`transparent` cannot be combined with a primitive representation",
+        ),
+    ];
+    for (input, message) in &cases {
+        assert_eq!(repr(input).unwrap_err().to_string(), *message, "{}", input);
+    }
+}
@@ -47,3 +47,356 @@ should_parse!(match_with_block_expr, {
         }
     }
 });
+
+should_parse!(try_block, {
+    fn main() {
+        let r: Result<_, _> = try { foo()? };
+    }
+});
+
+should_parse!(try_macro_is_not_try_block, {
+    fn main() {
+        let r = try!(foo());
+    }
+});
+
+should_parse!(let_with_type_annotation, {
+    fn main() {
+        let (a, b): (u8, u16) = pair;
+        let Struct { x }: Struct = s;
+        let [first, .., last]: [u8; 4];
+    }
+});
+
+should_parse!(await_chain_with_try, {
+    async fn f() {
+        let x = foo().await?.bar().await;
+    }
+});
+
+should_parse!(extern_block_with_foreign_items, {
+    extern "C" {
+        fn printf(format: *const c_char, ...) -> c_int;
+        static mut errno: c_int;
+        type Opaque;
+        #[link_name = "real_name"]
+        pub fn renamed(x: u8);
+    }
+});
+
+should_parse!(block_expressions_in_statement_and_value_position, {
+    fn main() {
+        match x {
+            _ => {}
+        }
+        let y = if c { a } else { b };
+        if c {
+            a
+        } else {
+            b
+        }
+        loop {}
+        match y {
+            _ => {}
+        }
+    }
+});
+
+should_parse!(macro_in_type_position, {
+    type X = gen!();
+    fn f() -> my_macro!(i32) {}
+    fn g(x: Vec<path::to::m![u8]>) {}
+});
+
+should_parse!(qualified_path_in_expression_position, {
+    fn main() {
+        let s = <i32 as ToString>::to_string(&5);
+        let v = <Vec<u8> as Default>::default();
+        let x = <T as Into<U>>::into(t);
+        let y = <[u8]>::len(&b);
+        let z = <T as Trait>::method::<u8, u16>();
+    }
+});
+
+should_parse!(box_pattern_with_nested_ref, {
+    fn main() {
+        let box Point { ref x, .. } = b;
+        let (ref a, (ref mut b, box ref c)) = t;
+        match e {
+            Some(box Node {
+                ref mut left,
+                right: box ref right,
+            }) => {}
+            _ => {}
+        }
+    }
+});
+
+should_parse!(const_generics_in_impl_header, {
+    impl<const N: usize> Default for MyArray<N> {}
+    impl<T: Default, const N: usize> Trait for [T; N] {}
+    impl<const N: usize> MyArray<{ N }> {
+        fn len(&self) -> usize {
+            N
+        }
+    }
+});
+
+should_parse!(const_trait_bound_modifiers, {
+    fn f<T: ~const Clone + ?Sized>(t: &T) -> T
+    where
+        T: ~const Default + ?const PartialEq,
+    {
+        t.clone()
+    }
+    impl<T: ~const Clone> Clone for W<T> {}
+});
+
+should_parse!(attributes_on_enum_struct_variant_fields, {
+    enum E {
+        V {
+            #[cfg(x)]
+            a: u8,
+            #[doc = "b"]
+            #[serde(rename = "c")]
+            b: u16,
+        },
+        W(#[cfg(y)] u8, #[allow(dead_code)] u16),
+    }
+});
+
+should_parse!(const_generics_exprs, {
+    type A = Foo<{ -1 }>;
+    type B = Foo<-1>;
+    type C = Foo<-1i32, 5u8>;
+    type D = Foo<{ N * 2 }>;
+    type E = Foo<T, -128, { -N }>;
+    fn f() -> Foo<-1> {
+        g::<-1, 2usize>()
+    }
+});
+
+should_parse!(inline_const_blocks, {
+    type A = [u8; const { N }];
+    type B = Foo<const { N }>;
+    fn f() -> Foo<const { N }> {
+        const { assert!(N > 0) }
+        let a: [u8; const { N }] = [0; const { N }];
+        g::<const { N }, u8>(const { N + 1 })
+    }
+});
+
+should_parse!(let_else, {
+    fn main() {
+        let Some(x) = opt else { return };
+        let Ok(y) = x.parse::<u8>() else {
+            panic!("not a number");
+        };
+        let (a, b) = if c { (1, 2) } else { (3, 4) };
+    }
+});
+
+#[rustfmt::skip]
+should_parse!(nested_tuple_index, {
+    fn main() {
+        let a = t.0.0;
+        let b = t.0.1.2;
+        let c = t.0.1.foo().2.3;
+        t.1.0 = 1;
+        (t.0).0.await;
+    }
+});
+
+should_parse!(raw_identifiers, {
+    use r#mod::r#type::Foo;
+
+    struct r#struct {
+        r#match: u8,
+    }
+
+    fn r#fn(r#in: r#struct) -> a::r#mod::b {
+        let x = a::r#mod::b::r#fn(r#in.r#match);
+        x.r#match();
+        x.r#await.r#loop::<r#u8>();
+        r#mod::r#type { r#match: 1 }
+    }
+});
+
+should_parse!(array_length_exprs, {
+    type A = [u8; N + 1];
+    type B = [u8; { compute() }];
+    type C = [[u8; N * 2]; M - 1];
+    fn f() -> [u8; SIZE * 2] {}
+    fn g(x: [u8; { N + 1 }]) -> [u8; <T as Trait>::LEN] {}
+});
+
+should_parse!(labeled_block, {
+    fn main() {
+        let x = 'a: {
+            if cond() {
+                break 'a 5;
+            }
+            'b: {
+                break 'a 'b: { break 'b 6 };
+            }
+        };
+        'c: {
+            break 'c;
+        }
+        f('d: { break 'd 1 });
+    }
+});
+
+#[rustfmt::skip]
+should_parse!(generic_associated_type_definitions, {
+    impl Foo for Bar {
+        type Item<'a> = &'a str;
+        type Assoc<T> = Vec<T>;
+        type Other<'a, T: 'a> where T: Clone = &'a T;
+    }
+});
+
+#[rustfmt::skip]
+should_parse!(async_move_closures, {
+    fn main() {
+        let a = async move || foo().await;
+        let b = async move |x| { x.await };
+        let c = async |x: u8| -> u8 { x };
+        let d = move || async move { 1 };
+        spawn(async move |x, y| bar(x, y).await);
+    }
+});
+
+should_parse!(items_in_fn_body, {
+    fn main() {
+        use std::fmt::{self, Display};
+
+        struct Local;
+
+        impl Local {
+            fn new() -> Self {
+                Local
+            }
+        }
+
+        impl Display for Local {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                Ok(())
+            }
+        }
+
+        unsafe impl Send for Local {}
+
+        impl<T> From<T> for Local {
+            fn from(_: T) -> Self {
+                Local
+            }
+        }
+
+        mod inner {
+            pub fn f() {}
+        }
+
+        trait Helper {}
+        enum E {
+            A,
+        }
+        const N: usize = 1;
+        static S: u8 = 0;
+        type Alias = Local;
+        extern crate alloc;
+        extern "C" {}
+        macro_rules! m {
+            () => {};
+        }
+
+        let x = Local::new();
+        inner::f();
+    }
+});
+
+should_parse!(attributes_on_generic_params, {
+    struct S<#[cfg(x)] T>(T);
+    struct R<
+        #[may_dangle] 'a,
+        #[cfg(y)]
+        #[doc = "T"] T: 'a,
+        #[cfg(z)] const N: usize,
+    >(&'a T);
+    impl<#[may_dangle] 'a, #[cfg(x)] T> Drop for S<'a, T> {
+        fn drop(&mut self) {}
+    }
+    fn f<#[cfg(x)] 'a, #[cfg(y)] T>(x: &'a T) {}
+});
+
+should_parse!(async_methods_in_traits_and_impls, {
+    trait Service {
+        async fn call(&self);
+        async fn with_default(&self) -> u8 {
+            0
+        }
+        async unsafe fn raw<T>(&self, value: T) -> T
+        where
+            T: Send;
+    }
+
+    impl Service for S {
+        async fn call(&self) {}
+        async fn with_default(&self) -> u8 {
+            self.0.await
+        }
+        async unsafe fn raw<T>(&self, value: T) -> T
+        where
+            T: Send,
+        {
+            value
+        }
+    }
+
+    impl S {
+        pub async fn new() -> Self {
+            S(0)
+        }
+        pub(crate) async fn borrowed<'a>(&'a self) -> &'a u8
+        where
+            Self: 'a,
+        {
+            &self.0
+        }
+    }
+});
+
+should_parse!(raw_borrows, {
+    fn main() {
+        let p = &raw const x;
+        let q = &raw mut x;
+        let r = &raw const (*ptr).field;
+        let s = &&raw mut x.0;
+        let raw = &raw;
+        f(&raw const a[0], &mut raw);
+    }
+});
+
+#[rustfmt::skip]
+should_parse!(patterns_in_closures_and_for_loops, {
+    fn main() {
+        let f = |(a, b)| a + b;
+        let g = |Point { x, .. }: Point, [first, .., last]: [u8; 4]| x;
+        let h = move |&(ref a, mut b): &(u8, u8), Wrapper(c)| (a, b, c);
+        for (k, v) in map {}
+        for Point { x, y: ref mut z, .. } in points {}
+        for &(a, [b, c]) in pairs.iter() {}
+        for Some(x) | None in options {}
+    }
+});
+
+#[rustfmt::skip]
+should_parse!(impl_trait_in_type_alias, {
+    type Ret = impl Iterator<Item = u8>;
+    type Fut<'a> = impl Future<Output = ()> + Send + 'a;
+    pub type Handler<T> where T: Clone = impl Fn(T) -> u8;
+
+    impl Trait for S {
+        type Assoc = impl Debug;
+    }
+});
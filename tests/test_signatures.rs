@@ -0,0 +1,74 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, to_signatures, ItemImpl, ItemTrait};
+
+#[test]
+fn test_trait_impl() {
+    let item: ItemImpl = parse_quote! {
+        #[cfg(feature = "iter")]
+        unsafe impl<'a, T: 'a> Iterator for Iter<'a, T> where T: Copy {
+            type Item = &'a T;
+            const LEN: usize = 0;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                None
+            }
+
+            fn nth<F>(mut self, (a, b): (usize, usize), mut n: usize, ref r: &u8) -> F
+            where
+                F: Default
+            {
+                F::default()
+            }
+
+            some_macro!();
+        }
+    };
+
+    let expected: ItemTrait = parse_quote! {
+        #[cfg(feature = "iter")]
+        unsafe trait Iterator<'a, T: 'a> where T: Copy {
+            type Item;
+            const LEN: usize;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item>;
+
+            fn nth<F>(self, _: (usize, usize), n: usize, r: &u8) -> F
+            where
+                F: Default;
+        }
+    };
+
+    assert_eq!(to_signatures(item), expected);
+}
+
+#[test]
+fn test_inherent_impl() {
+    let item: ItemImpl = parse_quote! {
+        impl<K, V> crate::map::Map<K, V> {
+            pub const fn new() -> Self {
+                Map { entries: Vec::new() }
+            }
+
+            pub(crate) default async fn get(&self, key: &K) -> Option<&V> {
+                None
+            }
+        }
+    };
+
+    let expected = quote! {
+        trait MapExt<K, V> {
+            fn new() -> Self;
+            async fn get(&self, key: &K) -> Option<&V>;
+        }
+    };
+
+    let item_trait = to_signatures(item);
+    assert_eq!(quote!(#item_trait).to_string(), expected.to_string());
+}
@@ -0,0 +1,32 @@
+extern crate proc_macro2;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::Span;
+use syn::spanned::spanned_error;
+use syn::{Data, DeriveInput, Generics};
+
+#[test]
+fn test_spanned_error() {
+    let input: DeriveInput = syn::parse_str("struct S {\n    a: u8,\n    b: u16,\n}").unwrap();
+    let field = match &input.data {
+        Data::Struct(data) => data.fields.iter().nth(1).unwrap(),
+        _ => unreachable!(),
+    };
+    let err = spanned_error(field, format!("field `{}` is invalid", "b"));
+    assert_eq!(err.to_string(), "field `b` is invalid");
+
+    #[cfg(feature = "layout")]
+    {
+        let start = err.span().start();
+        assert_eq!((start.line, start.column), (3, 4));
+    }
+
+    let err = spanned_error(&Span::call_site(), "at a span");
+    assert_eq!(err.to_string(), "at a span");
+
+    // A node without tokens falls back to the call site.
+    let err = spanned_error(&Generics::default(), "empty");
+    assert_eq!(err.to_string(), "empty");
+}
@@ -0,0 +1,135 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+#[macro_use]
+mod macros;
+
+use quote::quote;
+use syn::{parse_quote, Block, Item, Stmt};
+
+#[test]
+fn test_let_else() {
+    let tokens = quote!(let Some(x) = opt else { return; };);
+    let stmt = snapshot!(tokens.clone() as Stmt, @r###"
+   ⋮Local(Local {
+   ⋮    pat: Pat::TupleStruct {
+   ⋮        path: Path {
+   ⋮            segments: [
+   ⋮                PathSegment {
+   ⋮                    ident: "Some",
+   ⋮                    arguments: None,
+   ⋮                },
+   ⋮            ],
+   ⋮        },
+   ⋮        pat: PatTuple {
+   ⋮            elems: [
+   ⋮                Pat::Ident {
+   ⋮                    ident: "x",
+   ⋮                },
+   ⋮            ],
+   ⋮        },
+   ⋮    },
+   ⋮    init: Some(Expr::Path {
+   ⋮        path: Path {
+   ⋮            segments: [
+   ⋮                PathSegment {
+   ⋮                    ident: "opt",
+   ⋮                    arguments: None,
+   ⋮                },
+   ⋮            ],
+   ⋮        },
+   ⋮    }),
+   ⋮    diverge: Some(Expr::Block {
+   ⋮        block: Block {
+   ⋮            stmts: [
+   ⋮                Semi(
+   ⋮                    Expr::Return,
+   ⋮                ),
+   ⋮            ],
+   ⋮        },
+   ⋮    }),
+   ⋮})
+    "###);
+    assert_eq!(syn::parse2::<Stmt>(quote!(#stmt)).unwrap(), stmt);
+}
+
+#[test]
+fn test_validate_let_else() {
+    let valid: Vec<Stmt> = vec![
+        parse_quote!(let x = 1;),
+        parse_quote!(let Some(x) = opt else { return; };),
+        parse_quote!(let Some(x) = opt else { return };),
+        parse_quote!(let Ok(x) = res else { continue };),
+        parse_quote!(let Ok(x) = res else { break 'outer; };),
+        parse_quote!(let Some(x) = opt else { panic!("none") };),
+        parse_quote!(let Some(x) = opt else { log(); std::process::exit(1) };),
+        parse_quote!(let Some(x) = opt else { loop {} };),
+        parse_quote!(let Some(x) = opt else { unreachable!(); };),
+        parse_quote!(let Some(x) = opt else {
+            if retry { continue } else { return Err(e) }
+        };),
+        parse_quote!(let Some(x) = opt else {
+            match e {
+                E::A => return,
+                E::B => unsafe { abort() },
+            }
+        };),
+    ];
+    for stmt in &valid {
+        match stmt {
+            Stmt::Local(local) => local.validate_let_else().unwrap(),
+            _ => panic!("expected let statement"),
+        }
+    }
+
+    let invalid: Vec<Stmt> = vec![
+        parse_quote!(let Some(x) = opt else {};),
+        parse_quote!(let Some(x) = opt else { 0 };),
+        parse_quote!(let Some(x) = opt else { return; 0 };),
+        parse_quote!(let Some(x) = opt else { if retry { return } };),
+        parse_quote!(let Some(x) = opt else {
+            match e {
+                E::A => return,
+                E::B => {}
+            }
+        };),
+    ];
+    for stmt in &invalid {
+        match stmt {
+            Stmt::Local(local) => {
+                let err = local.validate_let_else().unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "`else` clause of `let...else` does not diverge",
+                );
+            }
+            _ => panic!("expected let statement"),
+        }
+    }
+}
+
+#[test]
+fn test_nested_items() {
+    let block: Block = parse_quote!({
+        struct Local;
+        impl Local {}
+        mod m {}
+        use std::mem;
+        Local
+    });
+    let kinds: Vec<_> = block
+        .stmts
+        .iter()
+        .map(|stmt| match stmt {
+            Stmt::Item(Item::Struct(_)) => "struct",
+            Stmt::Item(Item::Impl(_)) => "impl",
+            Stmt::Item(Item::Mod(_)) => "mod",
+            Stmt::Item(Item::Use(_)) => "use",
+            Stmt::Expr(_) => "expr",
+            other => panic!("unexpected statement {:?}", other),
+        })
+        .collect();
+    assert_eq!(kinds, ["struct", "impl", "mod", "use", "expr"]);
+}
@@ -0,0 +1,67 @@
+extern crate syn;
+
+mod features;
+
+use syn::{parse_quote, string_literals, File};
+
+fn strings(file: &File, include_doc_and_cfg: bool) -> Vec<String> {
+    string_literals(file, include_doc_and_cfg)
+        .iter()
+        .map(|lit| lit.value())
+        .collect()
+}
+
+#[test]
+fn test_string_literals() {
+    let file: File = parse_quote! {
+        //! Crate docs.
+
+        #[derive(Debug, Error)]
+        enum E {
+            #[error("not found: {0}")]
+            NotFound(String),
+        }
+
+        /// Item docs.
+        #[cfg_attr(feature = "serde", derive(Serialize))]
+        #[cfg(not(test))]
+        fn f(x: &str) -> &'static str {
+            let raw = r"C:\path";
+            match x {
+                "a" => "first",
+                _ => {
+                    log!(target: "app", "unknown {}", concat!("x", 1));
+                    b"bytes";
+                    "other"
+                }
+            }
+        }
+    };
+
+    let expected = vec![
+        "not found: {0}",
+        "C:\\path",
+        "a",
+        "first",
+        "app",
+        "unknown {}",
+        "x",
+        "other",
+    ];
+    assert_eq!(strings(&file, false), expected);
+
+    let expected = vec![
+        " Crate docs.",
+        "not found: {0}",
+        " Item docs.",
+        "serde",
+        "C:\\path",
+        "a",
+        "first",
+        "app",
+        "unknown {}",
+        "x",
+        "other",
+    ];
+    assert_eq!(strings(&file, true), expected);
+}
@@ -0,0 +1,165 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, substitute_ident, Block, Expr, ItemFn};
+
+#[test]
+fn test_value_positions() {
+    let block: Block = parse_quote!({
+        let y = f(n, n + 1);
+        v[n] = n.pow(2);
+        [0u8; n]
+    });
+
+    let expected: Block = parse_quote!({
+        let y = f(LEN, LEN + 1);
+        v[LEN] = LEN.pow(2);
+        [0u8; LEN]
+    });
+
+    assert_eq!(substitute_ident(block, "n", parse_quote!(LEN)), expected);
+}
+
+#[test]
+fn test_non_value_positions() {
+    let item: ItemFn = parse_quote! {
+        fn n(n: n) -> n::Output {
+            let n = x.n + x.n();
+            let Some(n) = n::new();
+            S { n: 1 };
+            println!("{}", n);
+            ::n
+        }
+    };
+
+    let expected: ItemFn = parse_quote! {
+        fn n(n: n) -> n::Output {
+            let n = x.n + x.n();
+            let Some(n) = n::new();
+            S { n: 1 };
+            println!("{}", n);
+            ::n
+        }
+    };
+
+    assert_eq!(substitute_ident(item, "n", parse_quote!(0)), expected);
+}
+
+#[test]
+fn test_precedence() {
+    let expr: Expr = parse_quote!(-x * x.len() + *x);
+    let replacement: Expr = parse_quote!(a + b);
+
+    let substituted = substitute_ident(expr, "x", replacement);
+    let expected = quote!(-(a + b) * (a + b).len() + *(a + b));
+    assert_eq!(quote!(#substituted).to_string(), expected.to_string());
+
+    let expr: Expr = parse_quote!(x * 2);
+    let replacement: Expr = parse_quote!(f(a));
+
+    let substituted = substitute_ident(expr, "x", replacement);
+    let expected = quote!(f(a) * 2);
+    assert_eq!(quote!(#substituted).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_operands() {
+    let cases = [
+        ("x * 2", "a * b", "a * b * 2"),
+        ("2 * x", "a * b", "2 * (a * b)"),
+        ("x - 1", "a + b", "a + b - 1"),
+        ("1 - x", "a + b", "1 - (a + b)"),
+        ("x * 2", "a + b", "(a + b) * 2"),
+        ("x + 1", "a * b", "a * b + 1"),
+        ("x == y", "a == b", "(a == b) == y"),
+        ("x && y", "a || b", "(a || b) && y"),
+        ("x + 1", "-a", "-a + 1"),
+        ("x + 1", "a as u8", "a as u8 + 1"),
+        ("x < 1", "a as u8", "(a as u8) < 1"),
+        ("1 < x", "a as u8", "1 < a as u8"),
+        ("x + 1", "|| a", "(|| a) + 1"),
+        ("-x", "-a", "- -a"),
+        ("&x", "*a", "&*a"),
+        ("!x", "a as bool", "!(a as bool)"),
+        ("x as u16", "a as u8", "a as u8 as u16"),
+        ("x as u16", "-a", "-a as u16"),
+        ("x as u16", "a + b", "(a + b) as u16"),
+        ("x..y", "a + b", "a + b..y"),
+        ("x..", "a..b", "(a..b).."),
+        ("x[0]", "a + b", "(a + b)[0]"),
+        ("x?", "a + b", "(a + b)?"),
+        ("x.await", "a + b", "(a + b).await"),
+    ];
+    for &(expr, replacement, expected) in &cases {
+        let expr: Expr = syn::parse_str(expr).unwrap();
+        let replacement: Expr = syn::parse_str(replacement).unwrap();
+        let expected: Expr = syn::parse_str(expected).unwrap();
+
+        let substituted = substitute_ident(expr, "x", replacement);
+        let reparsed: Expr = syn::parse2(quote!(#substituted)).unwrap();
+        assert_eq!(reparsed, substituted);
+        assert_eq!(
+            quote!(#substituted).to_string(),
+            quote!(#expected).to_string(),
+        );
+    }
+}
+
+#[test]
+fn test_unparenthesized_positions() {
+    let block: Block = parse_quote!({
+        let y = x;
+        f(x, [x], (x, 1));
+        y = x;
+        S { x, y: x };
+        x
+    });
+
+    let expected = quote!({
+        let y = a + b;
+        f(a + b, [a + b], (a + b, 1));
+        y = a + b;
+        S { x: a + b, y: a + b };
+        a + b
+    });
+
+    let substituted = substitute_ident(block, "x", parse_quote!(a + b));
+    assert_eq!(quote!(#substituted).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_postfix_positions() {
+    let expr: Expr = parse_quote!(x(x));
+    let replacement: Expr = parse_quote!(s.f);
+
+    let substituted = substitute_ident(expr, "x", replacement);
+    let expected = quote!((s.f)(s.f));
+    assert_eq!(quote!(#substituted).to_string(), expected.to_string());
+    assert_eq!(syn::parse2::<Expr>(quote!(#substituted)).unwrap(), substituted);
+
+    let expr: Expr = parse_quote!(x.0 + x.pow(2));
+    let replacement: Expr = parse_quote!(1);
+
+    let substituted = substitute_ident(expr, "x", replacement);
+    let expected = quote!((1).0 + (1).pow(2));
+    assert_eq!(quote!(#substituted).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_struct_shorthand() {
+    let expr: Expr = parse_quote!(S { x, y, z: x });
+
+    let substituted = substitute_ident(expr.clone(), "x", parse_quote!(0));
+    let expected: Expr = parse_quote!(S { x: 0, y, z: 0 });
+    assert_eq!(substituted, expected);
+    assert_eq!(
+        quote!(#substituted).to_string(),
+        quote!(#expected).to_string(),
+    );
+
+    let substituted = substitute_ident(expr.clone(), "x", parse_quote!(x));
+    assert_eq!(quote!(#substituted).to_string(), quote!(#expr).to_string());
+}
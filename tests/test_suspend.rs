@@ -0,0 +1,95 @@
+extern crate syn;
+
+mod features;
+
+use syn::{contains_await, contains_yield, parse_quote, Expr};
+
+#[test]
+fn test_contains_await() {
+    let cases: Vec<(Expr, bool)> = vec![
+        (parse_quote!(f().await), true),
+        (parse_quote!(async { f().await }), true),
+        (parse_quote!(async move || f(x.await?)), true),
+        (
+            parse_quote!(|| {
+                if c {
+                    g(h().await)
+                }
+            }),
+            true,
+        ),
+        (parse_quote!(|| f()), false),
+        (parse_quote!(|| async { f().await }), false),
+        (
+            parse_quote!(|| {
+                let g = async || f().await;
+                g
+            }),
+            false,
+        ),
+        (
+            parse_quote!(|| {
+                async fn g() {
+                    f().await
+                }
+                g()
+            }),
+            false,
+        ),
+        (
+            parse_quote!(|| {
+                yield f();
+            }),
+            false,
+        ),
+    ];
+
+    for (expr, expected) in &cases {
+        assert_eq!(contains_await(expr), *expected, "{:?}", expr);
+    }
+}
+
+#[test]
+fn test_contains_yield() {
+    let cases: Vec<(Expr, bool)> = vec![
+        (
+            parse_quote!(|| {
+                yield 1;
+            }),
+            true,
+        ),
+        (
+            parse_quote!(|| {
+                for x in xs {
+                    yield x;
+                }
+            }),
+            true,
+        ),
+        (
+            parse_quote!(|| {
+                let y = f(yield);
+            }),
+            true,
+        ),
+        (parse_quote!(|| f().await), false),
+        (
+            parse_quote!(|| {
+                let g = || {
+                    yield 1;
+                };
+            }),
+            false,
+        ),
+        (
+            parse_quote!(|| async {
+                yield 1;
+            }),
+            false,
+        ),
+    ];
+
+    for (expr, expected) in &cases {
+        assert_eq!(contains_yield(expr), *expected, "{:?}", expr);
+    }
+}
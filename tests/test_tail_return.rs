@@ -0,0 +1,86 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+mod macros;
+
+use crate::macros::{assert_rewrite, rewrite};
+use quote::quote;
+use syn::{elide_tail_return, Block};
+
+#[test]
+fn test_tail_return() {
+    assert_rewrite(elide_tail_return, "{ return x; }", "{ x }");
+    assert_rewrite(
+        elide_tail_return,
+        "{ f(); return x + 1; }",
+        "{ f(); x + 1 }",
+    );
+    assert_rewrite(elide_tail_return, "{ f(); return x }", "{ f(); x }");
+    assert_rewrite(
+        elide_tail_return,
+        "{ return match x { _ => y }; }",
+        "{ match x { _ => y } }",
+    );
+    assert_rewrite(
+        elide_tail_return,
+        "{ return S { a: 1 }; }",
+        "{ S { a: 1 } }",
+    );
+}
+
+#[test]
+fn test_unchanged() {
+    let unchanged = [
+        "{}",
+        "{ x }",
+        "{ return; }",
+        "{ f(); return }",
+        "{ #[allow(unreachable_code)] return x; }",
+        "{ if c { return x; } y }",
+        "{ return x; f(); }",
+        "{ loop { return x; } }",
+        "{ while c { return x; } }",
+        "{ for i in v { return i; } }",
+        "{ if c { return x; } else { return y; } }",
+        "{ match c { _ => { return x; } } }",
+        "{ unsafe { return x; } }",
+        "{ { return x; } }",
+        "{ let f = || return x; }",
+    ];
+    for input in &unchanged {
+        assert_rewrite(elide_tail_return, input, input);
+    }
+}
+
+#[test]
+fn test_nested_bodies() {
+    let input = "{
+        fn inner() -> u8 { return 1; }
+        struct S;
+        impl S { fn method(&self) -> u8 { g(); return 2; } }
+        trait T { fn provided(&self) -> u8 { return 3; } fn required(&self); }
+        let f = |x| { return x; };
+        let g = async { return 4; };
+        loop { return 5; }
+    }";
+    let expected = "{
+        fn inner() -> u8 { 1 }
+        struct S;
+        impl S { fn method(&self) -> u8 { g(); 2 } }
+        trait T { fn provided(&self) -> u8 { 3 } fn required(&self); }
+        let f = |x| { x };
+        let g = async { 4 };
+        loop { return 5; }
+    }";
+    assert_rewrite(elide_tail_return, input, expected);
+}
+
+#[test]
+fn test_reparse() {
+    let input = "{ if c { a() } return -x; }";
+    let elided = rewrite(elide_tail_return, input);
+    let reparsed: Block = syn::parse_str(&elided).unwrap();
+    assert_eq!(reparsed.stmts.len(), 2);
+    assert_eq!(quote!(#reparsed).to_string(), elided);
+}
@@ -0,0 +1,93 @@
+extern crate syn;
+
+mod features;
+
+use syn::{find_todos, File};
+
+fn todos(input: &str) -> Vec<String> {
+    let file: File = syn::parse_str(input).unwrap();
+    find_todos(&file)
+        .into_iter()
+        .map(|(_span, todo)| todo)
+        .collect()
+}
+
+#[test]
+fn test_doc_comments() {
+    let input = r#"
+        //! Crate docs. TODO: write more.
+
+        /// Frobs the widget.
+        ///
+        /// FIXME: frobs twice
+        /// TODO and FIXME on one line
+        fn frob() {}
+
+        /** Block comment.
+            TODO: remove
+        */
+        struct S;
+    "#;
+    let expected = [
+        "TODO: write more.",
+        "FIXME: frobs twice",
+        "TODO and FIXME on one line",
+        "TODO: remove",
+    ];
+    assert_eq!(todos(input), expected);
+}
+
+#[test]
+fn test_doc_attributes() {
+    let input = r#"
+        #[doc = "first line\nsecond TODO line\nFIXME third"]
+        #[doc(alias = "TODO")]
+        #[doc(hidden)]
+        #[deprecated = "TODO: not documentation"]
+        fn f() {}
+    "#;
+    let expected = ["TODO line", "FIXME third"];
+    assert_eq!(todos(input), expected);
+}
+
+#[test]
+fn test_nested_attributes() {
+    let input = r#"
+        impl S {
+            /// TODO: method
+            fn method(&self) {
+                /// TODO: local item
+                fn local() {}
+                let x = 1; // TODO: not a doc comment
+            }
+        }
+
+        enum E {
+            /// FIXME: variant
+            V {
+                /// TODO: field
+                field: u8,
+            },
+        }
+
+        macro_rules! m {
+            () => {
+                /// TODO: inside of macro tokens
+                fn hidden() {}
+            };
+        }
+    "#;
+    let expected = [
+        "TODO: method",
+        "TODO: local item",
+        "FIXME: variant",
+        "TODO: field",
+    ];
+    assert_eq!(todos(input), expected);
+}
+
+#[test]
+fn test_no_markers() {
+    let input = "/// Lowercase todo and fixme are not markers.\nfn f() {}";
+    assert!(todos(input).is_empty());
+}
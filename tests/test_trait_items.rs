@@ -0,0 +1,50 @@
+extern crate syn;
+
+mod features;
+
+use syn::{parse_quote, provided_methods, required_consts, required_methods, ItemTrait};
+
+#[test]
+fn test_required_and_provided() {
+    let item: ItemTrait = parse_quote! {
+        pub trait Store: Send {
+            type Key;
+            const CAPACITY: usize;
+            const VERSION: u32 = 1;
+            fn get(&self, key: &Self::Key) -> Option<&[u8]>;
+            fn contains(&self, key: &Self::Key) -> bool {
+                self.get(key).is_some()
+            }
+            fn insert(&mut self, key: Self::Key, value: Vec<u8>);
+            unsafe fn raw(&self) -> *const u8 where Self: Sized {
+                ptr::null()
+            }
+            declare_more!();
+        }
+    };
+
+    let names = |methods: Vec<&syn::TraitItemMethod>| -> Vec<String> {
+        methods
+            .into_iter()
+            .map(|method| method.sig.ident.to_string())
+            .collect()
+    };
+    assert_eq!(names(required_methods(&item)), ["get", "insert"]);
+    assert_eq!(names(provided_methods(&item)), ["contains", "raw"]);
+
+    let consts: Vec<_> = required_consts(&item)
+        .into_iter()
+        .map(|item| item.ident.to_string())
+        .collect();
+    assert_eq!(consts, ["CAPACITY"]);
+}
+
+#[test]
+fn test_empty_trait() {
+    let item: ItemTrait = parse_quote! {
+        trait Marker {}
+    };
+    assert!(required_methods(&item).is_empty());
+    assert!(provided_methods(&item).is_empty());
+    assert!(required_consts(&item).is_empty());
+}
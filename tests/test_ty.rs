@@ -0,0 +1,114 @@
+extern crate syn;
+
+mod features;
+
+use syn::{parse_quote, BinOp, Expr, ExprBinary, ExprBlock, Type, TypeArray};
+
+#[test]
+fn test_matches_with_self() {
+    let self_ty: Type = parse_quote!(Wrapper<T>);
+
+    let pairs: Vec<(Type, Type)> = vec![
+        (parse_quote!(Self), parse_quote!(Wrapper<T>)),
+        (parse_quote!(&'a mut Self), parse_quote!(&'a mut Wrapper<T>)),
+        (
+            parse_quote!(Vec<(Self, *const Self)>),
+            parse_quote!(Vec<(Wrapper<T>, *const Wrapper<T>)>),
+        ),
+        (parse_quote!([Self; 4]), parse_quote!([Self; 4])),
+        (parse_quote!(Self::Item), parse_quote!(Self::Item)),
+        (
+            parse_quote!(Box<dyn Fn(Self) -> Self>),
+            parse_quote!(Box<dyn Fn(Wrapper<T>) -> Self>),
+        ),
+    ];
+    for (in_trait, in_impl) in &pairs {
+        assert!(in_trait.matches_with_self(in_impl, &self_ty));
+        assert!(in_impl.matches_with_self(in_trait, &self_ty));
+    }
+
+    let mismatches: Vec<(Type, Type)> = vec![
+        (parse_quote!(Self), parse_quote!(Wrapper<U>)),
+        (parse_quote!(&Self), parse_quote!(&mut Wrapper<T>)),
+        (parse_quote!(Self::Item), parse_quote!(Wrapper<T>::Item)),
+        (parse_quote!(Option<Self>), parse_quote!(Option<Other>)),
+    ];
+    for (in_trait, in_impl) in &mismatches {
+        assert!(!in_trait.matches_with_self(in_impl, &self_ty));
+    }
+}
+
+#[test]
+fn test_fn_signature() {
+    let signatures: Vec<(Type, Vec<Type>, Option<Type>)> = vec![
+        (
+            parse_quote!(fn(u8, String) -> bool),
+            vec![parse_quote!(u8), parse_quote!(String)],
+            Some(parse_quote!(bool)),
+        ),
+        (
+            parse_quote!(unsafe extern "C" fn(x: *const u8)),
+            vec![parse_quote!(*const u8)],
+            None,
+        ),
+        (parse_quote!(fn()), vec![], None),
+        (
+            parse_quote!(dyn Fn(&str) -> usize + Send),
+            vec![parse_quote!(&str)],
+            Some(parse_quote!(usize)),
+        ),
+        (parse_quote!(impl FnMut(u8)), vec![parse_quote!(u8)], None),
+        (
+            parse_quote!(dyn 'static + std::ops::FnOnce() -> ()),
+            vec![],
+            Some(parse_quote!(())),
+        ),
+        (
+            parse_quote!(dyn Fn<(u8, u16), Output = u32>),
+            vec![parse_quote!(u8), parse_quote!(u16)],
+            Some(parse_quote!(u32)),
+        ),
+        (
+            parse_quote!((impl Fn(i32) -> i32)),
+            vec![parse_quote!(i32)],
+            Some(parse_quote!(i32)),
+        ),
+    ];
+    for (ty, expected_inputs, expected_output) in &signatures {
+        let (inputs, output) = ty.fn_signature().unwrap();
+        let inputs: Vec<Type> = inputs.into_iter().cloned().collect();
+        assert_eq!(inputs, *expected_inputs);
+        assert_eq!(output, expected_output.as_ref());
+        assert_eq!(ty.arity(), Some(expected_inputs.len()));
+    }
+
+    let not_functions: Vec<Type> = vec![
+        parse_quote!(u8),
+        parse_quote!(Box<dyn Fn()>),
+        parse_quote!(dyn Iterator<Item = u8>),
+        parse_quote!(impl Fn),
+    ];
+    for ty in &not_functions {
+        assert!(ty.fn_signature().is_none());
+        assert!(ty.arity().is_none());
+    }
+}
+
+#[test]
+fn test_array_len_expr() {
+    let len = |input: &str| match syn::parse_str(input).unwrap() {
+        Type::Array(TypeArray { len, .. }) => len,
+        other => panic!("expected array type, found {:?}", other),
+    };
+
+    match len("[u8; N + 1]") {
+        Expr::Binary(ExprBinary {
+            op: BinOp::Add(_), ..
+        }) => {}
+        other => panic!("expected addition, found {:?}", other),
+    }
+    match len("[u8; { compute() }]") {
+        Expr::Block(ExprBlock { block, .. }) => assert_eq!(block.stmts.len(), 1),
+        other => panic!("expected block, found {:?}", other),
+    }
+}
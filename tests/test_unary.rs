@@ -0,0 +1,72 @@
+extern crate quote;
+extern crate syn;
+
+mod features;
+mod macros;
+
+use crate::macros::assert_rewrite;
+use quote::quote;
+use syn::{simplify_unary, Expr};
+
+#[test]
+fn test_folded() {
+    for &(input, expected) in &[
+        ("!!x", "x"),
+        ("!(!x)", "x"),
+        ("!((!x))", "x"),
+        ("- -x", "x"),
+        ("-(-x)", "x"),
+        ("- -1.5", "1.5"),
+        ("*&x", "x"),
+        ("*&mut x", "x"),
+        ("*(&x.y)", "x.y"),
+        ("!!!x", "!x"),
+        ("- - - -x", "x"),
+        ("*&*&x", "x"),
+        ("!!(a || b)", "(a || b)"),
+        ("!!a as u8", "a as u8"),
+        ("f(!!a, [- -b], *&c)", "f(a, [b], c)"),
+        ("!!x.y().z", "x.y().z"),
+        ("!(!x).y()", "!(!x).y()"),
+    ] {
+        assert_rewrite(simplify_unary, input, expected);
+    }
+}
+
+#[test]
+fn test_not_folded() {
+    for &input in &[
+        "&*x",
+        "&mut *x",
+        "!-x",
+        "-!x",
+        "*-x",
+        "-*x",
+        "*!x",
+        "-x",
+        "!x",
+        "*x",
+        "#[a] !!x",
+        "!#[a] !x",
+        "!#[a] (!x)",
+        "*#[a] &x",
+        "-(x as i32)",
+    ] {
+        assert_rewrite(simplify_unary, input, input);
+    }
+}
+
+#[test]
+fn test_nested_blocks() {
+    let expr: Expr = syn::parse_str("{ let a = !!b; if - -c { *&d } else { &*e } }").unwrap();
+    let expr = simplify_unary(expr);
+    let expected = quote!({
+        let a = b;
+        if c {
+            d
+        } else {
+            &*e
+        }
+    });
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+}
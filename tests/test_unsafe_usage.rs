@@ -0,0 +1,75 @@
+extern crate syn;
+
+mod features;
+
+use syn::{parse_quote, unsafe_usage, File};
+
+#[test]
+fn test_declarations_and_blocks() {
+    let file: File = parse_quote! {
+        unsafe fn f() {
+            unsafe {
+                g();
+            }
+        }
+
+        fn g() {
+            unsafe fn nested() {}
+            let x = unsafe { nested() };
+            let y = || unsafe { *ptr };
+        }
+    };
+    let stats = unsafe_usage(&file);
+    assert_eq!(stats.fns, 2);
+    assert_eq!(stats.blocks, 3);
+    assert_eq!(stats.impls, 0);
+    assert_eq!(stats.traits, 0);
+}
+
+#[test]
+fn test_traits_and_impls() {
+    let file: File = parse_quote! {
+        unsafe trait Zeroable {
+            unsafe fn zeroed() -> Self;
+            fn is_zero(&self) -> bool;
+        }
+
+        unsafe impl Zeroable for u8 {
+            unsafe fn zeroed() -> Self {
+                0
+            }
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+        }
+
+        impl Foo {
+            pub unsafe fn get_unchecked(&self) {}
+        }
+
+        unsafe impl<T: Send> Send for Wrapper<T> {}
+
+        extern "C" {
+            fn strlen(s: *const u8) -> usize;
+        }
+    };
+    let stats = unsafe_usage(&file);
+    assert_eq!(stats.fns, 3);
+    assert_eq!(stats.blocks, 0);
+    assert_eq!(stats.impls, 2);
+    assert_eq!(stats.traits, 1);
+}
+
+#[test]
+fn test_not_counted() {
+    let file: File = parse_quote! {
+        type Callback = unsafe extern "C" fn(*mut u8);
+
+        fn f(callback: unsafe fn()) {
+            m!(unsafe { g() });
+        }
+    };
+    let stats = unsafe_usage(&file);
+    assert_eq!(stats.fns, 0);
+    assert_eq!(stats.blocks, 0);
+}
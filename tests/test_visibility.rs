@@ -0,0 +1,61 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Type, Visibility};
+
+#[test]
+fn test_restricted() {
+    let cases = [
+        ("pub(crate)", false, "crate"),
+        ("pub(super)", false, "super"),
+        ("pub(self)", false, "self"),
+        ("pub(in a::b)", true, "a :: b"),
+        ("pub(in crate::module)", true, "crate :: module"),
+        ("pub(in super::super)", true, "super :: super"),
+        ("pub(in self::a)", true, "self :: a"),
+    ];
+    for &(input, has_in, path) in &cases {
+        let vis: Visibility = syn::parse_str(input).unwrap();
+        let restricted = match &vis {
+            Visibility::Restricted(restricted) => restricted,
+            other => panic!("expected restricted visibility, found {:?}", other),
+        };
+        assert_eq!(restricted.in_token.is_some(), has_in, "{}", input);
+        let restricted_path = &restricted.path;
+        assert_eq!(quote!(#restricted_path).to_string(), path, "{}", input);
+
+        let expected: TokenStream = input.parse().unwrap();
+        assert_eq!(quote!(#vis).to_string(), expected.to_string());
+        assert_eq!(syn::parse2::<Visibility>(quote!(#vis)).unwrap(), vis);
+    }
+}
+
+#[test]
+fn test_parenthesized_tuple_field_type() {
+    // The parentheses belong to the type of the field, not to the visibility.
+    let input: DeriveInput =
+        syn::parse_str("struct S(pub (crate::A, B), pub (self::C), pub(crate) D);").unwrap();
+    let fields = match input.data {
+        Data::Struct(data) => data.fields,
+        _ => panic!("expected struct"),
+    };
+    let fields: Vec<_> = fields.iter().collect();
+
+    match (&fields[0].vis, &fields[0].ty) {
+        (Visibility::Public(_), Type::Tuple(ty)) => assert_eq!(ty.elems.len(), 2),
+        other => panic!("unexpected field {:?}", other),
+    }
+    match (&fields[1].vis, &fields[1].ty) {
+        (Visibility::Public(_), Type::Paren(_)) => {}
+        other => panic!("unexpected field {:?}", other),
+    }
+    match &fields[2].vis {
+        Visibility::Restricted(vis) => assert!(vis.path.is_ident("crate")),
+        other => panic!("expected restricted visibility, found {:?}", other),
+    }
+}
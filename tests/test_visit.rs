@@ -0,0 +1,67 @@
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod features;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::fold::Fold;
+use syn::visit::Visit;
+use syn::File;
+
+#[test]
+fn test_visit_token_stream() {
+    struct CollectTokens(Vec<String>);
+
+    impl<'ast> Visit<'ast> for CollectTokens {
+        fn visit_token_stream(&mut self, tokens: &'ast TokenStream) {
+            self.0.push(tokens.to_string());
+        }
+    }
+
+    let file: File = syn::parse2(quote! {
+        #[attr(a)]
+        fn f() {
+            m!(b);
+        }
+        macro n { () => {} }
+    })
+    .unwrap();
+
+    let mut visitor = CollectTokens(Vec::new());
+    visitor.visit_file(&file);
+
+    let expected = vec![
+        quote!((a)).to_string(),
+        quote!(b).to_string(),
+        quote!({ () => {} }).to_string(),
+    ];
+    assert_eq!(visitor.0, expected);
+}
+
+#[test]
+fn test_fold_token_stream() {
+    struct ReplaceTokens;
+
+    impl Fold for ReplaceTokens {
+        fn fold_token_stream(&mut self, _tokens: TokenStream) -> TokenStream {
+            quote!(replaced)
+        }
+    }
+
+    let file: File = syn::parse2(quote! {
+        fn f() {
+            m!(b);
+        }
+    })
+    .unwrap();
+
+    let file = ReplaceTokens.fold_file(file);
+    let expected = quote! {
+        fn f() {
+            m!(replaced);
+        }
+    };
+    assert_eq!(quote!(#file).to_string(), expected.to_string());
+}